@@ -0,0 +1,119 @@
+//! Benchmarks of ASCII string spec.
+
+enum AsciiStrSpec {}
+
+impl validated_slice::SliceSpec for AsciiStrSpec {
+    type Custom = AsciiStr;
+    type Inner = str;
+    type Error = AsciiError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        match s.as_bytes().iter().position(|b| !b.is_ascii()) {
+            Some(pos) => Err(AsciiError { valid_up_to: pos }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// ASCII string validation error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsciiError {
+    /// Byte position of the first invalid byte.
+    valid_up_to: usize,
+}
+
+/// ASCII string slice.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiStr(str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+        custom: AsciiStr,
+        inner: str,
+        error: AsciiError,
+    };
+    { TryFrom<&{Inner}> for &{Custom} };
+}
+
+enum AsciiStringSpec {}
+
+impl validated_slice::OwnedSliceSpec for AsciiStringSpec {
+    type Custom = AsciiString;
+    type Inner = String;
+    type Error = AsciiError;
+    type SliceSpec = AsciiStrSpec;
+    type SliceCustom = AsciiStr;
+    type SliceInner = str;
+    type SliceError = AsciiError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        AsciiString(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// ASCII string.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiString(String);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+        custom: AsciiString,
+        inner: String,
+        error: AsciiError,
+        slice_custom: AsciiStr,
+        slice_inner: str,
+        slice_error: AsciiError,
+    };
+    { Borrow<{SliceCustom}> };
+    { ToOwned<Owned = {Custom}> for {SliceCustom} };
+    { TryFrom<{Inner}> };
+}
+
+validated_slice::spec_benches!(
+    ascii_str,
+    AsciiStrSpec,
+    samples = [
+        "",
+        "hello",
+        "The quick brown fox jumps over the lazy dog",
+        "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt",
+    ],
+    owned = AsciiStringSpec,
+);
+
+criterion::criterion_group!(benches, ascii_str);
+criterion::criterion_main!(benches);
@@ -0,0 +1,118 @@
+//! Integration with `bumpalo` arena allocator.
+//!
+//! Functions in this module validate the inner slices and copy them into the arena, so that parser
+//! pipelines can create many custom slices without individual heap allocations.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[repr(transparent)]
+//! # pub struct AsciiStr(str);
+//! # enum AsciiStrSpec {}
+//! # impl validated_slice::SliceSpec for AsciiStrSpec {
+//! #     type Custom = AsciiStr;
+//! #     type Inner = str;
+//! #     type Error = ();
+//! #     fn validate(s: &str) -> Result<(), ()> {
+//! #         if s.is_ascii() { Ok(()) } else { Err(()) }
+//! #     }
+//! #     validated_slice::impl_slice_spec_methods! {
+//! #         field=0;
+//! #         methods=[as_inner, as_inner_mut, from_inner_unchecked, from_inner_unchecked_mut];
+//! #     }
+//! # }
+//! use bumpalo::Bump;
+//! use validated_slice::bumpalo_adapter::alloc_validated;
+//!
+//! let bump = Bump::new();
+//! let words: Vec<&AsciiStr> = "foo bar baz"
+//!     .split(' ')
+//!     .map(|word| alloc_validated::<AsciiStrSpec>(&bump, word))
+//!     .collect::<Result<_, _>>()
+//!     .expect("Should never fail");
+//! assert_eq!(words.len(), 3);
+//!
+//! assert!(alloc_validated::<AsciiStrSpec>(&bump, "caf\u{e9}").is_err());
+//! ```
+
+use bumpalo::Bump;
+
+use crate::SliceSpec;
+
+/// A trait for inner slice types which can be copied into the `bumpalo` arena.
+///
+/// This is implemented for `str` and `[T]` where `T: Copy`.
+///
+/// # Safety
+///
+/// [`alloc_in`][`BumpAllocSlice::alloc_in`] should return a copy of `self`, with the same length
+/// and the same elements.
+/// [`alloc_validated`] and [`alloc_custom`] rely on this, and create the custom slices from the
+/// copies without validating them again.
+///
+/// [`BumpAllocSlice::alloc_in`]: trait.BumpAllocSlice.html#tymethod.alloc_in
+/// [`alloc_validated`]: fn.alloc_validated.html
+/// [`alloc_custom`]: fn.alloc_custom.html
+pub unsafe trait BumpAllocSlice {
+    /// Copies the slice into the arena.
+    fn alloc_in<'b>(&self, bump: &'b Bump) -> &'b Self;
+}
+
+// `Bump::alloc_str` returns a copy of the given string.
+unsafe impl BumpAllocSlice for str {
+    #[inline]
+    fn alloc_in<'b>(&self, bump: &'b Bump) -> &'b Self {
+        bump.alloc_str(self)
+    }
+}
+
+// `Bump::alloc_slice_copy` returns a copy of the given slice.
+unsafe impl<T: Copy> BumpAllocSlice for [T] {
+    #[inline]
+    fn alloc_in<'b>(&self, bump: &'b Bump) -> &'b Self {
+        bump.alloc_slice_copy(self)
+    }
+}
+
+/// Validates the inner slice, and copies it into the arena as the custom slice.
+///
+/// The slice is validated before the allocation, so invalid slices do not consume the arena.
+pub fn alloc_validated<'b, S>(bump: &'b Bump, s: &S::Inner) -> Result<&'b S::Custom, S::Error>
+where
+    S: ?Sized + SliceSpec,
+    S::Inner: BumpAllocSlice + 'b,
+    S::Custom: 'b,
+{
+    S::validate(s)?;
+    let allocated: &'b S::Inner = s.alloc_in(bump);
+    Ok(unsafe {
+        // This is safe only when all of the conditions below are met:
+        //
+        // * `S::validate(allocated)` returns `Ok(())`.
+        //     + This is ensured by the leading `validate()` call, since `allocated` is a copy of
+        //       `s` as the safety condition of `BumpAllocSlice` requires.
+        // * Safety condition for `<S as SliceSpec>` is satisfied.
+        crate::__from_inner_unchecked!(slice; S, allocated)
+    })
+}
+
+/// Copies the custom slice into the arena without validation.
+///
+/// This does not validate the slice again, since the copy of a valid slice is also valid.
+pub fn alloc_custom<'b, S>(bump: &'b Bump, s: &S::Custom) -> &'b S::Custom
+where
+    S: ?Sized + SliceSpec,
+    S::Inner: BumpAllocSlice + 'b,
+    S::Custom: 'b,
+{
+    let allocated: &'b S::Inner = S::as_inner(s).alloc_in(bump);
+    unsafe {
+        // This is safe only when all of the conditions below are met:
+        //
+        // * `S::validate(allocated)` returns `Ok(())`.
+        //     + This is ensured by the type of `s`, since `allocated` is a copy of `s` as the
+        //       safety condition of `BumpAllocSlice` requires.
+        // * Safety condition for `<S as SliceSpec>` is satisfied.
+        crate::__from_inner_unchecked!(slice; S, allocated)
+    }
+}
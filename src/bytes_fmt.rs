@@ -0,0 +1,78 @@
+//! Human-readable formatting of byte slices.
+
+use core::fmt;
+
+/// Writes the bytes in hexadecimal, two digits per byte.
+///
+/// With the alternate flag (such as `{:#x}`), `0x` prefix is written.
+pub fn fmt_hex(bytes: &[u8], upper: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+    for byte in bytes {
+        if upper {
+            write!(f, "{:02X}", byte)?;
+        } else {
+            write!(f, "{:02x}", byte)?;
+        }
+    }
+    Ok(())
+}
+
+/// A wrapper to display the bytes in base64.
+///
+/// This is returned by `{ fn display_base64 }` of [`impl_methods_for_slice!`].
+///
+/// # Examples
+///
+/// ```
+/// use validated_slice::Base64Display;
+///
+/// assert_eq!(Base64Display::new(b"hello").to_string(), "aGVsbG8=");
+/// assert_eq!(Base64Display::new_url_safe(b"\xfb\xff").to_string(), "-_8");
+/// ```
+///
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+#[cfg(feature = "base64")]
+#[derive(Debug, Clone, Copy)]
+pub struct Base64Display<'a> {
+    /// Bytes.
+    bytes: &'a [u8],
+    /// Whether to use the URL-safe alphabet without padding.
+    url_safe: bool,
+}
+
+#[cfg(feature = "base64")]
+impl<'a> Base64Display<'a> {
+    /// Creates a new wrapper with the standard alphabet and padding.
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            url_safe: false,
+        }
+    }
+
+    /// Creates a new wrapper with the URL-safe alphabet without padding.
+    #[inline]
+    pub fn new_url_safe(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            url_safe: true,
+        }
+    }
+}
+
+#[cfg(feature = "base64")]
+impl fmt::Display for Base64Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use base64::display::Base64Display as Inner;
+        use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+
+        if self.url_safe {
+            Inner::new(self.bytes, &URL_SAFE_NO_PAD).fmt(f)
+        } else {
+            Inner::new(self.bytes, &STANDARD).fmt(f)
+        }
+    }
+}
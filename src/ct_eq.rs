@@ -0,0 +1,85 @@
+//! Constant-time comparison.
+
+use crate::SliceSpec;
+
+/// A trait for slice specs which compare values in constant time.
+///
+/// This is used by `base: ConstTime` of [`impl_cmp_for_slice!`] and
+/// [`impl_cmp_for_owned_slice!`], which implement `PartialEq` by
+/// [`eq_const_time`][`ConstTimeEqSpec::eq_const_time`] instead of the comparison of the inner
+/// slices.
+/// This is useful for secrets such as tokens and passwords, to prevent timing side channels.
+///
+/// `PartialOrd` is not supported with `base: ConstTime`, since the ordering reveals the position
+/// of the first difference anyway.
+///
+/// With `subtle` feature, [`ct_eq_bytes`] is available to implement this trait for `str` and
+/// `[u8]`.
+///
+/// # Examples
+///
+/// ```
+/// use validated_slice::ConstTimeEqSpec;
+/// # use validated_slice::SliceSpec;
+/// # #[repr(transparent)]
+/// # pub struct Token(str);
+/// # enum TokenSpec {}
+/// # impl SliceSpec for TokenSpec {
+/// #     type Custom = Token;
+/// #     type Inner = str;
+/// #     type Error = ();
+/// #     fn validate(s: &str) -> Result<(), ()> {
+/// #         if s.is_ascii() { Ok(()) } else { Err(()) }
+/// #     }
+/// #     validated_slice::impl_slice_spec_methods! {
+/// #         field=0;
+/// #         methods=[as_inner, as_inner_mut, from_inner_unchecked, from_inner_unchecked_mut];
+/// #     }
+/// # }
+///
+/// impl ConstTimeEqSpec for TokenSpec {
+///     fn eq_const_time(lhs: &str, rhs: &str) -> bool {
+///         if lhs.len() != rhs.len() {
+///             return false;
+///         }
+///         lhs.bytes().zip(rhs.bytes()).fold(0, |acc, (l, r)| acc | (l ^ r)) == 0
+///     }
+/// }
+///
+/// validated_slice::impl_cmp_for_slice! {
+///     Spec {
+///         spec: TokenSpec,
+///         custom: Token,
+///         inner: str,
+///         base: ConstTime,
+///     };
+///     Cmp { PartialEq };
+///     { ({Custom}), ({Custom}) };
+///     { ({Custom}), (&{Custom}), rev };
+/// }
+/// ```
+///
+/// [`impl_cmp_for_slice!`]: macro.impl_cmp_for_slice.html
+/// [`impl_cmp_for_owned_slice!`]: macro.impl_cmp_for_owned_slice.html
+/// [`ct_eq_bytes`]: fn.ct_eq_bytes.html
+/// [`ConstTimeEqSpec::eq_const_time`]: trait.ConstTimeEqSpec.html#tymethod.eq_const_time
+pub trait ConstTimeEqSpec: SliceSpec {
+    /// Returns `true` if the slices are equal.
+    ///
+    /// This should take the same time for any slices of the same length.
+    fn eq_const_time(lhs: &Self::Inner, rhs: &Self::Inner) -> bool;
+}
+
+/// Compares the byte strings in constant time, using `subtle` crate.
+///
+/// This takes the same time for any byte strings of the same length.
+/// Note that the lengths are not hidden: this returns `false` immediately for different lengths.
+///
+/// This can be used to implement [`ConstTimeEqSpec`] for `str` and `[u8]`.
+///
+/// [`ConstTimeEqSpec`]: trait.ConstTimeEqSpec.html
+#[cfg(feature = "subtle")]
+#[inline]
+pub fn ct_eq_bytes<T: ?Sized + AsRef<[u8]>>(lhs: &T, rhs: &T) -> bool {
+    subtle::ConstantTimeEq::ct_eq(lhs.as_ref(), rhs.as_ref()).into()
+}
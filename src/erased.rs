@@ -0,0 +1,95 @@
+//! Type-erased validated slices.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+use core::any;
+
+use crate::{SliceSpec, Validated};
+
+/// An object-safe trait for values of validated custom slice types.
+///
+/// This allows storing values of different validated types in one heterogeneous collection, such
+/// as `Vec<&dyn ErasedValidatedSlice>`, while keeping access to the raw data and the validity.
+///
+/// This is implemented for the custom types by `{ ErasedValidatedSlice }` target of
+/// [`impl_std_traits_for_slice!`] and [`impl_std_traits_for_owned_slice!`], and for
+/// [`Validated`] witnesses.
+///
+/// Since the borrowed custom slice types are unsized, they cannot be coerced into trait objects
+/// directly. Use references to them (such as `&&Custom`) or smart pointers (such as
+/// `Box<Custom>`), which also implement this trait.
+///
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+/// [`Validated`]: struct.Validated.html
+pub trait ErasedValidatedSlice {
+    /// Returns the raw bytes of the inner slice.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Returns the name of the custom slice type.
+    ///
+    /// This is intended for diagnostics, and the content is same as `core::any::type_name`.
+    fn type_name(&self) -> &'static str;
+
+    /// Validates the inner slice again, and returns whether it is valid.
+    ///
+    /// This always returns `true` as long as the safety conditions of the spec are satisfied, so
+    /// this is useful for consistency checks such as `debug_assert!`.
+    fn revalidate(&self) -> bool;
+}
+
+impl<S> ErasedValidatedSlice for Validated<'_, S>
+where
+    S: ?Sized + SliceSpec,
+    S::Inner: AsRef<[u8]>,
+{
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_inner().as_ref()
+    }
+
+    #[inline]
+    fn type_name(&self) -> &'static str {
+        any::type_name::<S::Custom>()
+    }
+
+    #[inline]
+    fn revalidate(&self) -> bool {
+        S::validate(self.as_inner()).is_ok()
+    }
+}
+
+/// Implements `ErasedValidatedSlice` for pointer types by forwarding to the pointee.
+macro_rules! impl_erased_for_pointer {
+    ($($(#[$meta:meta])* $ptr:ty;)*) => {
+        $(
+            $(#[$meta])*
+            impl<T: ?Sized + ErasedValidatedSlice> ErasedValidatedSlice for $ptr {
+                #[inline]
+                fn as_bytes(&self) -> &[u8] {
+                    (**self).as_bytes()
+                }
+
+                #[inline]
+                fn type_name(&self) -> &'static str {
+                    (**self).type_name()
+                }
+
+                #[inline]
+                fn revalidate(&self) -> bool {
+                    (**self).revalidate()
+                }
+            }
+        )*
+    };
+}
+
+impl_erased_for_pointer! {
+    &T;
+    #[cfg(feature = "alloc")]
+    Box<T>;
+    #[cfg(feature = "alloc")]
+    Rc<T>;
+    #[cfg(feature = "alloc")]
+    Arc<T>;
+}
@@ -0,0 +1,225 @@
+//! Validation error helpers.
+
+use core::error;
+use core::fmt;
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A trait for validation errors with the location of the invalid part.
+///
+/// Implementing this trait is optional, but it enables diagnostic output such as
+/// [`CaretSnippet`].
+///
+/// [`CaretSnippet`]: struct.CaretSnippet.html
+pub trait SpannedError {
+    /// Returns the byte range of the invalid part in the input.
+    ///
+    /// The range can be empty, for example when the input ends unexpectedly.
+    fn span(&self) -> Range<usize>;
+
+    /// Returns the description of what was expected at the span.
+    #[inline]
+    fn expected(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the description of what was found at the span.
+    #[inline]
+    fn found(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Caret-style snippet of the input with the invalid part marked.
+///
+/// This renders the line containing the start of the span, and carets under the span.
+///
+/// ```text
+/// foo bar
+///    ^ expected identifier character, found space
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use core::ops::Range;
+/// use validated_slice::{CaretSnippet, SpannedError};
+///
+/// struct NonIdentError {
+///     valid_up_to: usize,
+/// }
+///
+/// impl SpannedError for NonIdentError {
+///     fn span(&self) -> Range<usize> {
+///         self.valid_up_to..(self.valid_up_to + 1)
+///     }
+///
+///     fn expected(&self) -> Option<&str> {
+///         Some("identifier character")
+///     }
+/// }
+///
+/// let snippet = CaretSnippet::new("foo bar", &NonIdentError { valid_up_to: 3 });
+/// assert_eq!(snippet.to_string(), "foo bar\n   ^ expected identifier character");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CaretSnippet<'a, E: ?Sized> {
+    /// Input string.
+    input: &'a str,
+    /// Error.
+    error: &'a E,
+}
+
+impl<'a, E: ?Sized + SpannedError> CaretSnippet<'a, E> {
+    /// Creates a new snippet for the given input and error.
+    #[inline]
+    pub fn new(input: &'a str, error: &'a E) -> Self {
+        Self { input, error }
+    }
+}
+
+impl<E: ?Sized + SpannedError> fmt::Display for CaretSnippet<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let span = self.error.span();
+        let start = floor_char_boundary(self.input, span.start);
+        let end = floor_char_boundary(self.input, span.end.max(start));
+
+        let line_start = self.input[..start].rfind('\n').map_or(0, |pos| pos + 1);
+        let line_end = self.input[start..]
+            .find('\n')
+            .map_or(self.input.len(), |pos| start + pos);
+        let line = self.input[line_start..line_end].trim_end_matches('\r');
+        // The span is clamped to the line.
+        let end = end.min(line_start + line.len()).max(start);
+
+        let indent = self.input[line_start..start].chars().count();
+        let width = self.input[start..end].chars().count().max(1);
+
+        f.write_str(line)?;
+        f.write_str("\n")?;
+        write!(f, "{:indent$}", "", indent = indent)?;
+        (0..width).try_for_each(|_| f.write_str("^"))?;
+        match (self.error.expected(), self.error.found()) {
+            (Some(expected), Some(found)) => write!(f, " expected {}, found {}", expected, found),
+            (Some(expected), None) => write!(f, " expected {}", expected),
+            (None, Some(found)) => write!(f, " found {}", found),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Returns the largest char boundary of the string which is not greater than `index`.
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    if index >= s.len() {
+        return s.len();
+    }
+    (0..=index)
+        .rev()
+        .find(|&i| s.is_char_boundary(i))
+        .unwrap_or(0)
+}
+
+/// Error of conversion from bytes, which checks UTF-8 validity before the spec validation.
+///
+/// This is returned by the conversions such as `{ TryFrom<&[u8]> for &{Custom} }` target of
+/// [`impl_std_traits_for_slice!`] and `{ TryFrom<Vec<u8>> }` target of
+/// [`impl_std_traits_for_owned_slice!`].
+///
+/// `U` is the UTF-8 error type (such as `Utf8Error` and `FromUtf8Error`), and `E` is the
+/// validation error type of the spec.
+///
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromBytesError<U, E> {
+    /// The bytes are not valid UTF-8.
+    Utf8(U),
+    /// The string is rejected by the spec.
+    Spec(E),
+}
+
+impl<U: fmt::Display, E: fmt::Display> fmt::Display for FromBytesError<U, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Utf8(e) => e.fmt(f),
+            Self::Spec(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<U, E> error::Error for FromBytesError<U, E>
+where
+    U: error::Error + 'static,
+    E: error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Utf8(e) => Some(e),
+            Self::Spec(e) => Some(e),
+        }
+    }
+}
+
+/// Validation error of bulk conversion, with the index of the first invalid element.
+///
+/// This is returned by `{ fn try_from_vec }` of [`impl_methods_for_owned_slice!`].
+/// The error keeps the whole input vector, so that the caller can recover the values.
+///
+/// [`impl_methods_for_owned_slice!`]: macro.impl_methods_for_owned_slice.html
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BulkValidationError<E, T> {
+    /// Index of the first invalid element.
+    index: usize,
+    /// Validation error of the element.
+    error: E,
+    /// Input vector.
+    values: Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<E, T> BulkValidationError<E, T> {
+    /// Creates a new error.
+    #[inline]
+    pub fn new(index: usize, error: E, values: Vec<T>) -> Self {
+        Self {
+            index,
+            error,
+            values,
+        }
+    }
+
+    /// Returns the index of the first invalid element.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the validation error of the element.
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the input vector.
+    #[inline]
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: fmt::Display, T> fmt::Display for BulkValidationError<E, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid element at index {}: {}", self.index, self.error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: error::Error + 'static, T: fmt::Debug> error::Error for BulkValidationError<E, T> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
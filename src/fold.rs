@@ -0,0 +1,87 @@
+//! Comparison by folded keys.
+
+use core::cmp::Ordering;
+
+use crate::SliceSpec;
+
+/// A trait for slice specs which compare values by folded keys.
+///
+/// This is used by `base: Folded` of [`impl_cmp_for_slice!`] and [`impl_cmp_for_owned_slice!`],
+/// which implement `PartialEq` and `PartialOrd` by [`cmp_folded`][`FoldedCmpSpec::cmp_folded`]
+/// instead of the comparison of the inner slices.
+/// This is useful for values such as header names, identifiers, and file names on case-insensitive
+/// file systems.
+///
+/// The exact comparison is still available by `{ fn eq_exact }` of [`impl_methods_for_slice!`].
+///
+/// Note that `Hash` and `Ord` should be consistent with the folded comparison.
+/// Use `{ Hash(with = ..) }` of [`impl_std_traits_for_slice!`] with a function hashing the folded
+/// key, rather than `#[derive(Hash)]`.
+///
+/// # Examples
+///
+/// ```
+/// use validated_slice::{cmp_ignore_ascii_case, FoldedCmpSpec};
+/// # use validated_slice::SliceSpec;
+/// # #[repr(transparent)]
+/// # pub struct HeaderName(str);
+/// # enum HeaderNameSpec {}
+/// # impl SliceSpec for HeaderNameSpec {
+/// #     type Custom = HeaderName;
+/// #     type Inner = str;
+/// #     type Error = ();
+/// #     fn validate(s: &str) -> Result<(), ()> {
+/// #         if s.is_ascii() { Ok(()) } else { Err(()) }
+/// #     }
+/// #     validated_slice::impl_slice_spec_methods! {
+/// #         field=0;
+/// #         methods=[as_inner, as_inner_mut, from_inner_unchecked, from_inner_unchecked_mut];
+/// #     }
+/// # }
+///
+/// impl FoldedCmpSpec for HeaderNameSpec {
+///     fn cmp_folded(lhs: &str, rhs: &str) -> std::cmp::Ordering {
+///         cmp_ignore_ascii_case(lhs, rhs)
+///     }
+/// }
+///
+/// validated_slice::impl_cmp_for_slice! {
+///     Spec {
+///         spec: HeaderNameSpec,
+///         custom: HeaderName,
+///         inner: str,
+///         base: Folded,
+///     };
+///     Cmp { PartialEq, PartialOrd };
+///     { ({Custom}), ({Custom}) };
+///     { ({Custom}), ({Inner}), rev };
+/// }
+/// ```
+///
+/// [`impl_cmp_for_slice!`]: macro.impl_cmp_for_slice.html
+/// [`impl_cmp_for_owned_slice!`]: macro.impl_cmp_for_owned_slice.html
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+/// [`FoldedCmpSpec::cmp_folded`]: trait.FoldedCmpSpec.html#tymethod.cmp_folded
+pub trait FoldedCmpSpec: SliceSpec {
+    /// Compares the slices by their folded keys.
+    fn cmp_folded(lhs: &Self::Inner, rhs: &Self::Inner) -> Ordering;
+
+    /// Returns `true` if the slices have the same folded key.
+    #[inline]
+    fn eq_folded(lhs: &Self::Inner, rhs: &Self::Inner) -> bool {
+        Self::cmp_folded(lhs, rhs) == Ordering::Equal
+    }
+}
+
+/// Compares the byte strings, ignoring ASCII case.
+///
+/// This compares the strings as if they are converted to ASCII lowercase.
+/// This can be used to implement [`FoldedCmpSpec`] for `str` and `[u8]`.
+///
+/// [`FoldedCmpSpec`]: trait.FoldedCmpSpec.html
+pub fn cmp_ignore_ascii_case<T: ?Sized + AsRef<[u8]>>(lhs: &T, rhs: &T) -> Ordering {
+    let lhs = lhs.as_ref().iter().map(u8::to_ascii_lowercase);
+    let rhs = rhs.as_ref().iter().map(u8::to_ascii_lowercase);
+    lhs.cmp(rhs)
+}
@@ -0,0 +1,207 @@
+//! Mutation guard.
+
+use core::fmt;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut, IndexMut};
+
+use crate::{MutationHookSpec, OwnedSliceSpec, SliceSpec};
+
+/// A guard to mutate a custom owned slice through the borrowed custom slice.
+///
+/// [`MutationHookSpec::before_mutation`] is called when the guard is created, and
+/// [`MutationHookSpec::after_mutation`] is called when the guard is dropped.
+///
+/// [`MutationHookSpec::before_mutation`]: trait.MutationHookSpec.html#method.before_mutation
+/// [`MutationHookSpec::after_mutation`]: trait.MutationHookSpec.html#method.after_mutation
+pub struct MutationGuard<'a, S: MutationHookSpec> {
+    /// Custom owned slice being mutated.
+    custom: &'a mut S::Custom,
+}
+
+impl<'a, S: MutationHookSpec> MutationGuard<'a, S> {
+    /// Creates a new guard, and calls [`MutationHookSpec::before_mutation`].
+    ///
+    /// [`MutationHookSpec::before_mutation`]: trait.MutationHookSpec.html#method.before_mutation
+    #[inline]
+    pub fn new(custom: &'a mut S::Custom) -> Self {
+        S::before_mutation(custom);
+        Self { custom }
+    }
+}
+
+impl<S> Deref for MutationGuard<'_, S>
+where
+    S: MutationHookSpec,
+    S::SliceSpec: SliceSpec<Custom = S::SliceCustom, Inner = S::SliceInner>,
+{
+    type Target = S::SliceCustom;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `S::validate(s)` returns `Ok(())`.
+            //     + This is ensured when `self.custom` is constructed.
+            // * Safety condition for `<S as OwnedSliceSpec>` is satisfied.
+            crate::__from_inner_unchecked!(slice; S::SliceSpec, S::as_slice_inner(self.custom))
+        }
+    }
+}
+
+impl<S> DerefMut for MutationGuard<'_, S>
+where
+    S: MutationHookSpec,
+    S::SliceSpec: SliceSpec<Custom = S::SliceCustom, Inner = S::SliceInner>,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `S::validate(s)` returns `Ok(())`.
+            //     + This is ensured when `self.custom` is constructed.
+            // * Safety condition for `<S as OwnedSliceSpec>` is satisfied.
+            crate::__from_inner_unchecked!(
+                slice_mut;
+                S::SliceSpec,
+                S::as_slice_inner_mut(self.custom)
+            )
+        }
+    }
+}
+
+impl<S: MutationHookSpec> Drop for MutationGuard<'_, S> {
+    #[inline]
+    fn drop(&mut self) {
+        S::after_mutation(self.custom);
+    }
+}
+
+impl<S: MutationHookSpec> fmt::Debug for MutationGuard<'_, S>
+where
+    S::Custom: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MutationGuard").field(&self.custom).finish()
+    }
+}
+
+/// A guard to restore the replaced element of a slice on drop, unless committed.
+///
+/// This is used by `try_modify` generated by `impl_methods_for_slice!`, so that the slice is
+/// restored even if the revalidation panics.
+///
+/// This is not a public API.
+#[doc(hidden)]
+pub struct ElementRestoreGuard<'a, T>
+where
+    T: ?Sized + IndexMut<usize>,
+    T::Output: Sized,
+{
+    /// Slice being modified.
+    slice: &'a mut T,
+    /// Index of the replaced element.
+    index: usize,
+    /// Old element to be restored.
+    old: Option<T::Output>,
+}
+
+impl<'a, T> ElementRestoreGuard<'a, T>
+where
+    T: ?Sized + IndexMut<usize>,
+    T::Output: Sized,
+{
+    /// Replaces the element at the given index, and returns the guard to restore it.
+    #[inline]
+    pub fn replace(slice: &'a mut T, index: usize, new: T::Output) -> Self {
+        let old = core::mem::replace(&mut slice[index], new);
+        Self {
+            slice,
+            index,
+            old: Some(old),
+        }
+    }
+
+    /// Returns the modified slice.
+    #[inline]
+    pub fn slice(&self) -> &T {
+        self.slice
+    }
+
+    /// Keeps the new element.
+    #[inline]
+    pub fn commit(mut self) {
+        self.old = None;
+    }
+}
+
+impl<T> Drop for ElementRestoreGuard<'_, T>
+where
+    T: ?Sized + IndexMut<usize>,
+    T::Output: Sized,
+{
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(old) = self.old.take() {
+            self.slice[self.index] = old;
+        }
+    }
+}
+
+/// A guard to put the owned inner value back into the custom owned slice on drop.
+///
+/// This is used by `modify_owned_inner`, so that the value taken out of the custom value is put
+/// back even if the modification panics.
+pub(crate) struct OwnedInnerGuard<'a, S: OwnedSliceSpec>
+where
+    S::SliceSpec: SliceSpec<Inner = S::SliceInner>,
+{
+    /// Custom value to put the inner value back into.
+    custom: &'a mut S::Custom,
+    /// Inner value being modified.
+    inner: ManuallyDrop<S::Inner>,
+}
+
+impl<'a, S: OwnedSliceSpec> OwnedInnerGuard<'a, S>
+where
+    S::SliceSpec: SliceSpec<Inner = S::SliceInner>,
+{
+    /// Creates a new guard.
+    ///
+    /// # Safety
+    ///
+    /// `inner` should be kept valid while the guard is alive, including while unwinding.
+    #[inline]
+    pub(crate) unsafe fn new(custom: &'a mut S::Custom, inner: S::Inner) -> Self {
+        Self {
+            custom,
+            inner: ManuallyDrop::new(inner),
+        }
+    }
+
+    /// Returns the inner value being modified.
+    #[inline]
+    pub(crate) fn inner_mut(&mut self) -> &mut S::Inner {
+        &mut self.inner
+    }
+}
+
+impl<S: OwnedSliceSpec> Drop for OwnedInnerGuard<'_, S>
+where
+    S::SliceSpec: SliceSpec<Inner = S::SliceInner>,
+{
+    #[inline]
+    fn drop(&mut self) {
+        // The inner value is not used after this.
+        let inner = unsafe { ManuallyDrop::take(&mut self.inner) };
+        *self.custom = unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `S::validate(s)` returns `Ok(())`.
+            //     + This is ensured by the caller of `Self::new`.
+            // * Safety condition for `<S as OwnedSliceSpec>` is satisfied.
+            crate::__from_inner_unchecked!(owned; S, inner)
+        };
+    }
+}
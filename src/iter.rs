@@ -0,0 +1,126 @@
+//! Iterators.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::SliceSpec;
+
+/// An iterator which converts the inner sub-slices into the custom slices.
+///
+/// This is returned by the methods generated by [`impl_methods_for_slice!`], such as
+/// `{ fn windows }` and `{ fn chunks }`.
+///
+/// This implements `DoubleEndedIterator`, `ExactSizeIterator`, and `FusedIterator` when the
+/// iterator of the inner sub-slices does.
+///
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+pub struct SubSlices<'a, S: ?Sized + SliceSpec, I> {
+    /// Iterator of the inner sub-slices.
+    iter: I,
+    /// Spec.
+    _spec: PhantomData<fn() -> &'a S>,
+}
+
+impl<'a, S: ?Sized + SliceSpec, I> SubSlices<'a, S, I>
+where
+    I: Iterator<Item = &'a S::Inner>,
+{
+    /// Creates a new iterator from the iterator of the inner sub-slices.
+    ///
+    /// # Safety
+    ///
+    /// `S::validate(s)` should return `Ok(())` for every item `s` of the given iterator.
+    ///
+    /// Users and generated codes may rely on the validity of the custom slices.
+    #[inline]
+    pub unsafe fn new_unchecked(iter: I) -> Self {
+        Self {
+            iter,
+            _spec: PhantomData,
+        }
+    }
+}
+
+impl<'a, S: ?Sized + SliceSpec, I> Iterator for SubSlices<'a, S, I>
+where
+    S::Inner: 'a,
+    S::Custom: 'a,
+    I: Iterator<Item = &'a S::Inner>,
+{
+    type Item = &'a S::Custom;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let inner = self.iter.next()?;
+        Some(unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `S::validate(inner)` returns `Ok(())`.
+            //     + This is ensured when `self` is created.
+            // * Safety condition for `<S as SliceSpec>` is satisfied.
+            crate::__from_inner_unchecked!(slice; S, inner)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, S: ?Sized + SliceSpec, I> DoubleEndedIterator for SubSlices<'a, S, I>
+where
+    S::Inner: 'a,
+    S::Custom: 'a,
+    I: DoubleEndedIterator<Item = &'a S::Inner>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let inner = self.iter.next_back()?;
+        Some(unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `S::validate(inner)` returns `Ok(())`.
+            //     + This is ensured when `self` is created.
+            // * Safety condition for `<S as SliceSpec>` is satisfied.
+            crate::__from_inner_unchecked!(slice; S, inner)
+        })
+    }
+}
+
+impl<'a, S: ?Sized + SliceSpec, I> ExactSizeIterator for SubSlices<'a, S, I>
+where
+    S::Inner: 'a,
+    S::Custom: 'a,
+    I: ExactSizeIterator<Item = &'a S::Inner>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, S: ?Sized + SliceSpec, I> FusedIterator for SubSlices<'a, S, I>
+where
+    S::Inner: 'a,
+    S::Custom: 'a,
+    I: FusedIterator<Item = &'a S::Inner>,
+{
+}
+
+impl<S: ?Sized + SliceSpec, I: Clone> Clone for SubSlices<'_, S, I> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            _spec: PhantomData,
+        }
+    }
+}
+
+impl<S: ?Sized + SliceSpec, I: fmt::Debug> fmt::Debug for SubSlices<'_, S, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SubSlices").field(&self.iter).finish()
+    }
+}
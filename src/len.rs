@@ -0,0 +1,363 @@
+//! Length constraint combinators.
+
+use core::convert::Infallible;
+use core::error;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{NonEmptySpec, RevalidateRegionSpec, SliceSpec, SortedSliceSpec, TrustedSpec};
+
+/// A trait for inner slice types which have length.
+///
+/// This is used by the length constraint combinators such as [`MinLen`].
+///
+/// This is implemented for `str` (length in bytes) and `[T]` (number of elements).
+///
+/// [`MinLen`]: struct.MinLen.html
+pub trait SliceLen {
+    /// Returns the length of the slice.
+    fn slice_len(&self) -> usize;
+}
+
+impl SliceLen for str {
+    #[inline]
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> SliceLen for [T] {
+    #[inline]
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Validation error of the length constraint combinators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LenError<E> {
+    /// The slice is shorter than the minimum length.
+    TooShort {
+        /// Minimum length.
+        min: usize,
+        /// Actual length.
+        len: usize,
+    },
+    /// The slice is longer than the maximum length.
+    TooLong {
+        /// Maximum length.
+        max: usize,
+        /// Actual length.
+        len: usize,
+    },
+    /// The slice is rejected by the wrapped spec.
+    Inner(E),
+}
+
+impl<E: fmt::Display> fmt::Display for LenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort { min, len } => {
+                write!(f, "too short: expected at least {}, got {}", min, len)
+            }
+            Self::TooLong { max, len } => {
+                write!(f, "too long: expected at most {}, got {}", max, len)
+            }
+            Self::Inner(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for LenError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Inner(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Defines a length constraint combinator.
+macro_rules! define_len_spec {
+    (
+        $(#[$meta:meta])*
+        $name:ident $(<const $n:ident>)?;
+        |$len:ident| $check:expr
+    ) => {
+        $(#[$meta])*
+        pub struct $name<$(const $n: usize,)? S: ?Sized> {
+            /// Uninhabited field, since specs are not intended to be instantiated.
+            _never: Infallible,
+            /// Wrapped spec.
+            _spec: PhantomData<S>,
+        }
+
+        impl<$(const $n: usize,)? S: ?Sized + SliceSpec> SliceSpec for $name<$($n,)? S>
+        where
+            S::Inner: SliceLen,
+        {
+            type Custom = S::Custom;
+            type Inner = S::Inner;
+            type Error = LenError<S::Error>;
+
+            #[inline]
+            fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+                let $len = s.slice_len();
+                $check?;
+                S::validate(s).map_err(LenError::Inner)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn validate_all(s: &Self::Inner) -> Vec<Self::Error> {
+                let $len = s.slice_len();
+                let mut errors: Vec<_> = $check.err().into_iter().collect();
+                errors.extend(S::validate_all(s).into_iter().map(LenError::Inner));
+                errors
+            }
+
+            #[inline]
+            fn as_inner(s: &Self::Custom) -> &Self::Inner {
+                S::as_inner(s)
+            }
+
+            #[inline]
+            fn as_inner_mut(s: &mut Self::Custom) -> &mut Self::Inner {
+                S::as_inner_mut(s)
+            }
+
+            #[inline]
+            unsafe fn from_inner_unchecked(s: &Self::Inner) -> &Self::Custom {
+                // Valid slices for `Self` are also valid for `S`.
+                unsafe { S::from_inner_unchecked(s) }
+            }
+
+            #[inline]
+            unsafe fn from_inner_unchecked_mut(s: &mut Self::Inner) -> &mut Self::Custom {
+                // Valid slices for `Self` are also valid for `S`.
+                unsafe { S::from_inner_unchecked_mut(s) }
+            }
+        }
+
+        // Forwarding is sound, since `Self` only forwards the conversions to `S`.
+        unsafe impl<$(const $n: usize,)? S: ?Sized + TrustedSpec> TrustedSpec
+            for $name<$($n,)? S>
+        {
+        }
+
+        // The length is checked as `validate()` does, and the rest is forwarded to `S`.
+        unsafe impl<$(const $n: usize,)? S: ?Sized + RevalidateRegionSpec> RevalidateRegionSpec
+            for $name<$($n,)? S>
+        where
+            S::Inner: SliceLen,
+        {
+            #[inline]
+            fn revalidate_region(s: &Self::Inner, edited: Range<usize>) -> Result<(), Self::Error> {
+                let $len = s.slice_len();
+                $check?;
+                S::revalidate_region(s, edited).map_err(LenError::Inner)
+            }
+        }
+
+        impl<$(const $n: usize,)? S: ?Sized + SortedSliceSpec> SortedSliceSpec
+            for $name<$($n,)? S>
+        where
+            S::Inner: SliceLen,
+        {
+        }
+
+        $(
+            impl<const $n: usize, S: ?Sized + NonEmptySpec> NonEmptySpec for $name<$n, S>
+            where
+                S::Inner: SliceLen,
+            {
+            }
+        )?
+    };
+}
+
+/// Checks the minimum length.
+#[inline]
+fn check_min<E>(min: usize, len: usize) -> Result<(), LenError<E>> {
+    if len < min {
+        return Err(LenError::TooShort { min, len });
+    }
+    Ok(())
+}
+
+/// Checks the maximum length.
+#[inline]
+fn check_max<E>(max: usize, len: usize) -> Result<(), LenError<E>> {
+    if len > max {
+        return Err(LenError::TooLong { max, len });
+    }
+    Ok(())
+}
+
+define_len_spec! {
+    /// A spec combinator which requires the length to be at least `N`.
+    ///
+    /// The length is checked before the validation by the wrapped spec `S`.
+    /// The custom and inner types are same as those of `S`.
+    ///
+    /// Use this combinator as the spec of the custom type, instead of `S` itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validated_slice::{LenError, MaxLen, MinLen, SliceSpec};
+    ///
+    /// #[repr(transparent)]
+    /// pub struct Name(str);
+    ///
+    /// enum NameCharsSpec {}
+    ///
+    /// impl SliceSpec for NameCharsSpec {
+    ///     type Custom = Name;
+    ///     type Inner = str;
+    ///     type Error = ();
+    ///
+    ///     fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+    ///         if s.bytes().all(|b| b.is_ascii_alphabetic()) {
+    ///             Ok(())
+    ///         } else {
+    ///             Err(())
+    ///         }
+    ///     }
+    ///
+    ///     validated_slice::impl_slice_spec_methods! {
+    ///         field=0;
+    ///         methods=[
+    ///             as_inner,
+    ///             as_inner_mut,
+    ///             from_inner_unchecked,
+    ///             from_inner_unchecked_mut,
+    ///         ];
+    ///     }
+    /// }
+    ///
+    /// type NameSpec = MaxLen<8, MinLen<1, NameCharsSpec>>;
+    ///
+    /// assert_eq!(NameSpec::validate("alice"), Ok(()));
+    /// assert_eq!(
+    ///     NameSpec::validate(""),
+    ///     Err(LenError::Inner(LenError::TooShort { min: 1, len: 0 }))
+    /// );
+    /// assert_eq!(
+    ///     NameSpec::validate("bartholomew"),
+    ///     Err(LenError::TooLong { max: 8, len: 11 })
+    /// );
+    /// assert_eq!(NameSpec::validate("bob1"), Err(LenError::Inner(LenError::Inner(()))));
+    /// assert_eq!(
+    ///     NameSpec::validate_all("bartholomew1"),
+    ///     [LenError::TooLong { max: 8, len: 12 }, LenError::Inner(LenError::Inner(()))]
+    /// );
+    /// ```
+    MinLen<const N>;
+    |len| check_min(N, len)
+}
+
+define_len_spec! {
+    /// A spec combinator which requires the length to be at most `N`.
+    ///
+    /// The length is checked before the validation by the wrapped spec `S`.
+    /// The custom and inner types are same as those of `S`.
+    ///
+    /// See [`MinLen`] for usage.
+    ///
+    /// [`MinLen`]: struct.MinLen.html
+    MaxLen<const N>;
+    |len| check_max(N, len)
+}
+
+define_len_spec! {
+    /// A spec combinator which requires the length to be exactly `N`.
+    ///
+    /// The length is checked before the validation by the wrapped spec `S`.
+    /// The custom and inner types are same as those of `S`.
+    ///
+    /// See [`MinLen`] for usage.
+    ///
+    /// [`MinLen`]: struct.MinLen.html
+    ExactLen<const N>;
+    |len| check_min(N, len).and_then(|()| check_max(N, len))
+}
+
+define_len_spec! {
+    /// A spec combinator which requires the slice to be non-empty.
+    ///
+    /// This is same as `MinLen<1, S>`, but this also implements [`NonEmptySpec`], so that the
+    /// methods such as `{ fn first }` of [`impl_methods_for_slice!`] can be generated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use validated_slice::NonEmpty;
+    ///
+    /// #[repr(transparent)]
+    /// pub struct Samples([i32]);
+    ///
+    /// enum SamplesSpec {}
+    ///
+    /// impl validated_slice::SliceSpec for SamplesSpec {
+    ///     type Custom = Samples;
+    ///     type Inner = [i32];
+    ///     type Error = std::convert::Infallible;
+    ///
+    ///     fn validate(_: &Self::Inner) -> Result<(), Self::Error> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     validated_slice::impl_slice_spec_methods! {
+    ///         field=0;
+    ///         methods=[
+    ///             as_inner,
+    ///             as_inner_mut,
+    ///             from_inner_unchecked,
+    ///             from_inner_unchecked_mut,
+    ///         ];
+    ///     }
+    /// }
+    ///
+    /// validated_slice::impl_std_traits_for_slice! {
+    ///     Spec {
+    ///         spec: NonEmpty<SamplesSpec>,
+    ///         custom: Samples,
+    ///         inner: [i32],
+    ///         error: validated_slice::LenError<std::convert::Infallible>,
+    ///     };
+    ///     { TryFrom<&{Inner}> for &{Custom} };
+    /// }
+    ///
+    /// validated_slice::impl_methods_for_slice! {
+    ///     Spec {
+    ///         spec: NonEmpty<SamplesSpec>,
+    ///         custom: Samples,
+    ///         inner: [i32],
+    ///         error: validated_slice::LenError<std::convert::Infallible>,
+    ///     };
+    ///     { pub fn first };
+    ///     { pub fn last };
+    /// }
+    ///
+    /// use core::convert::TryFrom;
+    ///
+    /// let samples = <&Samples>::try_from(&[3, 1, 4][..]).unwrap();
+    /// // Not `Option<&i32>`.
+    /// let first: &i32 = samples.first();
+    /// assert_eq!(*first, 3);
+    /// assert_eq!(*samples.last(), 4);
+    ///
+    /// assert!(<&Samples>::try_from(&[][..]).is_err());
+    /// ```
+    ///
+    /// [`NonEmptySpec`]: trait.NonEmptySpec.html
+    /// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+    NonEmpty;
+    |len| check_min(1, len)
+}
+
+impl<S: ?Sized + SliceSpec> NonEmptySpec for NonEmpty<S> where S::Inner: SliceLen {}
@@ -1,10 +1,200 @@
 //! A library to easily define validated custom slice and vector types.
+//!
+//! # Features
+//!
+//! * `std` (enabled by default): enables items which require `std`.
+//!     + Without this feature, this crate is `no_std`.
+//! * `alloc`: enables items which require `alloc`, and lets the macros use `alloc` crate
+//!   re-exported from this crate when `Std { .. };` is omitted.
+//!     + Without this feature, the macros use `alloc` crate of the caller by default. Users should
+//!       declare `extern crate alloc;` to use the targets which require `alloc`.
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![warn(clippy::missing_docs_in_private_items)]
 
+#[cfg(feature = "base64")]
+pub use self::bytes_fmt::Base64Display;
+#[cfg(feature = "subtle")]
+pub use self::ct_eq::ct_eq_bytes;
+pub use self::ct_eq::ConstTimeEqSpec;
+pub use self::erased::ErasedValidatedSlice;
+#[cfg(feature = "alloc")]
+pub use self::error::BulkValidationError;
+pub use self::error::{CaretSnippet, FromBytesError, SpannedError};
+pub use self::fold::{cmp_ignore_ascii_case, FoldedCmpSpec};
+pub use self::guard::MutationGuard;
+pub use self::iter::SubSlices;
+pub use self::len::{ExactLen, LenError, MaxLen, MinLen, NonEmpty, SliceLen};
+pub use self::parse::{
+    validate_by_complete_parser, validate_by_parser, ParseError, ParseErrorKind, ParseErrorPosition,
+};
+#[cfg(feature = "regex")]
+pub use self::regex_spec::RegexMismatchError;
+pub use self::search::{Needle, SearchSlice};
+pub use self::witness::Validated;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::Range;
+
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "bumpalo")]
+pub mod bumpalo_adapter;
+mod bytes_fmt;
+mod ct_eq;
+mod erased;
+mod error;
+mod fold;
+mod guard;
+mod iter;
+mod len;
+mod parse;
+#[cfg(feature = "regex")]
+mod regex_spec;
+mod search;
+#[cfg(feature = "serde")]
+pub mod serde_adapter;
+mod witness;
+
+/// Items used by generated codes.
+///
+/// This is not a public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "regex")]
+    pub use crate::regex_spec::RegexValidator;
+    #[cfg(feature = "bumpalo")]
+    pub use bumpalo;
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+    #[cfg(feature = "equivalent")]
+    pub use equivalent;
+    #[cfg(feature = "nom")]
+    pub use nom;
+    #[cfg(feature = "rand")]
+    pub use rand;
+    #[cfg(feature = "rkyv")]
+    pub use rkyv;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "sqlx")]
+    pub use sqlx;
+    #[cfg(feature = "ufmt")]
+    pub use ufmt;
+
+    #[cfg(feature = "alloc")]
+    pub extern crate alloc;
+    pub extern crate core;
+
+    pub use crate::bytes_fmt::fmt_hex;
+    pub use crate::guard::ElementRestoreGuard;
+    pub use crate::search::{strip_prefix_len, strip_suffix_len};
+
+    use crate::{
+        FoldedCmpSpec, OwnedSliceSpec, SliceClosedSpec, SliceSpec, TrustedSpec, ValueSpec,
+    };
+
+    /// Compares the slices by the folded keys, for `PartialOrd` impls.
+    #[inline]
+    pub fn partial_cmp_folded<S: ?Sized + FoldedCmpSpec>(
+        lhs: &S::Inner,
+        rhs: &S::Inner,
+    ) -> Option<core::cmp::Ordering> {
+        Some(S::cmp_folded(lhs, rhs))
+    }
+
+    /// Asserts that the slice spec is trusted.
+    #[inline]
+    pub fn assert_trusted_slice_spec<T: ?Sized + SliceSpec + TrustedSpec>() {}
+
+    /// Asserts that the owned slice spec and its slice spec are trusted.
+    #[inline]
+    pub fn assert_trusted_owned_slice_spec<T>()
+    where
+        T: ?Sized + OwnedSliceSpec + TrustedSpec,
+        T::SliceSpec: TrustedSpec,
+    {
+    }
+
+    /// Asserts that the slice spec is closed under sub-slicing.
+    ///
+    /// This is used instead of `where` clauses in the impls generic over lifetimes and tags,
+    /// because such clauses prevent the associated types of `SliceSpec` from being normalized.
+    #[inline]
+    pub fn assert_slice_closed_spec<T: ?Sized + SliceClosedSpec>() {}
+
+    /// Asserts that the value spec is trusted.
+    #[inline]
+    pub fn assert_trusted_value_spec<T: ?Sized + ValueSpec + TrustedSpec>() {}
+
+    /// Asserts that the slice spec never fails the validation.
+    #[inline]
+    pub fn assert_infallible_slice_spec<T>()
+    where
+        T: ?Sized + SliceSpec<Error = core::convert::Infallible>,
+    {
+    }
+
+    /// Asserts that the slice spec of the owned slice spec never fails the validation.
+    #[inline]
+    pub fn assert_infallible_owned_slice_spec<T>()
+    where
+        T: ?Sized + OwnedSliceSpec,
+        T::SliceSpec: SliceSpec<Error = core::convert::Infallible>,
+    {
+    }
+
+    /// Calls the function with the owned inner value of the custom owned slice.
+    ///
+    /// While `f` is running, `custom` holds the value created from `S::Inner::default()`.
+    /// The inner value is put back into `custom` after `f` returns, or while unwinding if `f`
+    /// panics.
+    ///
+    /// # Safety
+    ///
+    /// `f` should keep the inner value valid, even when it panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S::Inner::default()` is not valid.
+    pub unsafe fn modify_owned_inner<S, R>(
+        custom: &mut S::Custom,
+        f: impl FnOnce(&mut S::Inner) -> R,
+    ) -> R
+    where
+        S: OwnedSliceSpec,
+        S::Inner: Default,
+        S::SliceSpec: SliceSpec<Inner = S::SliceInner>,
+    {
+        let placeholder = S::Inner::default();
+        assert!(
+            S::SliceSpec::validate(S::inner_as_slice_inner(&placeholder)).is_ok(),
+            "the default inner value should be valid"
+        );
+        let placeholder = unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `S::validate(s)` returns `Ok(())`.
+            //     + This is ensured by the leading assertion.
+            // * Safety condition for `<S as OwnedSliceSpec>` is satisfied.
+            crate::__from_inner_unchecked!(owned; S, placeholder)
+        };
+        let inner = S::into_inner(core::mem::replace(custom, placeholder));
+        // The inner value is kept valid by `f`, as required to the caller.
+        let mut guard = unsafe { crate::guard::OwnedInnerGuard::<S>::new(custom, inner) };
+        f(guard.inner_mut())
+    }
+}
+
 /// A trait to provide types and features for a custom slice type.
 ///
 /// # Safety
@@ -14,6 +204,7 @@ mod macros;
 ///
 /// * `Self::validate()` always returns the same result for the same input.
 /// * `Self::Inner` is the only non-zero type field of `Self::Custom`.
+///     + Other fields (if any) should be zero-sized and 1-byte aligned, such as `PhantomData<T>`.
 /// * `Self::Custom` has attribute `#[repr(transparent)]` or `#[repr(C)]`.
 ///
 /// If any of the condition is not met, use of methods may cause undefined behavior.
@@ -73,6 +264,36 @@ pub trait SliceSpec {
     /// Returns `Ok(())` if the value is valid (and safely convertible to `Self::Custom`.
     /// Returns `Err(_)` if the validation failed.
     fn validate(s: &Self::Inner) -> Result<(), Self::Error>;
+    /// Validates the inner slice, and returns all the violations found.
+    ///
+    /// Returns an empty vector if the value is valid.
+    /// This is used by `{ fn try_from_reporting }` of [`impl_methods_for_slice!`], for use cases
+    /// such as form validation and linting which need complete diagnostics in one pass.
+    ///
+    /// The default implementation returns the error of [`validate`], if any.
+    /// Specs can override this to continue the validation after the first violation.
+    ///
+    /// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+    /// [`validate`]: #tymethod.validate
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn validate_all(s: &Self::Inner) -> Vec<Self::Error> {
+        Self::validate(s).err().into_iter().collect()
+    }
+    /// Validates the inner slice, and returns the proof of the validity.
+    ///
+    /// The returned [`Validated`] can be converted into the custom slice types without `unsafe`
+    /// codes, even in generic codes which the macros do not cover.
+    /// `{ fn from_proof }` of [`impl_methods_for_slice!`] and [`impl_methods_for_owned_slice!`]
+    /// take the proof.
+    ///
+    /// [`Validated`]: struct.Validated.html
+    /// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+    /// [`impl_methods_for_owned_slice!`]: macro.impl_methods_for_owned_slice.html
+    #[inline]
+    fn check(s: &Self::Inner) -> Result<Validated<'_, Self>, Self::Error> {
+        Validated::new(s)
+    }
     /// Converts a reference to the custom slice into a reference to the inner slice type.
     fn as_inner(s: &Self::Custom) -> &Self::Inner;
     /// Converts a mutable reference to the custom slice into a mutable reference to the inner slice
@@ -86,6 +307,8 @@ pub trait SliceSpec {
     ///
     /// * `Self::validate(s)` returns `Ok(())`.
     /// * `Self::Inner` is the only non-zero type field of `Self::Custom`.
+    ///     + Other fields (if any) should be zero-sized and 1-byte aligned, such as
+    ///       `PhantomData<T>`.
     /// * `Self::Custom` has attribute `#[repr(transparent)]` or `#[repr(C)]`.
     ///
     /// If any of the condition is not met, this function may cause undefined behavior.
@@ -100,6 +323,193 @@ pub trait SliceSpec {
     unsafe fn from_inner_unchecked_mut(s: &mut Self::Inner) -> &mut Self::Custom;
 }
 
+/// A marker trait for slice specs whose validity is closed under sub-slicing.
+///
+/// If a spec implements this trait, any sub-slice of a valid inner slice is also valid.
+/// Here, "sub-slice" means a contiguous range of the inner slice which is itself a valid value of
+/// the inner slice type (for example, a sub-slice of `str` starts and ends at char boundaries).
+///
+/// For example, "ASCII string" satisfies this, but "non-empty slice" and "sorted slice with
+/// specific prefix" do not.
+///
+/// # Safety
+///
+/// To avoid undefined behavior, users are responsible to let implementations satisfy the
+/// condition below:
+///
+/// * If `Self::validate(s)` returns `Ok(())`, `Self::validate(sub)` also returns `Ok(())` for any
+///   sub-slice `sub` of `s`.
+///
+/// If the condition is not met, use of methods generated with this trait may cause undefined
+/// behavior.
+pub unsafe trait SliceClosedSpec: SliceSpec {}
+
+/// A marker trait for slice specs whose validity is closed under concatenation.
+///
+/// If a spec implements this trait, concatenation of valid inner slices is also valid.
+///
+/// For example, "ASCII string" and "identifier not starting with a digit" satisfy this, but
+/// "string with at most 8 characters" and "sorted slice" do not.
+///
+/// This is used by the methods generated by [`impl_methods_for_owned_slice!`] which rebuild the
+/// value from the valid pieces without validation, such as `{ fn replace }`.
+///
+/// # Safety
+///
+/// To avoid undefined behavior, users are responsible to let implementations satisfy the
+/// condition below:
+///
+/// * If `Self::validate(a)` and `Self::validate(b)` return `Ok(())`, `Self::validate(ab)` also
+///   returns `Ok(())` where `ab` is the concatenation of `a` and `b`.
+///
+/// If the condition is not met, use of methods generated with this trait may cause undefined
+/// behavior.
+///
+/// [`impl_methods_for_owned_slice!`]: macro.impl_methods_for_owned_slice.html
+pub unsafe trait ConcatClosedSpec: SliceSpec {}
+
+/// A marker trait for slice specs whose validity does not depend on the order of the elements.
+///
+/// If a spec implements this trait, reordering and removing the elements of a valid inner slice
+/// keeps it valid.
+///
+/// For example, "slice of ASCII bytes" and "slice without duplicate elements" satisfy this, but
+/// "sorted slice" and "non-empty slice" do not.
+/// Specs implementing [`ElementSpec`] always satisfy this.
+///
+/// This is used by the methods generated by [`impl_methods_for_owned_slice!`] which reorder or
+/// remove the elements without validation, such as `{ fn sort }` and `{ fn retain }`.
+///
+/// # Safety
+///
+/// To avoid undefined behavior, users are responsible to let implementations satisfy the
+/// condition below:
+///
+/// * If `Self::validate(s)` returns `Ok(())`, `Self::validate(t)` also returns `Ok(())` for any
+///   slice `t` which consists of some (or none) of the elements of `s` in any order.
+///     + In particular, an empty slice is always valid.
+///
+/// If the condition is not met, use of methods generated with this trait may cause undefined
+/// behavior.
+///
+/// [`ElementSpec`]: trait.ElementSpec.html
+/// [`impl_methods_for_owned_slice!`]: macro.impl_methods_for_owned_slice.html
+pub unsafe trait OrderInsensitiveSpec: SliceSpec {}
+
+/// A trait for slice specs which can revalidate the slice by checking only around the edit.
+///
+/// This is used by `{ fn try_modify(region) }` of [`impl_methods_for_slice!`] instead of
+/// [`SliceSpec::validate`], to avoid validating the whole large slice for each small edit.
+///
+/// # Safety
+///
+/// To avoid undefined behavior, users are responsible to let implementations satisfy the
+/// condition below:
+///
+/// * `Self::revalidate_region(s, edited)` returns `Ok(())` if and only if `Self::validate(s)`
+///   returns `Ok(())`, for any slice `s` which was valid before the edit within `edited`.
+///
+/// If the condition is not met, use of methods generated with this trait may cause undefined
+/// behavior.
+///
+/// [`SliceSpec::validate`]: trait.SliceSpec.html#tymethod.validate
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+pub unsafe trait RevalidateRegionSpec: SliceSpec {
+    /// Revalidates the inner slice after the edit within the given region.
+    ///
+    /// `s` is the whole inner slice after the edit, and it was valid before the edit.
+    /// `edited` is the range of the edited region, in the unit of indices of the inner slice
+    /// (elements for `[T]`, and bytes for `str`).
+    fn revalidate_region(s: &Self::Inner, edited: Range<usize>) -> Result<(), Self::Error>;
+}
+
+/// A trait for slice specs whose validity is defined per element.
+///
+/// If a spec implements this trait, a slice is valid if and only if every element of it is valid.
+/// This allows the owned custom types to validate only the new elements on `push`, `insert`, and
+/// `extend` operations.
+/// See `{ fn try_push }`, `{ fn try_insert }`, and `{ fn try_extend }` of
+/// [`impl_methods_for_owned_slice!`].
+///
+/// # Safety
+///
+/// To avoid undefined behavior, users are responsible to let implementations satisfy the
+/// condition below:
+///
+/// * `Self::validate(s)` returns `Ok(())` if and only if `Self::validate_element(elem)` returns
+///   `Ok(())` for every element `elem` of `s`.
+///     + In particular, an empty slice is always valid.
+///
+/// If the condition is not met, use of methods generated with this trait may cause undefined
+/// behavior.
+///
+/// [`impl_methods_for_owned_slice!`]: macro.impl_methods_for_owned_slice.html
+pub unsafe trait ElementSpec: SliceClosedSpec {
+    /// Element type.
+    type Element;
+
+    /// Validates the element.
+    fn validate_element(elem: &Self::Element) -> Result<(), Self::Error>;
+}
+
+/// A marker trait for slice specs which guarantee that the elements are sorted.
+///
+/// If a spec implements this trait, elements of any valid inner slice are sorted in ascending
+/// order by `Ord` of the element type.
+///
+/// This is used by the methods generated by [`impl_methods_for_slice!`] which rely on the order,
+/// such as `{ fn contains_sorted }`.
+/// If the condition is not met, such methods return unspecified results, but this does not cause
+/// undefined behavior.
+///
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+pub trait SortedSliceSpec: SliceSpec {}
+
+/// A marker trait for slice specs which guarantee that valid slices are not empty.
+///
+/// This is used by the methods generated by [`impl_methods_for_slice!`] which return the elements
+/// without `Option`, such as `{ fn first }` and `{ fn last }`.
+/// If the condition is not met, such methods panic for empty slices, but this does not cause
+/// undefined behavior.
+///
+/// [`NonEmpty`] combinator implements this trait.
+///
+/// [`NonEmpty`]: struct.NonEmpty.html
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+pub trait NonEmptySpec: SliceSpec {}
+
+/// A marker trait to acknowledge the safety conditions of a spec at the impl site.
+///
+/// [`SliceSpec`], [`OwnedSliceSpec`], and [`ValueSpec`] are safe to implement, though the
+/// soundness of the generated codes rely on the safety conditions described in their documents.
+/// By implementing this unsafe trait, users explicitly declare that the spec satisfies them.
+///
+/// Macros check this trait only when `Check { Trusted };` is specified.
+/// This is opt-in for now, to let existing users migrate gradually.
+///
+/// # Safety
+///
+/// To avoid undefined behavior, users are responsible to let implementations satisfy the
+/// condition below:
+///
+/// * Safety conditions for the spec traits implemented by `Self` are satisfied.
+///     + For [`SliceSpec`], they are the conditions described at the trait and at
+///       [`SliceSpec::from_inner_unchecked`].
+///     + For [`OwnedSliceSpec`], they are the conditions described at the trait.
+///       This includes the conditions for `Self::SliceSpec`, so `Self::SliceSpec` should also
+///       implement this trait.
+///     + For [`ValueSpec`], they are the conditions described at
+///       [`ValueSpec::from_inner_unchecked`].
+///
+/// If the condition is not met, use of the generated codes may cause undefined behavior.
+///
+/// [`SliceSpec`]: trait.SliceSpec.html
+/// [`SliceSpec::from_inner_unchecked`]: trait.SliceSpec.html#tymethod.from_inner_unchecked
+/// [`OwnedSliceSpec`]: trait.OwnedSliceSpec.html
+/// [`ValueSpec`]: trait.ValueSpec.html
+/// [`ValueSpec::from_inner_unchecked`]: trait.ValueSpec.html#tymethod.from_inner_unchecked
+pub unsafe trait TrustedSpec {}
+
 /// A trait to provide types and features for an owned custom slice type.
 ///
 /// # Safety
@@ -184,11 +594,6 @@ pub trait SliceSpec {
 ///     }
 ///
 ///     #[inline]
-///     fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
-///         &mut s.0
-///     }
-///
-///     #[inline]
 ///     fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
 ///         s
 ///     }
@@ -203,6 +608,14 @@ pub trait SliceSpec {
 ///         s.0
 ///     }
 /// }
+///
+/// // `String` provides mutable access to `str`.
+/// impl validated_slice::OwnedSliceMutSpec for AsciiStringSpec {
+///     #[inline]
+///     fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
+///         &mut s.0
+///     }
+/// }
 /// ```
 pub trait OwnedSliceSpec {
     /// Custom owned slice type.
@@ -224,8 +637,6 @@ pub trait OwnedSliceSpec {
     fn convert_validation_error(e: Self::SliceError, v: Self::Inner) -> Self::Error;
     /// Returns the borrowed inner slice for the given reference to a custom owned slice.
     fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner;
-    /// Returns the borrowed inner slice for the given mutable reference to a custom owned slice.
-    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner;
     /// Returns the borrowed inner slice for the given reference to owned inner slice.
     fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner;
     /// Creates a reference to the custom slice type without any validation.
@@ -242,3 +653,287 @@ pub trait OwnedSliceSpec {
     /// Returns the inner value with its ownership.
     fn into_inner(s: Self::Custom) -> Self::Inner;
 }
+
+/// A trait for owned slice specs which provide mutable access to the inner slice.
+///
+/// This is separated from [`OwnedSliceSpec`], so that immutable owned inner types such as
+/// `Arc<str>`, `Rc<[u8]>`, and `CString` can be used.
+///
+/// This is required by the mutable targets of [`impl_std_traits_for_owned_slice!`], such as
+/// `{ AsMut<{SliceCustom}> }`, `{ BorrowMut<{SliceCustom}> }`, and `{ DerefMut }`, and by
+/// [`MutationHookSpec`].
+///
+/// See [`OwnedSliceSpec`] for an example.
+///
+/// [`MutationHookSpec`]: trait.MutationHookSpec.html
+/// [`OwnedSliceSpec`]: trait.OwnedSliceSpec.html
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+pub trait OwnedSliceMutSpec: OwnedSliceSpec {
+    /// Returns the borrowed inner slice for the given mutable reference to a custom owned slice.
+    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner;
+}
+
+/// A trait for owned slice specs which convert slice validation errors without invalid values.
+///
+/// [`OwnedSliceSpec::convert_validation_error`] requires the invalid owned value, but it is not
+/// available in some contexts, such as `{ From<{SliceError}> for {Error} }` target of
+/// [`impl_std_traits_for_owned_slice!`].
+///
+/// [`OwnedSliceSpec::convert_validation_error`]: trait.OwnedSliceSpec.html#tymethod.convert_validation_error
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+pub trait ConvertSliceErrorSpec: OwnedSliceSpec {
+    /// Converts a borrowed slice validation error into an owned slice validation error.
+    fn convert_slice_error(e: Self::SliceError) -> Self::Error;
+}
+
+/// A trait to observe mutations of a custom owned slice type.
+///
+/// This is useful for owned types which keep auxiliary derived state (such as caches) alongside
+/// the inner value.
+///
+/// Hooks are invoked by the generated mutable APIs which go through [`MutationGuard`], such as
+/// `{ fn mutate }` of [`impl_methods_for_owned_slice!`].
+/// Note that hooks are not invoked by `AsMut`, `BorrowMut`, and `DerefMut` impls.
+///
+/// [`MutationGuard`]: struct.MutationGuard.html
+/// [`impl_methods_for_owned_slice!`]: macro.impl_methods_for_owned_slice.html
+pub trait MutationHookSpec: OwnedSliceMutSpec {
+    /// Called before the value is mutated.
+    #[inline]
+    fn before_mutation(_s: &mut Self::Custom) {}
+
+    /// Called after the value is mutated.
+    ///
+    /// The value is valid here, since the mutation is done through the borrowed custom slice.
+    #[inline]
+    fn after_mutation(_s: &mut Self::Custom) {}
+}
+
+/// A trait for compile-time validation contexts.
+///
+/// Some validity depends on the context the value is used in, such as strings escaped for HTML
+/// and for SQL. Such a context can be expressed as a zero-sized type implementing this trait,
+/// and a generic spec can consult it in [`SliceSpec::validate`].
+///
+/// The macros can generate impls generic over the context, by declaring it with the bound as
+/// `tags: [C: ValidationContext<str>],` field of `Spec { .. }`.
+///
+/// # Examples
+///
+/// ```
+/// use std::marker::PhantomData;
+/// use validated_slice::{SliceSpec, ValidationContext};
+///
+/// /// Context for HTML text.
+/// pub enum Html {}
+///
+/// impl ValidationContext<str> for Html {
+///     type Error = char;
+///
+///     fn validate(s: &str) -> Result<(), Self::Error> {
+///         match s.chars().find(|c| matches!(c, '<' | '>' | '"')) {
+///             Some(c) => Err(c),
+///             None => Ok(()),
+///         }
+///     }
+/// }
+///
+/// /// String escaped for the context `C`.
+/// #[repr(transparent)]
+/// pub struct Escaped<C>(PhantomData<C>, str);
+///
+/// pub struct EscapedSpec<C>(PhantomData<C>);
+///
+/// impl<C: ValidationContext<str>> SliceSpec for EscapedSpec<C> {
+///     type Custom = Escaped<C>;
+///     type Inner = str;
+///     type Error = C::Error;
+///
+///     fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+///         C::validate(s)
+///     }
+///
+///     validated_slice::impl_slice_spec_methods! {
+///         field=1;
+///         methods=[
+///             as_inner,
+///             as_inner_mut,
+///             from_inner_unchecked,
+///             from_inner_unchecked_mut,
+///         ];
+///     }
+/// }
+///
+/// validated_slice::impl_std_traits_for_slice! {
+///     Spec {
+///         tags: [C: ValidationContext<str>],
+///         spec: EscapedSpec<C>,
+///         custom: Escaped<C>,
+///         inner: str,
+///         error: C::Error,
+///     };
+///     { TryFrom<&{Inner}> for &{Custom} };
+///     { Deref<Target = {Inner}> };
+/// }
+///
+/// use std::convert::TryFrom;
+///
+/// assert!(<&Escaped<Html>>::try_from("a &amp; b").is_ok());
+/// assert_eq!(<&Escaped<Html>>::try_from("<b>").map(|s| &**s), Err('<'));
+/// ```
+///
+/// [`SliceSpec::validate`]: trait.SliceSpec.html#tymethod.validate
+pub trait ValidationContext<T: ?Sized> {
+    /// Validation error.
+    type Error;
+
+    /// Validates the value in this context.
+    fn validate(s: &T) -> Result<(), Self::Error>;
+}
+
+/// A trait to normalize possibly invalid slices into valid owned values.
+///
+/// This is used by `{ fn from_normalized }` of [`impl_methods_for_owned_slice!`], which borrows
+/// the slice if it is already valid, and allocates the normalized value only if it is not.
+///
+/// Implementations can fix the input lossily (such as replacing invalid characters), or
+/// canonicalize it (such as lowercasing).
+///
+/// [`impl_methods_for_owned_slice!`]: macro.impl_methods_for_owned_slice.html
+pub trait NormalizeSpec: OwnedSliceSpec {
+    /// Normalizes the slice which is rejected by the validation.
+    ///
+    /// The returned value is validated by callers, so the normalization can give up by returning
+    /// an invalid value.
+    fn normalize(s: &Self::SliceInner) -> Self::Inner;
+}
+
+/// A trait to generate random valid values for a custom owned slice type.
+///
+/// This is used by `{ rand::Distribution }` target of [`impl_std_traits_for_owned_slice!`].
+///
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+#[cfg(feature = "rand")]
+pub trait GenerateSpec: OwnedSliceSpec {
+    /// Generates a random owned inner value which is valid as `Self::Custom`.
+    ///
+    /// The generated value is validated by callers, and an invalid value causes a panic.
+    fn generate<R: rand::Rng + ?Sized>(rng: &mut R) -> Self::Inner;
+}
+
+/// A trait to annotate JSON schemas of custom slice types.
+///
+/// This is used by `{ JsonSchema(spec) }` target of [`impl_std_traits_for_slice!`] and
+/// [`impl_std_traits_for_owned_slice!`], which add the annotations to the schema of the inner
+/// type.
+/// The owned slice macro uses the impl for the spec of the borrowed slice type.
+///
+/// # Examples
+///
+/// ```
+/// # use validated_slice::SliceSpec;
+/// # pub struct AsciiStr(str);
+/// # enum AsciiStrSpec {}
+/// # impl SliceSpec for AsciiStrSpec {
+/// #     type Custom = AsciiStr;
+/// #     type Inner = str;
+/// #     type Error = ();
+/// #     fn validate(s: &str) -> Result<(), ()> {
+/// #         if s.is_ascii() { Ok(()) } else { Err(()) }
+/// #     }
+/// #     validated_slice::impl_slice_spec_methods! {
+/// #         field=0;
+/// #         methods=[as_inner, as_inner_mut, from_inner_unchecked, from_inner_unchecked_mut];
+/// #     }
+/// # }
+/// impl validated_slice::JsonSchemaSpec for AsciiStrSpec {
+///     const PATTERN: Option<&'static str> = Some("^[\\x00-\\x7f]*$");
+/// }
+/// ```
+///
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+#[cfg(feature = "schemars")]
+pub trait JsonSchemaSpec: SliceSpec {
+    /// Value of `format` annotation.
+    const FORMAT: Option<&'static str> = None;
+    /// Value of `pattern` annotation.
+    ///
+    /// This should be a regular expression which matches exactly the valid values.
+    const PATTERN: Option<&'static str> = None;
+}
+
+/// A trait to provide types and features for a custom sized value type.
+///
+/// This is a counterpart of [`SliceSpec`] for sized newtypes such as `Port(u16)` and
+/// `Latitude(f64)`.
+///
+/// # Examples
+///
+/// ```
+/// /// Non-zero TCP/UDP port number.
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// pub struct Port(u16);
+///
+/// /// Port number validation error.
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// pub struct ZeroPortError;
+///
+/// enum PortSpec {}
+///
+/// impl validated_slice::ValueSpec for PortSpec {
+///     type Custom = Port;
+///     type Inner = u16;
+///     type Error = ZeroPortError;
+///
+///     fn validate(v: &Self::Inner) -> Result<(), Self::Error> {
+///         if *v == 0 {
+///             return Err(ZeroPortError);
+///         }
+///         Ok(())
+///     }
+///
+///     #[inline]
+///     fn as_inner(v: &Self::Custom) -> &Self::Inner {
+///         &v.0
+///     }
+///
+///     #[inline]
+///     unsafe fn from_inner_unchecked(v: Self::Inner) -> Self::Custom {
+///         Port(v)
+///     }
+///
+///     #[inline]
+///     fn into_inner(v: Self::Custom) -> Self::Inner {
+///         v.0
+///     }
+/// }
+/// ```
+///
+/// [`SliceSpec`]: trait.SliceSpec.html
+pub trait ValueSpec {
+    /// Custom value type.
+    type Custom;
+    /// Inner value type of `Self::Custom`.
+    type Inner;
+    /// Validation error type.
+    type Error;
+
+    /// Validates the inner value to check if the value is valid as the custom value type value.
+    ///
+    /// Returns `Ok(())` if the value is valid (and safely convertible to `Self::Custom`.
+    /// Returns `Err(_)` if the validation failed.
+    fn validate(v: &Self::Inner) -> Result<(), Self::Error>;
+    /// Returns a reference to the inner value.
+    fn as_inner(v: &Self::Custom) -> &Self::Inner;
+    /// Creates the custom value without any validation.
+    ///
+    /// # Safety
+    ///
+    /// `Self::validate(v)` should return `Ok(())`.
+    ///
+    /// Users and generated codes may rely on the validity of custom values.
+    unsafe fn from_inner_unchecked(v: Self::Inner) -> Self::Custom;
+    /// Returns the inner value with its ownership.
+    fn into_inner(v: Self::Custom) -> Self::Inner;
+}
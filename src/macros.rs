@@ -1,4 +1,25 @@
 //! Macros.
 
+mod assert;
+mod base64;
+mod benchkit;
 mod borrowed;
+mod bumpalo;
+mod cow;
+mod default_std;
+mod defmt;
+mod diesel;
+mod equivalent;
+mod error;
+mod nom;
 mod owned;
+mod paranoid;
+mod rand;
+mod regex;
+mod rkyv;
+mod schemars;
+mod serde;
+mod sqlx;
+mod testkit;
+mod ufmt;
+mod value;
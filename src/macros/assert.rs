@@ -0,0 +1,112 @@
+//! Macros for compile-time assertions.
+
+/// Asserts at compile time that the given types implement (or do not implement) the traits.
+///
+/// This is intended to lock in auto trait guarantees such as `Send`, `Sync`, and `Unpin` of the
+/// custom types, so that changes of the inner types do not silently break them.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// #[repr(transparent)]
+/// pub struct AsciiStr(str);
+///
+/// pub struct AsciiString(String);
+///
+/// pub struct RcAsciiStr(Rc<AsciiStr>);
+///
+/// validated_slice::assert_auto_traits! {
+///     AsciiStr: Send + Sync + Unpin;
+///     AsciiString: Send + Sync + Unpin + std::panic::UnwindSafe;
+///     RcAsciiStr: !Send + !Sync;
+/// }
+/// ```
+///
+/// Violation of the assertions causes a compile error.
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+///
+/// pub struct RcAsciiStr(Rc<str>);
+///
+/// validated_slice::assert_auto_traits! {
+///     RcAsciiStr: Send;
+/// }
+/// ```
+///
+/// ```compile_fail
+/// pub struct AsciiString(String);
+///
+/// validated_slice::assert_auto_traits! {
+///     AsciiString: !Send;
+/// }
+/// ```
+///
+/// # Usage
+///
+/// Each assertion is specified by `Type: Trait + !Trait;` format.
+/// Traits are specified by paths, and `!` means the type should not implement the trait.
+///
+/// Types should not have generic parameters.
+/// For types with lifetime parameters, specify concrete lifetimes such as `MyStr<'static>`.
+#[macro_export]
+macro_rules! assert_auto_traits {
+    () => {};
+    ($ty:ty: $($rest:tt)*) => {
+        $crate::assert_auto_traits! {
+            @entry[$ty]; bounds=[]; rest=[$($rest)*];
+        }
+    };
+
+    // Splits the entries at `;`.
+    (@entry[$ty:ty]; bounds=[$($bounds:tt)*]; rest=[; $($rest:tt)*];) => {
+        $crate::assert_auto_traits! {
+            @bound[$ty]; $($bounds)*
+        }
+        $crate::assert_auto_traits! { $($rest)* }
+    };
+    (@entry[$ty:ty]; bounds=[$($bounds:tt)*]; rest=[];) => {
+        $crate::assert_auto_traits! {
+            @bound[$ty]; $($bounds)*
+        }
+    };
+    (@entry[$ty:ty]; bounds=[$($bounds:tt)*]; rest=[$first:tt $($rest:tt)*];) => {
+        $crate::assert_auto_traits! {
+            @entry[$ty]; bounds=[$($bounds)* $first]; rest=[$($rest)*];
+        }
+    };
+
+    // Splits the bounds at `+`.
+    (@bound[$ty:ty]; ! $($trait:ident)::+ $(+ $($rest:tt)*)?) => {
+        const _: () = {
+            trait AmbiguousIfImpl<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+            #[allow(dead_code)]
+            struct Invalid;
+            impl<T: ?Sized + $($trait)::+> AmbiguousIfImpl<Invalid> for T {}
+
+            // If `$ty` implements the trait, the type parameter of `AmbiguousIfImpl` cannot be
+            // inferred, and this causes a compile error.
+            #[allow(dead_code)]
+            fn assert_not_impl() {
+                let _ = <$ty as AmbiguousIfImpl<_>>::some_item;
+            }
+        };
+        $($crate::assert_auto_traits! { @bound[$ty]; $($rest)* })?
+    };
+    (@bound[$ty:ty]; $($trait:ident)::+ $(+ $($rest:tt)*)?) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_impl<T: ?Sized + $($trait)::+>() {}
+            #[allow(dead_code)]
+            fn check() {
+                assert_impl::<$ty>();
+            }
+        };
+        $($crate::assert_auto_traits! { @bound[$ty]; $($rest)* })?
+    };
+}
@@ -0,0 +1,32 @@
+//! Macros for `base64` crate integration.
+
+/// Implements methods to display the custom slice in base64.
+///
+/// This is used internally by `impl_methods_for_slice!` macro.
+#[cfg(feature = "base64")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_base64 {
+    (@display_base64; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty); $vis:vis) => {
+        impl $custom {
+            /// Returns a wrapper to display the bytes in base64 with the standard alphabet.
+            #[inline]
+            $vis fn display_base64(&self) -> $crate::Base64Display<'_>
+            where
+                $inner: $core::convert::AsRef<[u8]>,
+            {
+                $crate::Base64Display::new(<$spec as $crate::SliceSpec>::as_inner(self).as_ref())
+            }
+        }
+    };
+}
+
+/// Emits a compile error, because `base64` feature is disabled.
+#[cfg(not(feature = "base64"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_base64 {
+    ($($rest:tt)*) => {
+        compile_error!("`base64` methods require `base64` feature of `validated-slice` crate");
+    };
+}
@@ -0,0 +1,184 @@
+//! Macros to benchmark specs.
+
+/// Generates a `criterion` benchmark function for the spec with valid samples.
+///
+/// This requires `criterion` crate in the dependencies of the caller (usually
+/// `[dev-dependencies]`), and generates a function `fn name(c: &mut criterion::Criterion)`
+/// which can be registered by `criterion::criterion_group!`.
+///
+/// The generated function has a benchmark group named after the spec, with the benchmarks below:
+///
+/// * `validate`
+///     + `SliceSpec::validate` for all the samples.
+///     + The throughput is reported in bytes of the samples.
+/// * `try_from`
+///     + `TryFrom<&{Inner}> for &{Custom}` for all the samples.
+/// * `eq`
+///     + `PartialEq` of the custom slice type for all the pairs of the samples.
+/// * `to_owned` (only when `owned = OwnedSpec` is specified)
+///     + `ToOwned` of the custom slice type for all the samples.
+/// * `owned_try_from` (only when `owned = OwnedSpec` is specified)
+///     + `TryFrom<{Inner}> for {Custom}` of the owned type for all the samples.
+///     + This includes the allocation of the owned inner values.
+///
+/// # Examples
+///
+/// ```ignore
+/// // benches/ascii_str.rs (with `harness = false` in `[[bench]]` section of `Cargo.toml`)
+/// validated_slice::spec_benches!(
+///     ascii_str,
+///     AsciiStrSpec,
+///     samples = ["", "hello", "The quick brown fox jumps over the lazy dog"],
+///     owned = AsciiStringSpec,
+/// );
+///
+/// criterion::criterion_group!(benches, ascii_str);
+/// criterion::criterion_main!(benches);
+/// ```
+///
+/// # Usage
+///
+/// ```text
+/// validated_slice::spec_benches!(
+///     function_name,
+///     SliceSpecType,
+///     samples = [valid_sample, ...],
+///     // Optional.
+///     owned = OwnedSliceSpecType,
+/// );
+/// ```
+///
+/// Samples are expressions of type `&{Inner}`, as [`spec_tests!`].
+/// All the samples should be valid.
+///
+/// The generated benchmarks require the types below:
+///
+/// * `{Inner}: Debug`.
+/// * `{Custom}: PartialEq`.
+/// * `TryFrom<&{Inner}> for &{Custom}`.
+/// * When `owned = OwnedSpec` is specified:
+///     + `{SliceInner}` of the owned spec should be same as `{Inner}` of the spec.
+///     + `{Custom}: ToOwned<Owned = {Custom of the owned spec}>`.
+///     + `{Inner}: From<&{SliceInner}>` for the owned spec.
+///     + `TryFrom<{Inner}> for {Custom}` for the owned spec.
+///
+/// [`spec_tests!`]: macro.spec_tests.html
+#[macro_export]
+macro_rules! spec_benches {
+    (
+        $name:ident,
+        $spec:ty,
+        samples = [$($sample:expr),* $(,)?]
+        $(,)?
+    ) => {
+        $crate::spec_benches! {
+            @impl; $name; $spec; samples=[$($sample),*]; |_samples, _group| {};
+        }
+    };
+    (
+        $name:ident,
+        $spec:ty,
+        samples = [$($sample:expr),* $(,)?],
+        owned = $owned:ty
+        $(,)?
+    ) => {
+        $crate::spec_benches! {
+            @impl; $name; $spec; samples=[$($sample),*]; |samples, group| {
+                group.bench_function("to_owned", |b| {
+                    b.iter(|| {
+                        for &(_, custom) in samples {
+                            let owned: <$owned as $crate::OwnedSliceSpec>::Custom =
+                                ::core::hint::black_box(
+                                    <<$spec as $crate::SliceSpec>::Custom as ::std::borrow::ToOwned>::to_owned(custom),
+                                );
+                            ::core::mem::drop(owned);
+                        }
+                    })
+                });
+                group.bench_function("owned_try_from", |b| {
+                    b.iter(|| {
+                        for &(s, _) in samples {
+                            let inner = <<$owned as $crate::OwnedSliceSpec>::Inner as ::core::convert::From<
+                                &<$owned as $crate::OwnedSliceSpec>::SliceInner,
+                            >>::from(::core::hint::black_box(s));
+                            let _ = ::core::hint::black_box(
+                                <<$owned as $crate::OwnedSliceSpec>::Custom as ::core::convert::TryFrom<
+                                    <$owned as $crate::OwnedSliceSpec>::Inner,
+                                >>::try_from(inner)
+                                .is_ok(),
+                            );
+                        }
+                    })
+                });
+            };
+        }
+    };
+
+    (
+        @impl; $name:ident; $spec:ty; samples=[$($sample:expr),*];
+        |$samples:pat_param, $group:pat_param| $owned_benches:block;
+    ) => {
+        /// Benchmarks the spec with the samples.
+        pub fn $name(c: &mut ::criterion::Criterion) {
+            let samples: &[(
+                &<$spec as $crate::SliceSpec>::Inner,
+                &<$spec as $crate::SliceSpec>::Custom,
+            )] = &[$({
+                let s: &<$spec as $crate::SliceSpec>::Inner = $sample;
+                let custom = <&<$spec as $crate::SliceSpec>::Custom as ::core::convert::TryFrom<
+                    &<$spec as $crate::SliceSpec>::Inner,
+                >>::try_from(s)
+                .ok()
+                .unwrap_or_else(|| panic!("should be valid: {:?}", s));
+                (s, custom)
+            }),*];
+            let bytes: usize = samples.iter().map(|&(s, _)| ::core::mem::size_of_val(s)).sum();
+
+            let mut group = c.benchmark_group(stringify!($spec));
+            group.throughput(::criterion::Throughput::Bytes(bytes as u64));
+            group.bench_function("validate", |b| {
+                b.iter(|| {
+                    for &(s, _) in samples {
+                        let _ = ::core::hint::black_box(
+                            <$spec as $crate::SliceSpec>::validate(::core::hint::black_box(s))
+                                .is_ok(),
+                        );
+                    }
+                })
+            });
+            group.bench_function("try_from", |b| {
+                b.iter(|| {
+                    for &(s, _) in samples {
+                        let _ = ::core::hint::black_box(
+                            <&<$spec as $crate::SliceSpec>::Custom as ::core::convert::TryFrom<
+                                &<$spec as $crate::SliceSpec>::Inner,
+                            >>::try_from(::core::hint::black_box(s))
+                            .is_ok(),
+                        );
+                    }
+                })
+            });
+            group.throughput(::criterion::Throughput::Elements(
+                (samples.len() * samples.len()) as u64,
+            ));
+            group.bench_function("eq", |b| {
+                b.iter(|| {
+                    for &(_, lhs) in samples {
+                        for &(_, rhs) in samples {
+                            let _ = ::core::hint::black_box(
+                                ::core::hint::black_box(lhs) == ::core::hint::black_box(rhs),
+                            );
+                        }
+                    }
+                })
+            });
+            group.throughput(::criterion::Throughput::Bytes(bytes as u64));
+            {
+                let $samples = samples;
+                let $group = &mut group;
+                $owned_benches
+            }
+            group.finish();
+        }
+    };
+}
@@ -50,6 +50,32 @@
 /// List methods to implement automatically.
 /// `validate` is not supported and should be manually implemented by the user.
 ///
+/// ## Inner types
+///
+/// The inner type can be any dynamically sized type whose pointer metadata is same as that of the
+/// custom slice type, such as `[T]`, `str`, `CStr`, `OsStr`, and `Path`.
+///
+/// ## Marker fields
+///
+/// The custom slice type can have zero-sized marker fields in addition to the inner slice field,
+/// such as `struct Tagged<T>(PhantomData<T>, str);`.
+/// In this case, specify the inner slice field as `field` (`field=1;` for this example).
+///
+/// The custom slice type can be generic over such marker types, and the spec can implement
+/// [`SliceSpec`] generically (e.g. `impl<T> SliceSpec for TaggedSpec<T>`).
+/// The generated pointer casts are valid for any `T`, because the marker fields do not change the
+/// layout and the pointer metadata.
+///
+/// Note that the marker fields should be zero-sized and 1-byte aligned, and `#[repr(transparent)]`
+/// is recommended because the compiler checks this condition for it.
+///
+/// ## Enums
+///
+/// Custom slice types cannot be enums, even single-variant `#[repr(transparent)]` ones, because
+/// enum variants cannot have dynamically sized fields such as `str` and `[T]`.
+/// To hide the inner slice, use a struct with a private field, which also prevents users from
+/// constructing the value without validation.
+///
 /// [`SliceSpec`]: trait.SliceSpec.html
 #[macro_export]
 macro_rules! impl_slice_spec_methods {
@@ -196,6 +222,8 @@ macro_rules! impl_slice_spec_methods {
 /// ## Core and alloc
 ///
 /// For `no_std` use, the macro uses custom `core` and `alloc` crate if given.
+/// If `Std { .. };` is omitted, the macro uses `core` and `alloc` crates re-exported from this
+/// crate (`alloc` requires `alloc` feature), regardless of the environment of the caller.
 /// You can support both nostd and non-nostd environment as below:
 ///
 /// ```
@@ -255,6 +283,69 @@ macro_rules! impl_slice_spec_methods {
 /// When you don't need `alloc` crate on nostd build, value of `alloc` field is not used.
 /// Simply specify `alloc: alloc,` or something.
 ///
+/// ## Omitting spec fields
+///
+/// `custom`, `inner`, and `error` fields can be omitted as `Spec { spec: MyStrSpec };`.
+/// In this case, they are resolved through the associated types of [`SliceSpec`] trait, such as
+/// `<MyStrSpec as SliceSpec>::Custom`.
+/// Note that the spec type should be as visible as the custom type in this case, because
+/// associated types of the generated trait impls (such as `Deref::Target`) refer to it.
+///
+/// ## Lifetime parameters
+///
+/// If the custom type has lifetime parameters, declare them by `lifetimes` field at the head of
+/// `Spec { .. }`.
+/// They are added to the generic parameters of every generated impl.
+///
+/// ```ignore
+/// validated_slice::impl_std_traits_for_slice! {
+///     Spec {
+///         lifetimes: ['a],
+///         spec: WordListSpec<'a>,
+///         custom: WordList<'a>,
+///         inner: [&'a str],
+///         error: WordError,
+///     };
+///     { TryFrom<&{Inner}> for &{Custom} };
+///     { Deref<Target = {Inner}> };
+/// }
+/// ```
+///
+/// In this case, spec fields cannot be omitted, because lifetimes used only through associated
+/// types of the spec type are not constrained by the impls.
+/// Integrations with optional features (such as `Diesel` and `Rand` targets) do not support
+/// lifetime parameters.
+///
+/// ## Type tag parameters
+///
+/// A family of distinct custom types can share one generic spec, by a zero-sized tag type
+/// parameter such as `Name<UserTag>` and `Name<GroupTag>`.
+/// Declare the tag parameters by `tags` field (after `lifetimes`, if any) at the head of
+/// `Spec { .. }`, and the impls are generated generically over the tags.
+///
+/// ```ignore
+/// #[repr(transparent)]
+/// pub struct Name<T>(PhantomData<fn() -> T>, str);
+///
+/// validated_slice::impl_std_traits_for_slice! {
+///     Spec {
+///         tags: [T],
+///         spec: NameSpec<T>,
+///         custom: Name<T>,
+///         inner: str,
+///         error: NameError,
+///     };
+///     { TryFrom<&{Inner}> for &{Custom} };
+///     { Deref<Target = {Inner}> };
+/// }
+/// ```
+///
+/// Each tag parameter can have a single trait bound, such as `tags: [C: ValidationContext<str>],`.
+/// This is useful when the spec implements [`SliceSpec`] only for some tags, for example when the
+/// validity depends on the context given by the tag (see [`ValidationContext`]).
+/// Without a bound, the spec should implement [`SliceSpec`] for any tag.
+/// The same restrictions as lifetime parameters apply.
+///
 /// ## Type names
 ///
 /// As type name, you can use `{Custom}` and `{Inner}` instead of a real type name.
@@ -272,11 +363,23 @@ macro_rules! impl_slice_spec_methods {
 /// Each trait impl is specified by `{ TraitName<TyParams> for TyImplTarget };` format.
 /// `<TyParams>` part and `for TyImplTarget` part is optional.
 ///
-/// Default impl target is `{Custom}`, and it should NOT be specified explicitly.
-/// Explicit `for {Custom}` is not supported and will cause compile error.
+/// Default impl target is `{Custom}`.
+/// Explicit `for {Custom}` is also accepted, and it is same as omitting `for TyImplTarget` part.
 ///
 /// Supported trait impls are:
 ///
+/// * `std::borrow`
+///     + `{ Borrow<{Inner}> };`
+///     + `{ Borrow<any_ty> };`
+///         - This requires `{Inner}: Borrow<any_ty>`.
+///     + `{ unsafe BorrowMut<{Inner}> };`
+///     + `{ unsafe BorrowMut<any_ty> };`
+///         - This requires `{Inner}: BorrowMut<any_ty>`.
+///     + Note that `Borrow` requires `Eq`, `Ord`, and `Hash` of `{Custom}` to behave identically
+///       to those of the borrowed type.
+///     + `BorrowMut` lets users mutate the inner slice without validation.
+///       `unsafe` is required to acknowledge that any mutation through it should keep the value
+///       valid, and `{ BorrowMut<..> }` without `unsafe` causes compile error.
 /// * `std::convert`
 ///     + `{ AsMut<{Custom}> };`
 ///     + `{ AsMut<any_ty> };`
@@ -291,23 +394,207 @@ macro_rules! impl_slice_spec_methods {
 ///     + `{ From<&{Custom}> for Arc<{Custom}> };
 ///     + `{ From<&{Custom}> for Box<{Custom}> };
 ///     + `{ From<&{Custom}> for Rc<{Custom}> };
+///     + `{ From<&{Custom}> for PathBuf };`
+///         - This requires `std`, and `PathBuf: From<&{Inner}>`.
+///         - This conflicts with `AsRef<OsStr>` impl for `{Custom}`, because std provides
+///           `PathBuf: From<&T>` for any `T: AsRef<OsStr>`.
+///     + `{ From<&mut Box<{Inner}>> for &mut Box<{Custom}> };
+///     + `{ unsafe From<&mut Box<{Custom}>> for &mut Box<{Inner}> };
+///         - `unsafe` is required as `BorrowMut`, because this lets users replace the box with
+///           an invalid one. `{ From<&mut Box<{Custom}>> for &mut Box<{Inner}> }` without `unsafe`
+///           causes compile error.
 ///     + `{ TryFrom<&{Inner}> for &{Custom} };
 ///     + `{ TryFrom<&mut {Inner}> for &mut {Custom} };
+///     + `{ TryFrom<&mut Box<{Inner}>> for &mut Box<{Custom}> };
+///         - This validates the boxed slice, and lets users mutate the box only through the
+///           validated custom type (for example, replacing it with another valid box).
+///     + `{ TryFrom<&[u8]> for &{Custom} };
+///         - This requires `str: AsRef<{Inner}>`, and is intended for `str`-backed custom types.
+///         - This checks UTF-8 validity and then validates the string, and the error type is
+///           [`FromBytesError`]`<Utf8Error, {Error}>`.
+///         - Note that this takes precedence over `{ TryFrom<&any_ty> for &{Custom} }` below.
+///     + `{ TryFrom<&any_ty> for &{Custom} };
+///         - This requires `any_ty: AsRef<{Inner}>`.
+///         - This converts the other custom type over the same inner type by revalidating the
+///           inner slice.
+///         - For example, `{ TryFrom<&HostnameStr> for &{Custom} };` converts `&HostnameStr`
+///           into `&{Custom}` without unwrapping it manually.
+///         - `{Custom}` itself cannot be `any_ty`, since the reflexive
+///           `TryFrom<&{Custom}> for &{Custom}` (with `Infallible` error) is provided by the
+///           blanket impls of std. Generic code bounded by `T: TryInto<&{Custom}>` already accepts
+///           `&{Custom}`.
+///     + `{ TryFrom<any_ty> for Box<{Custom}> };
+///         - This requires `Box<{Inner}>: From<any_ty>`, and the converted box is validated.
+///         - For example, `{ TryFrom<String> for Box<{Custom}> };` converts the validated string
+///           via `String::into_boxed_str`, and this is useful when the owned custom type is
+///           not defined.
 /// * `std::default`
 ///     + `{ Default for &{Custom} };`
 ///     + `{ Default for &mut {Custom} };`
 /// * `std::fmt`
 ///     + `{ Debug };`
+///     + `{ Debug(name = "Name") };`
+///         - Formats as a tuple struct `Name(..)` with the `{Inner}` as its field.
+///     + `{ Debug(with = path::to::fn) };`
+///         - The function should have the type `fn(&{Inner}, &mut Formatter<'_>) -> fmt::Result`.
 ///     + `{ Display };`
+///     + `{ Display(with = path::to::fn) };`
+///         - The function should have the type `fn(&{Inner}, &mut Formatter<'_>) -> fmt::Result`.
+///     + `{ LowerHex };`, `{ UpperHex };`, `{ Binary };`, `{ Octal };`
+///         - These are delegated to `{Inner}`.
+///     + `{ Display(hex) };`, `{ LowerHex(bytes) };`, `{ UpperHex(bytes) };`
+///         - These require `{Inner}: AsRef<[u8]>`, and format the bytes in hexadecimal, two
+///           digits per byte, such as `c0ffee`.
+///           `Display(hex)` uses lowercase digits.
+///         - With the alternate flag (such as `{:#x}`), `0x` prefix is written.
+/// * `std::hash`
+///     + `{ Hash };`
+///     + `{ Hash(with = path::to::fn) };`
+///         - The function should have the type `fn<H: Hasher>(&{Inner}, &mut H)`.
+///         - Use this together with custom comparison (`base: Custom`), so that `Hash` stays
+///           consistent with `PartialEq`.
+///     + `{ !Hash };`
+///         - This implements nothing, and explicitly opts out of `Hash` delegation.
+///           See "Checks" section.
+/// * `std::cmp`
+///     + `{ Ord };`
+///         - This is delegated to `{Inner}`, and requires `{Custom}: Eq + PartialOrd`.
+///     + `{ !Ord };`
+///         - This implements nothing, and explicitly opts out of `Ord` delegation.
+///           See "Checks" section.
 /// * `std::ops`
 ///     + `{ Deref<Target = {Inner}> };`
 ///     + `{ DerefMut<Target = {Inner}> };`
+///     + `{ Index<usize> };`
+///         - `Output` is same as `<{Inner} as Index<usize>>::Output`.
+/// * `validated_slice`
+///     + `{ ErasedValidatedSlice };`
+///         - This implements [`ErasedValidatedSlice`], and requires `{Inner}: AsRef<[u8]>`.
+///     + `{ Needle<{Inner}> for &{Custom} };`
+///         - This implements [`Needle`], so that the custom slice can be the needle of the search
+///           methods generated by [`impl_methods_for_slice!`].
+///         - This requires `{Inner}: SearchSlice + SliceLen`.
+/// * `nom` (requires `nom` feature)
+///     + `{ nom::AsBytes };`
+///     + `{ nom::Compare };`
+///     + `{ nom::FindSubstring };`
+///     + `{ nom::FindToken };`
+///     + `{ nom::Input };`
+///         - This requires `$spec: SliceClosedSpec`, because parsers take sub-slices of the input.
+///     + `{ nom::Offset };`
+///     + `{ nom::ParseTo };`
+///     + Traits are implemented for `&{Custom}`, and delegated to the inner slice.
+/// * `defmt` (requires `defmt` feature)
+///     + `{ defmt::Format };`
+///         - This is delegated to `{Inner}`.
+/// * `schemars` (requires `schemars` feature)
+///     + `{ JsonSchema };`
+///         - The schema of `{Inner}` is used.
+///     + `{ JsonSchema(spec) };`
+///         - The schema of `{Inner}` is used, with `format` and `pattern` annotations given by
+///           [`JsonSchemaSpec`] impl of `{Spec}`.
+/// * `ufmt` (requires `ufmt` feature)
+///     + `{ ufmt::uDebug };`
+///     + `{ ufmt::uDisplay };`
+///         - These are delegated to `{Inner}`.
+///
+/// ## Checks
+///
+/// Additional checks can be enabled by `Check { .. };` right after `Spec { .. };`.
+///
+/// * `Trusted`: Requires the spec to implement [`TrustedSpec`].
+/// * `NoPanic`: Rejects targets which panic on validation failure, unless the validation error
+///   type of the spec is `core::convert::Infallible`.
+///     + Such targets are `From<&{Inner}> for &{Custom}`, `From<&mut {Inner}> for &mut {Custom}`,
+///       `Default for &{Custom}`, and `Default for &mut {Custom}`.
+/// * `Borrow`: Requires `Hash` and `Ord` to be also specified when `Borrow<..>` targets are
+///   specified, as `impl_std_traits_for_owned_slice!` does.
+///     + `Hash` is satisfied by `{ Hash };` or `{ Hash(with = ..) };`, and `Ord` is satisfied by
+///       `{ Ord };`.
+///     + If the type should not implement them (or implements them by `derive`), opt out
+///       explicitly by `{ !Hash };` or `{ !Ord };`.
+///
+/// ```ignore
+/// validated_slice::impl_std_traits_for_slice! {
+///     Spec { /* ... */ };
+///     Check { Trusted, Borrow };
+///     { Borrow<{Inner}> };
+///     { Hash };
+///     { !Ord };
+///     /* ... */
+/// }
+/// ```
+///
+/// ## Listing the generated impls
+///
+/// With `@list(vis const NAME);` at the beginning, the macro also defines a constant
+/// `NAME: &[&str]` which lists the trait impl targets (without `Std`, `Spec`, and `Check`
+/// parts), so that downstream codes and tests can check the API surface of the custom types.
+/// Each target is stringified by `stringify!`, such as `"TryFrom<&{Inner}> for &{Custom}"`.
+///
+/// ```ignore
+/// validated_slice::impl_std_traits_for_slice! {
+///     @list(pub const ASCII_STR_IMPLS);
+///     Spec { /* ... */ };
+///     { AsRef<str> };
+///     { TryFrom<&{Inner}> for &{Custom} };
+/// }
 ///
+/// assert_eq!(ASCII_STR_IMPLS, ["AsRef<str>", "TryFrom<&{Inner}> for &{Custom}"]);
+/// ```
+///
+/// [`ErasedValidatedSlice`]: trait.ErasedValidatedSlice.html
+/// [`FromBytesError`]: enum.FromBytesError.html
 /// [`impl_cmp_for_slice!`]: macro.impl_cmp_for_slice.html
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+/// [`JsonSchemaSpec`]: trait.JsonSchemaSpec.html
+/// [`Needle`]: trait.Needle.html
+/// [`SliceSpec`]: trait.SliceSpec.html
+/// [`TrustedSpec`]: trait.TrustedSpec.html
+/// [`ValidationContext`]: trait.ValidationContext.html
 #[macro_export]
 macro_rules! impl_std_traits_for_slice {
+    // Lists the targets as a constant.
+    (
+        @list($vis:vis const $name:ident);
+        $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_slice! { $($rest)* }
+        $crate::impl_std_traits_for_slice! {
+            @list_collect; ($vis, $name); []; $($rest)*
+        }
+    };
+    (
+        @list_collect; ($vis:vis, $name:ident); [$($acc:expr,)*]; $(;)?
+    ) => {
+        $vis const $name: &[&str] = &[$($acc),*];
+    };
+    (
+        @list_collect; $params:tt; [$($acc:expr,)*]; $_kw:ident { $($_fields:tt)* }; $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @list_collect; $params; [$($acc,)*]; $($rest)*
+        }
+    };
+    (
+        @list_collect; $params:tt; [$($acc:expr,)*]; { ! $($_target:tt)* } $(; $($rest:tt)*)?
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @list_collect; $params; [$($acc,)*]; $($($rest)*)?
+        }
+    };
+    (
+        @list_collect; $params:tt; [$($acc:expr,)*]; { $($entry:tt)* } $(; $($rest:tt)*)?
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @list_collect; $params; [$($acc,)* stringify!($($entry)*),]; $($($rest)*)?
+        }
+    };
+
     (
         Spec {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
             spec: $spec:ty,
             custom: $custom:ty,
             inner: $inner:ty,
@@ -315,12 +602,13 @@ macro_rules! impl_std_traits_for_slice {
         };
         $({$($rest:tt)*});* $(;)?
     ) => {
-        $(
-            $crate::impl_std_traits_for_slice! {
-                @impl; ({std, std}, $spec, $custom, $inner, $error);
-                rest=[$($rest)*];
-            }
-        )*
+        $crate::__with_default_std! {
+            $crate::impl_std_traits_for_slice;
+            head=[@entries;];
+            lifetimes=[$($(, $lt)*)? $($(, $tag $(: $tag_bound)?)*)?];
+            args=[$spec, $custom, $inner, $error];
+            $({$($rest)*});*
+        }
     };
 
     (
@@ -329,6 +617,8 @@ macro_rules! impl_std_traits_for_slice {
             alloc: $alloc:ident,
         };
         Spec {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
             spec: $spec:ty,
             custom: $custom:ty,
             inner: $inner:ty,
@@ -336,20 +626,325 @@ macro_rules! impl_std_traits_for_slice {
         };
         $({$($rest:tt)*});* $(;)?
     ) => {
+        $crate::impl_std_traits_for_slice! {
+            @entries; ({$core, $alloc $($(, $lt)*)? $($(, $tag $(: $tag_bound)?)*)?}, $spec, $custom, $inner, $error);
+            $({$($rest)*});*
+        }
+    };
+    (@entries; $params:tt; $({$($rest:tt)*});* $(;)?) => {
         $(
             $crate::impl_std_traits_for_slice! {
-                @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+                @impl; $params;
                 rest=[$($rest)*];
             }
         )*
     };
 
+    // Spec fields inferred from the spec type.
+    (
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::SliceSpec>::Custom,
+                inner: <$spec as $crate::SliceSpec>::Inner,
+                error: <$spec as $crate::SliceSpec>::Error,
+            };
+            $($rest)*
+        }
+    };
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            Std {
+                core: $core,
+                alloc: $alloc,
+            };
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::SliceSpec>::Custom,
+                inner: <$spec as $crate::SliceSpec>::Inner,
+                error: <$spec as $crate::SliceSpec>::Error,
+            };
+            $($rest)*
+        }
+    };
+
+    // Checks.
+    (
+        $(
+            Std {
+                core: $core:ident,
+                alloc: $alloc:ident,
+            };
+        )?
+        Spec $spec_fields:tt;
+        Check { $($check:ident),+ $(,)? };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check; [$($check),+]; $spec_fields; [$({$($rest)*})*];
+        }
+        $crate::impl_std_traits_for_slice! {
+            $(
+                Std {
+                    core: $core,
+                    alloc: $alloc,
+                };
+            )?
+            Spec $spec_fields;
+            $({$($rest)*});*
+        }
+    };
+    (@check; [$check:ident $(, $checks:ident)*]; $spec_fields:tt; $entries:tt;) => {
+        $crate::impl_std_traits_for_slice! { @check[$check]; $spec_fields; $entries; }
+        $crate::impl_std_traits_for_slice! { @check; [$($checks),*]; $spec_fields; $entries; }
+    };
+    (@check; []; $spec_fields:tt; $entries:tt;) => {};
+    (@check[Trusted]; { spec: $spec:ty $(, $($_fields:tt)*)? }; $entries:tt;) => {
+        const _: fn() = $crate::__private::assert_trusted_slice_spec::<$spec>;
+    };
+    (
+        @check[Trusted];
+        {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
+            spec: $spec:ty $(, $($_fields:tt)*)?
+        };
+        $entries:tt;
+    ) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_trusted<$($($lt,)*)? $($($tag $(: $tag_bound)?,)*)?>() {
+                $crate::__private::assert_trusted_slice_spec::<$spec>();
+            }
+        };
+    };
+    (@check[Borrow]; $spec_fields:tt; [$($entries:tt)*];) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_borrow; borrow=[]; hash=[]; ord=[];
+            rest=[$($entries)*];
+        }
+    };
+    (@check[NoPanic]; $spec_fields:tt; [$($entries:tt)*];) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($entries)*];
+        }
+    };
+    (@check[$check:ident]; $spec_fields:tt; $entries:tt;) => {
+        compile_error!(concat!("Unsupported check: ", stringify!($check)));
+    };
+
+    // Borrow contract check.
+    (
+        @check_borrow; borrow=[$($borrow:tt)*]; hash=$hash:tt; ord=$ord:tt;
+        rest=[{ Borrow $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_borrow; borrow=[$($borrow)* Borrow]; hash=$hash; ord=$ord;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_borrow; borrow=$borrow:tt; hash=[$($hash:tt)*]; ord=$ord:tt;
+        rest=[{ $(!)? Hash $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_borrow; borrow=$borrow; hash=[$($hash)* Hash]; ord=$ord;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_borrow; borrow=$borrow:tt; hash=$hash:tt; ord=[$($ord:tt)*];
+        rest=[{ $(!)? Ord $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_borrow; borrow=$borrow; hash=$hash; ord=[$($ord)* Ord];
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_borrow; borrow=$borrow:tt; hash=$hash:tt; ord=$ord:tt;
+        rest=[{ $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_borrow; borrow=$borrow; hash=$hash; ord=$ord;
+            rest=[$($rest)*];
+        }
+    };
+    (@check_borrow; borrow=[]; hash=$hash:tt; ord=$ord:tt; rest=[];) => {};
+    (@check_borrow; borrow=[$($borrow:tt)+]; hash=[$($hash:tt)*]; ord=[$($ord:tt)*]; rest=[];) => {
+        $crate::impl_std_traits_for_slice! { @check_borrow_missing; Hash; [$($hash)*] }
+        $crate::impl_std_traits_for_slice! { @check_borrow_missing; Ord; [$($ord)*] }
+    };
+    (@check_borrow_missing; $trait:ident; [$($found:tt)+]) => {};
+    (@check_borrow_missing; $trait:ident; []) => {
+        compile_error!(concat!(
+            "`Borrow` target requires `{ ", stringify!($trait), " }` to keep the `Borrow` contract, ",
+            "or `{ !", stringify!($trait), " }` to explicitly opt out"
+        ));
+    };
+
+    // No-panic check.
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[{ From<&{Inner}> for &{Custom} } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic_assert; $spec_fields;
+        }
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[{ From<&mut {Inner}> for &mut {Custom} } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic_assert; $spec_fields;
+        }
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[{ Default for &{Custom} } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic_assert; $spec_fields;
+        }
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[{ Default for &mut {Custom} } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic_assert; $spec_fields;
+        }
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[{ $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (@check_no_panic; $spec_fields:tt; rest=[];) => {};
+    (@check_no_panic_assert; { spec: $spec:ty $(, $($_fields:tt)*)? };) => {
+        const _: fn() = $crate::__private::assert_infallible_slice_spec::<$spec>;
+    };
+    (
+        @check_no_panic_assert;
+        {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
+            spec: $spec:ty $(, $($_fields:tt)*)?
+        };
+    ) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_no_panic<$($($lt,)*)? $($($tag $(: $tag_bound)?,)*)?>() {
+                $crate::__private::assert_infallible_slice_spec::<$spec>();
+            }
+        };
+    };
+
+    // std::borrow::Borrow
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Borrow<{Inner}> ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::borrow::Borrow<$inner> for $custom {
+            #[inline]
+            fn borrow(&self) -> &$inner {
+                <$spec as $crate::SliceSpec>::as_inner(self)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Borrow<$param:ty> ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::borrow::Borrow<$param> for $custom
+        where
+            $inner: $core::borrow::Borrow<$param>,
+        {
+            #[inline]
+            fn borrow(&self) -> &$param {
+                <$spec as $crate::SliceSpec>::as_inner(self).borrow()
+            }
+        }
+    };
+
+    // std::borrow::BorrowMut
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ unsafe BorrowMut<{Inner}> ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::borrow::BorrowMut<$inner> for $custom {
+            #[inline]
+            fn borrow_mut(&mut self) -> &mut $inner {
+                <$spec as $crate::SliceSpec>::as_inner_mut(self)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ unsafe BorrowMut<$param:ty> ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::borrow::BorrowMut<$param> for $custom
+        where
+            $inner: $core::borrow::BorrowMut<$param>,
+        {
+            #[inline]
+            fn borrow_mut(&mut self) -> &mut $param {
+                <$spec as $crate::SliceSpec>::as_inner_mut(self).borrow_mut()
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ BorrowMut $($_rest:tt)* ];
+    ) => {
+        compile_error!(concat!(
+            "`BorrowMut` target allows arbitrary mutation of the inner slice, ",
+            "so it should be explicitly specified as `{ unsafe BorrowMut<..> }`"
+        ));
+    };
+
     // std::convert::AsMut
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ AsMut<{Custom}> ];
     ) => {
-        impl $core::convert::AsMut<$custom> for $custom {
+        impl<$($lt $(: $bound)?),*> $core::convert::AsMut<$custom> for $custom {
             #[inline]
             fn as_mut(&mut self) -> &mut $custom {
                 self
@@ -357,10 +952,10 @@ macro_rules! impl_std_traits_for_slice {
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ AsMut<$param:ty> ];
     ) => {
-        impl $core::convert::AsMut<$param> for $custom
+        impl<$($lt $(: $bound)?),*> $core::convert::AsMut<$param> for $custom
         where
             $inner: AsMut<$param>,
         {
@@ -373,10 +968,10 @@ macro_rules! impl_std_traits_for_slice {
 
     // std::convert::AsRef
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ AsRef<{Custom}> ];
     ) => {
-        impl $core::convert::AsRef<$custom> for $custom {
+        impl<$($lt $(: $bound)?),*> $core::convert::AsRef<$custom> for $custom {
             #[inline]
             fn as_ref(&self) -> &$custom {
                 self
@@ -384,10 +979,10 @@ macro_rules! impl_std_traits_for_slice {
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ AsRef<{Custom}> for Cow<{Custom}> ];
     ) => {
-        impl<'a> $core::convert::AsRef<$custom> for $alloc::borrow::Cow<'a, $custom> {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::AsRef<$custom> for $alloc::borrow::Cow<'__a, $custom> {
             #[inline]
             fn as_ref(&self) -> &$custom {
                 &**self
@@ -395,10 +990,10 @@ macro_rules! impl_std_traits_for_slice {
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ AsRef<$param:ty> ];
     ) => {
-        impl $core::convert::AsRef<$param> for $custom
+        impl<$($lt $(: $bound)?),*> $core::convert::AsRef<$param> for $custom
         where
             $inner: AsRef<$param>,
         {
@@ -409,10 +1004,10 @@ macro_rules! impl_std_traits_for_slice {
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ AsRef<$param:ty> for Cow<{Custom}> ];
     ) => {
-        impl<'a> $core::convert::AsRef<$param> for $alloc::borrow::Cow<'a, $custom>
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::AsRef<$param> for $alloc::borrow::Cow<'__a, $custom>
         where
             $inner: AsRef<$param>,
         {
@@ -425,11 +1020,11 @@ macro_rules! impl_std_traits_for_slice {
 
     // std::convert::From
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ From<&{Inner}> for &{Custom} ];
     ) => {
-        impl<'a> $core::convert::From<&'a $inner> for &'a $custom {
-            fn from(s: &'a $inner) -> Self {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a $inner> for &'__a $custom {
+            fn from(s: &'__a $inner) -> Self {
                 assert!(
                     <$spec as $crate::SliceSpec>::validate(s).is_ok(),
                     "Attempt to convert invalid data: `From<&{}> for &{}`",
@@ -441,17 +1036,17 @@ macro_rules! impl_std_traits_for_slice {
                     // * `$spec::validate(s)` returns `Ok(())`.
                     //     + This is ensured by the leading assert.
                     // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
-                    <$spec as $crate::SliceSpec>::from_inner_unchecked(s)
+                    $crate::__from_inner_unchecked!(slice; $spec, s)
                 }
             }
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ From<&mut {Inner}> for &mut {Custom} ];
     ) => {
-        impl<'a> $core::convert::From<&'a mut $inner> for &'a mut $custom {
-            fn from(s: &'a mut $inner) -> Self {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a mut $inner> for &'__a mut $custom {
+            fn from(s: &'__a mut $inner) -> Self {
                 assert!(
                     <$spec as $crate::SliceSpec>::validate(s).is_ok(),
                     "Attempt to convert invalid data: `From<&mut {}> for &mut {}`",
@@ -463,47 +1058,121 @@ macro_rules! impl_std_traits_for_slice {
                     // * `$spec::validate(s)` returns `Ok(())`.
                     //     + This is ensured by the leading assert.
                     // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
-                    <$spec as $crate::SliceSpec>::from_inner_unchecked_mut(s)
+                    $crate::__from_inner_unchecked!(slice_mut; $spec, s)
                 }
             }
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ From<&{Custom}> for &{Inner} ];
     ) => {
-        impl<'a> $core::convert::From<&'a $custom> for &'a $inner {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a $custom> for &'__a $inner {
             #[inline]
-            fn from(s: &'a $custom) -> Self {
+            fn from(s: &'__a $custom) -> Self {
                 <$spec as $crate::SliceSpec>::as_inner(s)
             }
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ From<&mut {Custom}> for &mut {Inner} ];
     ) => {
-        impl<'a> $core::convert::From<&'a mut $custom> for &'a mut $inner {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a mut $custom> for &'__a mut $inner {
             #[inline]
-            fn from(s: &'a mut $custom) -> Self {
+            fn from(s: &'__a mut $custom) -> Self {
                 <$spec as $crate::SliceSpec>::as_inner_mut(s)
             }
         }
     };
-
-    // std::convert::From for smart pointers
     (
-        @impl [smartptr]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty, $mut:ident);
-        rest=[ From<&{Custom}> for $($smartptr:ident)::* <{Custom}> ];
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ From<&mut Box<{Inner}>> for &mut Box<{Custom}> ];
     ) => {
-        impl<'a> $core::convert::From<&'a $custom> for $($smartptr)::* <$custom>
-        where
-            $($smartptr)::* <$inner>: $core::convert::From<&'a $inner>,
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a mut $alloc::boxed::Box<$inner>>
+            for &'__a mut $alloc::boxed::Box<$custom>
         {
-            fn from(s: &'a $custom) -> Self {
-                let inner = <$spec as $crate::SliceSpec>::as_inner(s);
-                let buf = $($smartptr)::* ::<$inner>::from(inner);
-                unsafe {
+            fn from(s: &'__a mut $alloc::boxed::Box<$inner>) -> Self {
+                assert!(
+                    <$spec as $crate::SliceSpec>::validate(s).is_ok(),
+                    "Attempt to convert invalid data: `From<&mut Box<{}>> for &mut Box<{}>`",
+                    stringify!($inner), stringify!($custom)
+                );
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading assert.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    //     + This ensures that `Box<$inner>` and `Box<$custom>` have the same
+                    //       memory layout.
+                    &mut *(s as *mut $alloc::boxed::Box<$inner> as *mut $alloc::boxed::Box<$custom>)
+                }
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ unsafe From<&mut Box<{Custom}>> for &mut Box<{Inner}> ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a mut $alloc::boxed::Box<$custom>>
+            for &'__a mut $alloc::boxed::Box<$inner>
+        {
+            #[inline]
+            fn from(s: &'__a mut $alloc::boxed::Box<$custom>) -> Self {
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    //     + This ensures that `Box<$inner>` and `Box<$custom>` have the same
+                    //       memory layout.
+                    //
+                    // Keeping the value valid is the responsibility of the user who specified
+                    // `unsafe` for this target.
+                    &mut *(s as *mut $alloc::boxed::Box<$custom> as *mut $alloc::boxed::Box<$inner>)
+                }
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ From<&mut Box<{Custom}>> for &mut Box<{Inner}> ];
+    ) => {
+        compile_error!(concat!(
+            "`From<&mut Box<{Custom}>> for &mut Box<{Inner}>` target allows replacing the value ",
+            "with an invalid one, so it should be explicitly specified as ",
+            "`{ unsafe From<&mut Box<{Custom}>> for &mut Box<{Inner}> }`"
+        ));
+    };
+
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ From<&{Custom}> for PathBuf ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a $custom> for ::std::path::PathBuf
+        where
+            ::std::path::PathBuf: $core::convert::From<&'__a $inner>,
+        {
+            #[inline]
+            fn from(s: &'__a $custom) -> Self {
+                ::std::path::PathBuf::from(<$spec as $crate::SliceSpec>::as_inner(s))
+            }
+        }
+    };
+
+    // std::convert::From for smart pointers
+    (
+        @impl [smartptr]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty, $mut:ident);
+        rest=[ From<&{Custom}> for $($smartptr:ident)::* <{Custom}> ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a $custom> for $($smartptr)::* <$custom>
+        where
+            $($smartptr)::* <$inner>: $core::convert::From<&'__a $inner>,
+        {
+            fn from(s: &'__a $custom) -> Self {
+                let inner = <$spec as $crate::SliceSpec>::as_inner(s);
+                let buf = $($smartptr)::* ::<$inner>::from(inner);
+                unsafe {
                     // This is safe only when all of the conditions below are met:
                     //
                     // * `$spec::validate(s)` returns `Ok(())`.
@@ -519,42 +1188,55 @@ macro_rules! impl_std_traits_for_slice {
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ From<&{Custom}> for Arc<{Custom}> ];
     ) => {
         $crate::impl_std_traits_for_slice! {
-            @impl [smartptr]; ({$core, $alloc}, $spec, $custom, $inner, $error, const);
+            @impl [smartptr]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error, const);
             rest=[ From<&{Custom}> for $alloc::sync::Arc <{Custom}> ];
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ From<&{Custom}> for Box<{Custom}> ];
     ) => {
         $crate::impl_std_traits_for_slice! {
-            @impl [smartptr]; ({$core, $alloc}, $spec, $custom, $inner, $error, mut);
+            @impl [smartptr]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error, mut);
             rest=[ From<&{Custom}> for $alloc::boxed::Box <{Custom}> ];
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ From<&{Custom}> for Rc<{Custom}> ];
     ) => {
         $crate::impl_std_traits_for_slice! {
-            @impl [smartptr]; ({$core, $alloc}, $spec, $custom, $inner, $error, const);
+            @impl [smartptr]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error, const);
             rest=[ From<&{Custom}> for $alloc::rc::Rc <{Custom}> ];
         }
     };
 
     // std::convert::TryFrom
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ TryFrom<&{Inner}> for &{Custom} ];
     ) => {
-        impl<'a> $core::convert::TryFrom<&'a $inner> for &'a $custom {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::TryFrom<&'__a $inner> for &'__a $custom {
+            type Error = $error;
+
+            #[inline]
+            fn try_from(s: &'__a $inner) -> $core::result::Result<Self, Self::Error> {
+                $crate::Validated::<$spec>::new(s).map($crate::Validated::into_custom)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ TryFrom<&mut {Inner}> for &mut {Custom} ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::TryFrom<&'__a mut $inner> for &'__a mut $custom {
             type Error = $error;
 
-            fn try_from(s: &'a $inner) -> $core::result::Result<Self, Self::Error> {
+            fn try_from(s: &'__a mut $inner) -> $core::result::Result<Self, Self::Error> {
                 <$spec as $crate::SliceSpec>::validate(s)?;
                 Ok(unsafe {
                     // This is safe only when all of the conditions below are met:
@@ -562,19 +1244,74 @@ macro_rules! impl_std_traits_for_slice {
                     // * `$spec::validate(s)` returns `Ok(())`.
                     //     + This is ensured by the leading `validate()?` call.
                     // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
-                    <$spec as $crate::SliceSpec>::from_inner_unchecked(s)
+                    $crate::__from_inner_unchecked!(slice_mut; $spec, s)
                 })
             }
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
-        rest=[ TryFrom<&mut {Inner}> for &mut {Custom} ];
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ TryFrom<&mut Box<{Inner}>> for &mut Box<{Custom}> ];
     ) => {
-        impl<'a> $core::convert::TryFrom<&'a mut $inner> for &'a mut $custom {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::TryFrom<&'__a mut $alloc::boxed::Box<$inner>>
+            for &'__a mut $alloc::boxed::Box<$custom>
+        {
+            type Error = $error;
+
+            fn try_from(
+                s: &'__a mut $alloc::boxed::Box<$inner>,
+            ) -> $core::result::Result<Self, Self::Error> {
+                <$spec as $crate::SliceSpec>::validate(s)?;
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    //     + This ensures that `Box<$inner>` and `Box<$custom>` have the same
+                    //       memory layout.
+                    &mut *(s as *mut $alloc::boxed::Box<$inner> as *mut $alloc::boxed::Box<$custom>)
+                })
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ TryFrom<&[u8]> for &{Custom} ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::TryFrom<&'__a [u8]> for &'__a $custom
+        where
+            str: $core::convert::AsRef<$inner>,
+        {
+            type Error = $crate::FromBytesError<$core::str::Utf8Error, $error>;
+
+            fn try_from(bytes: &'__a [u8]) -> $core::result::Result<Self, Self::Error> {
+                let s = $core::str::from_utf8(bytes).map_err($crate::FromBytesError::Utf8)?;
+                let s: &'__a $inner = $core::convert::AsRef::<$inner>::as_ref(s);
+                <$spec as $crate::SliceSpec>::validate(s).map_err($crate::FromBytesError::Spec)?;
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $spec, s)
+                })
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ TryFrom<&$param:ty> for &{Custom} ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::TryFrom<&'__a $param> for &'__a $custom
+        where
+            $param: $core::convert::AsRef<$inner>,
+        {
             type Error = $error;
 
-            fn try_from(s: &'a mut $inner) -> $core::result::Result<Self, Self::Error> {
+            fn try_from(s: &'__a $param) -> $core::result::Result<Self, Self::Error> {
+                let s: &'__a $inner = $core::convert::AsRef::<$inner>::as_ref(s);
                 <$spec as $crate::SliceSpec>::validate(s)?;
                 Ok(unsafe {
                     // This is safe only when all of the conditions below are met:
@@ -582,7 +1319,37 @@ macro_rules! impl_std_traits_for_slice {
                     // * `$spec::validate(s)` returns `Ok(())`.
                     //     + This is ensured by the leading `validate()?` call.
                     // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
-                    <$spec as $crate::SliceSpec>::from_inner_unchecked_mut(s)
+                    $crate::__from_inner_unchecked!(slice; $spec, s)
+                })
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ TryFrom<$param:ty> for Box<{Custom}> ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::convert::TryFrom<$param> for $alloc::boxed::Box<$custom>
+        where
+            $alloc::boxed::Box<$inner>: $core::convert::From<$param>,
+        {
+            type Error = $error;
+
+            fn try_from(s: $param) -> $core::result::Result<Self, Self::Error> {
+                // Validate the converted buffer rather than `s`, because `From<$param>` can be a
+                // user impl which does not keep the content.
+                let buf = $alloc::boxed::Box::<$inner>::from(s);
+                <$spec as $crate::SliceSpec>::validate(&*buf)?;
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call for `buf`.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    //     + This ensures that the memory layout of `into_raw(buf)` is also valid
+                    //       as `Box<$custom>`.
+                    $alloc::boxed::Box::<$custom>::from_raw(
+                        $alloc::boxed::Box::<$inner>::into_raw(buf) as *mut $custom
+                    )
                 })
             }
         }
@@ -590,15 +1357,15 @@ macro_rules! impl_std_traits_for_slice {
 
     // std::default::Default
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ Default for &{Custom} ];
     ) => {
-        impl<'a> $core::default::Default for &'a $custom
+        impl<'__a $(, $lt $(: $bound)?)*> $core::default::Default for &'__a $custom
         where
-            &'a $inner: $core::default::Default,
+            &'__a $inner: $core::default::Default,
         {
             fn default() -> Self {
-                let inner = <&'a $inner as $core::default::Default>::default();
+                let inner = <&'__a $inner as $core::default::Default>::default();
                 assert!(
                     <$spec as $crate::SliceSpec>::validate(inner).is_ok(),
                     "Attempt to create invalid data: `Default for &{}`",
@@ -610,21 +1377,21 @@ macro_rules! impl_std_traits_for_slice {
                     // * `$spec::validate(s)` returns `Ok(())`.
                     //     + This is ensured by the leading assert.
                     // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
-                    <$spec as $crate::SliceSpec>::from_inner_unchecked(inner)
+                    $crate::__from_inner_unchecked!(slice; $spec, inner)
                 }
             }
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ Default for &mut {Custom} ];
     ) => {
-        impl<'a> $core::default::Default for &'a mut $custom
+        impl<'__a $(, $lt $(: $bound)?)*> $core::default::Default for &'__a mut $custom
         where
-            &'a mut $inner: $core::default::Default,
+            &'__a mut $inner: $core::default::Default,
         {
             fn default() -> Self {
-                let inner = <&'a mut $inner as $core::default::Default>::default();
+                let inner = <&'__a mut $inner as $core::default::Default>::default();
                 assert!(
                     <$spec as $crate::SliceSpec>::validate(inner).is_ok(),
                     "Attempt to create invalid data: `Default for &{}`",
@@ -636,7 +1403,7 @@ macro_rules! impl_std_traits_for_slice {
                     // * `$spec::validate(s)` returns `Ok(())`.
                     //     + This is ensured by the leading assert.
                     // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
-                    <$spec as $crate::SliceSpec>::from_inner_unchecked_mut(inner)
+                    $crate::__from_inner_unchecked!(slice_mut; $spec, inner)
                 }
             }
         }
@@ -644,10 +1411,10 @@ macro_rules! impl_std_traits_for_slice {
 
     // std::fmt::Debug
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ Debug ];
     ) => {
-        impl $core::fmt::Debug for $custom
+        impl<$($lt $(: $bound)?),*> $core::fmt::Debug for $custom
         where
             $inner: $core::fmt::Debug,
         {
@@ -659,12 +1426,42 @@ macro_rules! impl_std_traits_for_slice {
         }
     };
 
+    // std::fmt::Debug with a wrapper name
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Debug(name = $name:expr) ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::Debug for $custom
+        where
+            $inner: $core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                f.debug_tuple($name).field(&inner).finish()
+            }
+        }
+    };
+
+    // std::fmt::Debug with a custom formatting function
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Debug(with = $fmt:path) ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::Debug for $custom {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner: &$inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                $fmt(inner, f)
+            }
+        }
+    };
+
     // std::fmt::Display
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ Display ];
     ) => {
-        impl $core::fmt::Display for $custom
+        impl<$($lt $(: $bound)?),*> $core::fmt::Display for $custom
         where
             $inner: $core::fmt::Display,
         {
@@ -676,12 +1473,185 @@ macro_rules! impl_std_traits_for_slice {
         }
     };
 
+    // std::fmt::Display with a custom formatting function
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Display(with = $fmt:path) ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::Display for $custom {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner: &$inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                $fmt(inner, f)
+            }
+        }
+    };
+
+    // std::fmt::{LowerHex, UpperHex, Binary, Octal}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ LowerHex ];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @impl [fmt]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error);
+            rest=[ LowerHex ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ UpperHex ];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @impl [fmt]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error);
+            rest=[ UpperHex ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Binary ];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @impl [fmt]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error);
+            rest=[ Binary ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Octal ];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @impl [fmt]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error);
+            rest=[ Octal ];
+        }
+    };
+    (
+        @impl [fmt]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $trait:ident ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::$trait for $custom
+        where
+            $inner: $core::fmt::$trait,
+        {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                <$inner as $core::fmt::$trait>::fmt(inner, f)
+            }
+        }
+    };
+
+    // std::fmt::{Display, LowerHex, UpperHex} in hexadecimal for bytes
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Display(hex) ];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @impl [hex]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error);
+            rest=[ Display ]; upper=false;
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ LowerHex(bytes) ];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @impl [hex]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error);
+            rest=[ LowerHex ]; upper=false;
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ UpperHex(bytes) ];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @impl [hex]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error);
+            rest=[ UpperHex ]; upper=true;
+        }
+    };
+    (
+        @impl [hex]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $trait:ident ]; upper=$upper:literal;
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::$trait for $custom
+        where
+            $inner: $core::convert::AsRef<[u8]>,
+        {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let bytes = <$spec as $crate::SliceSpec>::as_inner(self).as_ref();
+                $crate::__private::fmt_hex(bytes, $upper, f)
+            }
+        }
+    };
+
+    // std::hash::Hash
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Hash ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::hash::Hash for $custom
+        where
+            $inner: $core::hash::Hash,
+        {
+            #[inline]
+            fn hash<H: $core::hash::Hasher>(&self, state: &mut H) {
+                let inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                <$inner as $core::hash::Hash>::hash(inner, state)
+            }
+        }
+    };
+
+    // std::hash::Hash with a custom hash function
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Hash(with = $hash:path) ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::hash::Hash for $custom {
+            #[inline]
+            fn hash<H: $core::hash::Hasher>(&self, state: &mut H) {
+                let inner: &$inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                $hash(inner, state)
+            }
+        }
+    };
+
+    // Explicit opt-out of `Hash`, checked by `Check { Borrow };`.
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ !Hash ];
+    ) => {};
+
+    // std::cmp::Ord
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Ord ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::cmp::Ord for $custom
+        where
+            $inner: $core::cmp::Ord,
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> $core::cmp::Ordering {
+                <$inner as $core::cmp::Ord>::cmp(
+                    <$spec as $crate::SliceSpec>::as_inner(self),
+                    <$spec as $crate::SliceSpec>::as_inner(other),
+                )
+            }
+        }
+    };
+
+    // Explicit opt-out of `Ord`, checked by `Check { Borrow };`.
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ !Ord ];
+    ) => {};
+
     // std::ops::Deref
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ Deref<Target = {Inner}> ];
     ) => {
-        impl $core::ops::Deref for $custom {
+        impl<$($lt $(: $bound)?),*> $core::ops::Deref for $custom {
             type Target = $inner;
 
             #[inline]
@@ -693,10 +1663,10 @@ macro_rules! impl_std_traits_for_slice {
 
     // std::ops::DerefMut
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
         rest=[ DerefMut<Target = {Inner}> ];
     ) => {
-        impl $core::ops::DerefMut for $custom {
+        impl<$($lt $(: $bound)?),*> $core::ops::DerefMut for $custom {
             #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
                 <$spec as $crate::SliceSpec>::as_inner_mut(self)
@@ -704,21 +1674,177 @@ macro_rules! impl_std_traits_for_slice {
         }
     };
 
-    // Fallback.
+    // validated_slice::ErasedValidatedSlice
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
-        rest=[ $($rest:tt)* ];
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ ErasedValidatedSlice ];
     ) => {
-        compile_error!(concat!("Unsupported target: ", stringify!($($rest)*)));
+        impl<$($lt $(: $bound)?),*> $crate::ErasedValidatedSlice for $custom
+        where
+            $inner: $core::convert::AsRef<[u8]>,
+        {
+            #[inline]
+            fn as_bytes(&self) -> &[u8] {
+                <$spec as $crate::SliceSpec>::as_inner(self).as_ref()
+            }
+
+            #[inline]
+            fn type_name(&self) -> &'static str {
+                $core::any::type_name::<$custom>()
+            }
+
+            #[inline]
+            fn revalidate(&self) -> bool {
+                <$spec as $crate::SliceSpec>::validate(<$spec as $crate::SliceSpec>::as_inner(self))
+                    .is_ok()
+            }
+        }
     };
-}
 
-/// Implements `PartialEq` and `PartialOrd` for the given custom slice type.
-///
-/// # Usage
-///
-/// ## Examples
-///
+    // std::ops::Index
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Index<usize> ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::ops::Index<usize> for $custom {
+            type Output = <$inner as $core::ops::Index<usize>>::Output;
+
+            #[inline]
+            fn index(&self, index: usize) -> &Self::Output {
+                &<$spec as $crate::SliceSpec>::as_inner(self)[index]
+            }
+        }
+    };
+
+    // Needle<{Inner}> for &{Custom}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Needle<{Inner}> for &{Custom} ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $crate::Needle<$inner> for &'__a $custom
+        where
+            $inner: $crate::SearchSlice + $crate::SliceLen,
+        {
+            #[inline]
+            fn find_in(
+                &mut self,
+                haystack: &$inner,
+            ) -> $core::option::Option<$core::ops::Range<usize>> {
+                let mut needle = <$spec as $crate::SliceSpec>::as_inner(self);
+                $crate::Needle::find_in(&mut needle, haystack)
+            }
+
+            #[inline]
+            fn match_prefix_len(&mut self, haystack: &$inner) -> $core::option::Option<usize> {
+                let mut needle = <$spec as $crate::SliceSpec>::as_inner(self);
+                $crate::Needle::match_prefix_len(&mut needle, haystack)
+            }
+
+            #[inline]
+            fn match_suffix_len(&mut self, haystack: &$inner) -> $core::option::Option<usize> {
+                let mut needle = <$spec as $crate::SliceSpec>::as_inner(self);
+                $crate::Needle::match_suffix_len(&mut needle, haystack)
+            }
+        }
+    };
+
+    // nom::{AsBytes, Compare, FindSubstring, FindToken, Input, Offset, ParseTo}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ nom::$target:ident ];
+    ) => {
+        $crate::__impl_nom! {
+            @$target; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner);
+        }
+    };
+
+    // defmt::Format
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ defmt::Format ];
+    ) => {
+        $crate::__impl_defmt! {
+            ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $inner);
+            |this| <$spec as $crate::SliceSpec>::as_inner(this)
+        }
+    };
+
+    // ufmt::{uDebug, uDisplay}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ ufmt::$trait:ident ];
+    ) => {
+        $crate::__impl_ufmt! {
+            ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $inner);
+            $trait;
+            |this| <$spec as $crate::SliceSpec>::as_inner(this)
+        }
+    };
+
+    // schemars::JsonSchema
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ JsonSchema ];
+    ) => {
+        $crate::__impl_json_schema! {
+            ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $inner);
+            spec=[]
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ JsonSchema(spec) ];
+    ) => {
+        $crate::__impl_json_schema! {
+            ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $inner);
+            spec=[$spec]
+        }
+    };
+
+    // Explicit `for {Custom}`, which is same as the default impl target.
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $($rest:tt)* ];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @strip_for_custom; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error);
+            target=[]; rest=[$($rest)*];
+        }
+    };
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[for {Custom}];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @impl; $params;
+            rest=[$($target)*];
+        }
+    };
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[$first:tt $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_slice! {
+            @strip_for_custom; $params;
+            target=[$($target)* $first]; rest=[$($rest)*];
+        }
+    };
+
+    // Fallback.
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[];
+    ) => {
+        compile_error!(concat!("Unsupported target: ", stringify!($($target)*)));
+    };
+}
+
+/// Implements `PartialEq` and `PartialOrd` for the given custom slice type.
+///
+/// # Usage
+///
+/// ## Examples
+///
 /// ```
 /// extern crate alloc;
 /// #
@@ -786,6 +1912,8 @@ macro_rules! impl_std_traits_for_slice {
 /// ## Core and alloc
 ///
 /// For `no_std` use, the macro uses custom `core` and `alloc` crate if given.
+/// If `Std { .. };` is omitted, the macro uses `core` and `alloc` crates re-exported from this
+/// crate (`alloc` requires `alloc` feature), regardless of the environment of the caller.
 /// You can support both nostd and non-nostd environment as below:
 ///
 /// ```
@@ -854,10 +1982,28 @@ macro_rules! impl_std_traits_for_slice {
 ///
 /// The syntax of `Spec` part is very similar to [`impl_std_traits_for_slice!`] macro.
 ///
-/// As `base` field, specify `Custom` or `Inner` to decide which comparison should be used
-/// internally.
+/// As `base` field, specify `Custom`, `Inner`, `Folded`, or `ConstTime` to decide which comparison
+/// should be used internally.
 /// If you don't define custom comparison, use `base: Inner`.
 ///
+/// With `base: Folded`, the inner slices are compared by [`FoldedCmpSpec`] of the spec type.
+/// This is a preset for case-insensitive comparisons and similar, and the operand types are
+/// supported as `base: Inner`.
+///
+/// With `base: ConstTime`, the inner slices are compared by [`ConstTimeEqSpec`] of the spec type.
+/// This is intended for secrets such as tokens, and supports only `Cmp { PartialEq }`.
+///
+/// `custom` and `inner` fields can be omitted as `Spec { spec: MyStrSpec, base: Inner };`.
+/// In this case, they are resolved through the associated types of [`SliceSpec`] trait.
+///
+/// Lifetime parameters and type tag parameters of the custom type can be declared by
+/// `lifetimes: ['a],` and `tags: [T],` fields at the head of `Spec { .. }`, as
+/// [`impl_std_traits_for_slice!`] macro.
+///
+/// The owned inner type (such as `String` for `str`) can be specified by optional `owned_inner`
+/// field placed just before `base`, as `owned_inner: String,`.
+/// It is required to use `{OwnedInner}` operand types.
+///
 /// ## Traits to implement
 ///
 /// Comparison traits to implement is specified by `Cmp { .. };` format.
@@ -869,22 +2015,39 @@ macro_rules! impl_std_traits_for_slice {
 /// Comparisons are implemented between two types, so you should provide list of pairs to implement
 /// comparison.
 ///
-/// Supported syntaxes are: `{ (lhs_ty), (rhs_ty) };` and `{ (lhs_ty), (rhs_ty), rev };`.
+/// Supported syntaxes are: `{ (lhs_ty), (rhs_ty) };`, `{ (lhs_ty), (rhs_ty), rev };`, and
+/// `{ (lhs_ty), (rhs_ty), rev_only };`.
 ///
 /// Parentheses around types are not omittable.
 ///
 /// With `, rev`, the macro implements not only `PartialXx<rhs_ty> for lhs_ty`, but also
 /// `PartialXx<lhs_ty> for rhs_ty`.
 ///
+/// With `, rev_only`, the macro implements only `PartialXx<lhs_ty> for rhs_ty`.
+/// This is useful when `PartialXx<rhs_ty> for lhs_ty` is already implemented elsewhere.
+///
+/// Lifetimes used only by the operand types can be declared per pair by the `for<'a>` prefix, such
+/// as `{ for<'a> ({Custom}), (Cow<'a, {Custom}>), rev };`.
+/// Declared lifetimes become parameters of the generated impls.
+///
 /// ## Type names
 ///
 /// `{Custom}` and `{Inner}` will be replaced to the custom slice type and its inner type.
 ///
-/// `&ty` and `Cow<ty>` are also supported.
+/// `&ty`, `Cow<ty>`, and `Cow<'a, ty>` are also supported.
+/// `Arc<{Inner}>`, `Box<{Inner}>`, and `Rc<{Inner}>` are also supported, and they are compared
+/// through the dereferenced inner slice.
+/// `{OwnedInner}` will be replaced to the type specified by `owned_inner` field, and it is compared
+/// through `Borrow<{Inner}>`.
 ///
 /// Note that in case you specify arbitrary types (other than `{Custom}`, `{Inner}`, and its
 /// variations), that type should implement `AsRef<base_type>`.
 ///
+/// If the arbitrary type does not implement `AsRef<base_type>`, specify the projection function by
+/// `(ty via path::to::projection)` format, such as `(url::Url via url::Url::as_str)`.
+/// The projection function should have the type `fn(&ty) -> &base_type`, where `base_type` is
+/// `{Inner}` or `{Custom}` depending on the `base` field.
+///
 /// ## Supported types
 ///
 /// * `{Custom}`
@@ -893,36 +2056,40 @@ macro_rules! impl_std_traits_for_slice {
 /// * `{Inner}`
 /// * `&{Inner}`
 /// * `Cow<{Inner}>`
+/// * `Arc<{Inner}>`
+/// * `Box<{Inner}>`
+/// * `Rc<{Inner}>`
+/// * `{OwnedInner}`
+/// * `&{OwnedInner}`
 /// * ... and arbitrary types
 ///
 /// Note that, with `base: Custom`, `{Inner}` and its variants are not supported (because it does
 /// not make sense).
 ///
+/// [`ConstTimeEqSpec`]: trait.ConstTimeEqSpec.html
+/// [`FoldedCmpSpec`]: trait.FoldedCmpSpec.html
+/// [`SliceSpec`]: trait.SliceSpec.html
 /// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
 #[macro_export]
 macro_rules! impl_cmp_for_slice {
     (
         Spec {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
             spec: $spec:ty,
             custom: $custom:ty,
             inner: $inner:ty,
+            $(owned_inner: $owned_inner:ty,)?
             base: $base:ident,
         };
         Cmp { $($cmp_targets:ident),* };
         $($rest:tt)*
     ) => {
-        $crate::impl_cmp_for_slice! {
-            @full;
-            Std {
-                core: std,
-                alloc: std,
-            };
-            Spec {
-                spec: $spec,
-                custom: $custom,
-                inner: $inner,
-                base: $base,
-            };
+        $crate::__with_default_std! {
+            $crate::impl_cmp_for_slice;
+            head=[@full;];
+            lifetimes=[$($(, $lt)*)? $($(, $tag $(: $tag_bound)?)*)?];
+            args=[$spec, $custom, $inner, $base $(, $owned_inner)?];
             Cmp { $($cmp_targets),* };
             $($rest)*
         }
@@ -933,249 +2100,1854 @@ macro_rules! impl_cmp_for_slice {
             alloc: $alloc:ident,
         };
         Spec {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
             spec: $spec:ty,
             custom: $custom:ty,
             inner: $inner:ty,
+            $(owned_inner: $owned_inner:ty,)?
             base: $base:ident,
         };
         Cmp { $($cmp_targets:ident),* };
         $($rest:tt)*
     ) => {
         $crate::impl_cmp_for_slice! {
-            @full;
-            Std {
-                core: $core,
-                alloc: $alloc,
-            };
+            @full; ({$core, $alloc $($(, $lt)*)? $($(, $tag $(: $tag_bound)?)*)?}, $spec, $custom, $inner, $base $(, $owned_inner)?);
+            Cmp { $($cmp_targets),* };
+            $($rest)*
+        }
+    };
+
+    // Spec fields inferred from the spec type.
+    (
+        Spec {
+            spec: $spec:ty,
+            $(owned_inner: $owned_inner:ty,)?
+            base: $base:ident $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_cmp_for_slice! {
             Spec {
                 spec: $spec,
-                custom: $custom,
-                inner: $inner,
+                custom: <$spec as $crate::SliceSpec>::Custom,
+                inner: <$spec as $crate::SliceSpec>::Inner,
+                $(owned_inner: $owned_inner,)?
                 base: $base,
             };
-            Cmp { $($cmp_targets),* };
             $($rest)*
         }
     };
-
     (
-        @full;
         Std {
             core: $core:ident,
             alloc: $alloc:ident,
         };
         Spec {
             spec: $spec:ty,
-            custom: $custom:ty,
-            inner: $inner:ty,
-            base: $base:ident,
+            $(owned_inner: $owned_inner:ty,)?
+            base: $base:ident $(,)?
         };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_cmp_for_slice! {
+            Std {
+                core: $core,
+                alloc: $alloc,
+            };
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::SliceSpec>::Custom,
+                inner: <$spec as $crate::SliceSpec>::Inner,
+                $(owned_inner: $owned_inner,)?
+                base: $base,
+            };
+            $($rest)*
+        }
+    };
+
+    (
+        @full; $params:tt;
         Cmp { PartialEq, PartialOrd };
-        $({ ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
+        $({ $(for<$($plt:lifetime),+>)? ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
     ) => {
         $(
             $crate::impl_cmp_for_slice! {
-                @impl[PartialEq]; ({$core, $alloc}, $spec, $custom, $inner, $base);
+                @pair[PartialEq]; $params; [$($($plt),+)?];
                 { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
             }
             $crate::impl_cmp_for_slice! {
-                @impl[PartialOrd]; ({$core, $alloc}, $spec, $custom, $inner, $base);
+                @pair[PartialOrd]; $params; [$($($plt),+)?];
                 { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
             }
         )*
     };
     (
-        @full;
-        Std {
-            core: $core:ident,
-            alloc: $alloc:ident,
-        };
-        Spec {
-            spec: $spec:ty,
-            custom: $custom:ty,
-            inner: $inner:ty,
-            base: $base:ident,
-        };
+        @full; $params:tt;
         Cmp { PartialEq };
-        $({ ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
+        $({ $(for<$($plt:lifetime),+>)? ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
     ) => {
         $(
             $crate::impl_cmp_for_slice! {
-                @impl[PartialEq]; ({$core, $alloc}, $spec, $custom, $inner, $base);
+                @pair[PartialEq]; $params; [$($($plt),+)?];
                 { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
             }
         )*
     };
     (
-        @full;
-        Std {
-            core: $core:ident,
-            alloc: $alloc:ident,
-        };
-        Spec {
-            spec: $spec:ty,
-            custom: $custom:ty,
-            inner: $inner:ty,
-            base: $base:ident,
-        };
+        @full; $params:tt;
         Cmp { PartialOrd };
-        $({ ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
+        $({ $(for<$($plt:lifetime),+>)? ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
     ) => {
         $(
             $crate::impl_cmp_for_slice! {
-                @impl[PartialOrd]; ({$core, $alloc}, $spec, $custom, $inner, $base);
+                @pair[PartialOrd]; $params; [$($($plt),+)?];
                 { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
             }
         )*
     };
 
     (
-        @impl[PartialEq]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $base:ident);
+        @impl[PartialEq]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $base:ident $(, $owned_inner:ty)?);
         { ($($lhs:tt)*), ($($rhs:tt)*) };
     ) => {
-        impl $core::cmp::PartialEq<
-            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* })
-        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($lhs)* })
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialEq<
+            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* })
+        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($lhs)* })
         {
             #[inline]
-            fn eq(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* })) -> bool {
-                $crate::impl_cmp_for_slice!(@cmp_fn[PartialEq]; ($custom, $inner, $base))(
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($lhs)* }; self),
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($rhs)* }; other),
+            fn eq(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* })) -> bool {
+                $crate::impl_cmp_for_slice!(@cmp_fn[PartialEq]; ($spec, $custom, $inner, $base))(
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($lhs)* }; self),
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($rhs)* }; other),
                 )
             }
         }
     };
     (
-        @impl[PartialEq]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $base:ident);
+        @impl[PartialEq]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $base:ident $(, $owned_inner:ty)?);
         { ($($lhs:tt)*), ($($rhs:tt)*), rev };
     ) => {
-        impl $core::cmp::PartialEq<
-            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* })
-        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($lhs)* })
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialEq<
+            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* })
+        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($lhs)* })
         {
             #[inline]
-            fn eq(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* })) -> bool {
-                $crate::impl_cmp_for_slice!(@cmp_fn[PartialEq]; ($custom, $inner, $base))(
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($lhs)* }; self),
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($rhs)* }; other),
+            fn eq(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* })) -> bool {
+                $crate::impl_cmp_for_slice!(@cmp_fn[PartialEq]; ($spec, $custom, $inner, $base))(
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($lhs)* }; self),
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($rhs)* }; other),
                 )
             }
         }
-        impl $core::cmp::PartialEq<
-            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($lhs)* })
-        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* })
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialEq<
+            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($lhs)* })
+        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* })
         {
             #[inline]
-            fn eq(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($lhs)* })) -> bool {
-                $crate::impl_cmp_for_slice!(@cmp_fn[PartialEq]; ($custom, $inner, $base))(
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($rhs)* }; self),
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($lhs)* }; other),
+            fn eq(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($lhs)* })) -> bool {
+                $crate::impl_cmp_for_slice!(@cmp_fn[PartialEq]; ($spec, $custom, $inner, $base))(
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($rhs)* }; self),
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($lhs)* }; other),
                 )
             }
         }
     };
     (
-        @impl[PartialOrd]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $base:ident);
+        @impl[PartialOrd]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $base:ident $(, $owned_inner:ty)?);
         { ($($lhs:tt)*), ($($rhs:tt)*) };
     ) => {
-        impl $core::cmp::PartialOrd<
-            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* })
-        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($lhs)* })
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialOrd<
+            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* })
+        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($lhs)* })
         {
             #[inline]
-            fn partial_cmp(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* }))
+            fn partial_cmp(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* }))
                 -> $core::option::Option<$core::cmp::Ordering>
             {
-                $crate::impl_cmp_for_slice!(@cmp_fn[PartialOrd]; ($custom, $inner, $base))(
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($lhs)* }; self),
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($rhs)* }; other),
+                $crate::impl_cmp_for_slice!(@cmp_fn[PartialOrd]; ($spec, $custom, $inner, $base))(
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($lhs)* }; self),
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($rhs)* }; other),
                 )
             }
         }
     };
     (
-        @impl[PartialOrd]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $base:ident);
+        @impl[PartialOrd]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $base:ident $(, $owned_inner:ty)?);
         { ($($lhs:tt)*), ($($rhs:tt)*), rev };
     ) => {
-        impl $core::cmp::PartialOrd<
-            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* })
-        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($lhs)* })
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialOrd<
+            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* })
+        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($lhs)* })
         {
             #[inline]
-            fn partial_cmp(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* }))
+            fn partial_cmp(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* }))
                 -> $core::option::Option<$core::cmp::Ordering>
             {
-                $crate::impl_cmp_for_slice!(@cmp_fn[PartialOrd]; ($custom, $inner, $base))(
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($lhs)* }; self),
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($rhs)* }; other),
+                $crate::impl_cmp_for_slice!(@cmp_fn[PartialOrd]; ($spec, $custom, $inner, $base))(
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($lhs)* }; self),
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($rhs)* }; other),
                 )
             }
         }
-        impl $core::cmp::PartialOrd<
-            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($lhs)* })
-        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($rhs)* })
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialOrd<
+            $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($lhs)* })
+        > for $crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($rhs)* })
         {
             #[inline]
-            fn partial_cmp(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner); { $($lhs)* }))
+            fn partial_cmp(&self, other: &$crate::impl_cmp_for_slice!(@type; ({$core, $alloc}, $custom, $inner $(, $owned_inner)?); { $($lhs)* }))
                 -> $core::option::Option<$core::cmp::Ordering>
             {
-                $crate::impl_cmp_for_slice!(@cmp_fn[PartialOrd]; ($custom, $inner, $base))(
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($rhs)* }; self),
-                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($lhs)* }; other),
+                $crate::impl_cmp_for_slice!(@cmp_fn[PartialOrd]; ($spec, $custom, $inner, $base))(
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($rhs)* }; self),
+                    $crate::impl_cmp_for_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner $(, $owned_inner)?); { $($lhs)* }; other),
                 )
             }
         }
     };
 
-    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty); { {Custom} }) => { $custom };
-    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty); { &{Custom} }) => { &$custom };
-    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty); { Cow<{Custom}> }) => { $alloc::borrow::Cow<'_, $custom> };
-    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty); { {Inner} }) => { $inner };
-    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty); { &{Inner} }) => { &$inner };
-    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty); { Cow<{Inner}> }) => { $alloc::borrow::Cow<'_, $inner> };
-    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty); { $ty:ty }) => { $ty };
+    (
+        @pair[$cmp:ident]; ({$core:ident, $alloc:ident $(, $lt:lifetime)* $(, $tag:ident $(: $tag_bound:path)?)*}, $($params:tt)*);
+        [$($plt:lifetime),*]; $pair:tt;
+    ) => {
+        $crate::impl_cmp_for_slice! {
+            @impl[$cmp]; ({$core, $alloc $(, $lt)* $(, $plt)* $(, $tag $(: $tag_bound)?)*}, $($params)*);
+            $pair;
+        }
+    };
+    (
+        @impl[$cmp:ident]; $params:tt;
+        { ($($lhs:tt)*), ($($rhs:tt)*), rev_only };
+    ) => {
+        $crate::impl_cmp_for_slice! {
+            @impl[$cmp]; $params;
+            { ($($rhs)*), ($($lhs)*) };
+        }
+    };
+
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { {Custom} }) => { $custom };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { &{Custom} }) => { &$custom };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<{Custom}> }) => { $alloc::borrow::Cow<'_, $custom> };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<$l:lifetime, {Custom}> }) => { $alloc::borrow::Cow<$l, $custom> };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { {Inner} }) => { $inner };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { &{Inner} }) => { &$inner };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<{Inner}> }) => { $alloc::borrow::Cow<'_, $inner> };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<$l:lifetime, {Inner}> }) => { $alloc::borrow::Cow<$l, $inner> };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $owned_inner:ty); { {OwnedInner} }) => { $owned_inner };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $owned_inner:ty); { &{OwnedInner} }) => { &$owned_inner };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty); { $(&)? {OwnedInner} }) => {
+        compile_error!("`{OwnedInner}` requires `owned_inner` field in `Spec { .. }`")
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Arc<{Inner}> }) => { $alloc::sync::Arc<$inner> };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Box<{Inner}> }) => { $alloc::boxed::Box<$inner> };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Rc<{Inner}> }) => { $alloc::rc::Rc<$inner> };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { $ty:ty }) => { $ty };
+
+    (@cmp_fn[PartialEq]; ($spec:ty, $custom:ty, $inner:ty, Inner)) => { <$inner as core::cmp::PartialEq<$inner>>::eq };
+    (@cmp_fn[PartialEq]; ($spec:ty, $custom:ty, $inner:ty, Custom)) => { <$custom as core::cmp::PartialEq<$custom>>::eq };
+    (@cmp_fn[PartialEq]; ($spec:ty, $custom:ty, $inner:ty, Folded)) => { <$spec as $crate::FoldedCmpSpec>::eq_folded };
+    (@cmp_fn[PartialOrd]; ($spec:ty, $custom:ty, $inner:ty, Inner)) => { <$inner as core::cmp::PartialOrd<$inner>>::partial_cmp };
+    (@cmp_fn[PartialOrd]; ($spec:ty, $custom:ty, $inner:ty, Custom)) => { <$custom as core::cmp::PartialOrd<$custom>>::partial_cmp };
+    (@cmp_fn[PartialOrd]; ($spec:ty, $custom:ty, $inner:ty, Folded)) => {
+        $crate::__private::partial_cmp_folded::<$spec>
+    };
+    (@cmp_fn[PartialEq]; ($spec:ty, $custom:ty, $inner:ty, ConstTime)) => {
+        <$spec as $crate::ConstTimeEqSpec>::eq_const_time
+    };
+    (@cmp_fn[PartialOrd]; ($spec:ty, $custom:ty, $inner:ty, ConstTime)) => {
+        compile_error!("`base: ConstTime` supports only `PartialEq`")
+    };
 
-    (@cmp_fn[PartialEq]; ($custom:ty, $inner:ty, Inner)) => { <$inner as core::cmp::PartialEq<$inner>>::eq };
-    (@cmp_fn[PartialEq]; ($custom:ty, $inner:ty, Custom)) => { <$custom as core::cmp::PartialEq<$custom>>::eq };
-    (@cmp_fn[PartialOrd]; ($custom:ty, $inner:ty, Inner)) => { <$inner as core::cmp::PartialOrd<$inner>>::partial_cmp };
-    (@cmp_fn[PartialOrd]; ($custom:ty, $inner:ty, Custom)) => { <$custom as core::cmp::PartialOrd<$custom>>::partial_cmp };
+    // `base: Folded` compares the inner slices, as `base: Inner`.
+    (@expr[Folded]; $params:tt; $ty:tt; $expr:expr) => {
+        $crate::impl_cmp_for_slice!(@expr[Inner]; $params; $ty; $expr)
+    };
+    // `base: ConstTime` compares the inner slices, as `base: Inner`.
+    (@expr[ConstTime]; $params:tt; $ty:tt; $expr:expr) => {
+        $crate::impl_cmp_for_slice!(@expr[Inner]; $params; $ty; $expr)
+    };
 
-    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { {Custom} }; $expr:expr) => {
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { {Custom} }; $expr:expr) => {
         <$spec as $crate::SliceSpec>::as_inner($expr)
     };
-    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { &{Custom} }; $expr:expr) => {
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { &{Custom} }; $expr:expr) => {
         <$spec as $crate::SliceSpec>::as_inner(*$expr)
     };
-    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { Cow<{Custom}> }; $expr:expr) => {
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<{Custom}> }; $expr:expr) => {
+        <$spec as $crate::SliceSpec>::as_inner(&**$expr)
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<$l:lifetime, {Custom}> }; $expr:expr) => {
         <$spec as $crate::SliceSpec>::as_inner(&**$expr)
     };
-    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { {Inner} }; $expr:expr) => {
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { {Inner} }; $expr:expr) => {
         $expr
     };
-    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { &{Inner} }; $expr:expr) => {
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { &{Inner} }; $expr:expr) => {
         *$expr
     };
-    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { Cow<{Inner}> }; $expr:expr) => {
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<{Inner}> }; $expr:expr) => {
+        &**$expr
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<$l:lifetime, {Inner}> }; $expr:expr) => {
+        &**$expr
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $owned_inner:ty); { {OwnedInner} }; $expr:expr) => {
+        $core::borrow::Borrow::<$inner>::borrow($expr)
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $owned_inner:ty); { &{OwnedInner} }; $expr:expr) => {
+        $core::borrow::Borrow::<$inner>::borrow(*$expr)
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Arc<{Inner}> }; $expr:expr) => {
+        &**$expr
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Box<{Inner}> }; $expr:expr) => {
+        &**$expr
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Rc<{Inner}> }; $expr:expr) => {
         &**$expr
     };
-    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { $ty:ty }; $expr:expr) => {
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { $ty:ty }; $expr:expr) => {
         $core::convert::AsRef::<$inner>::as_ref($expr)
     };
-    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { {Custom} }; $expr:expr) => {
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { {Custom} }; $expr:expr) => {
         $expr
     };
-    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { &{Custom} }; $expr:expr) => {
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { &{Custom} }; $expr:expr) => {
         *$expr
     };
-    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { Cow<{Custom}> }; $expr:expr) => {
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<{Custom}> }; $expr:expr) => {
+        &**$expr
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { Cow<$l:lifetime, {Custom}> }; $expr:expr) => {
         &**$expr
     };
-    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { $ty:ty }; $expr:expr) => {
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty $(, $owned_inner:ty)?); { $ty:ty }; $expr:expr) => {
         $core::convert::AsRef::<$custom>::as_ref($expr)
     };
 
+    // Operand types with projections: `(ty via path::to::projection)`.
+    (@type; $params:tt; { $($ty:tt)+ }) => {
+        $crate::impl_cmp_for_slice!(@via[type]; []; $($ty)+)
+    };
+    (@expr[$base:ident]; $params:tt; { $($ty:tt)+ }; $expr:expr) => {
+        $crate::impl_cmp_for_slice!(@via[expr]; $expr; []; $($ty)+)
+    };
+    (@via[type]; [$($ty:tt)+]; via $proj:path) => { $($ty)+ };
+    (@via[type]; [$($ty:tt)*]; $first:tt $($rest:tt)*) => {
+        $crate::impl_cmp_for_slice!(@via[type]; [$($ty)* $first]; $($rest)*)
+    };
+    (@via[expr]; $expr:expr; [$($ty:tt)+]; via $proj:path) => { $proj($expr) };
+    (@via[expr]; $expr:expr; [$($ty:tt)*]; $first:tt $($rest:tt)*) => {
+        $crate::impl_cmp_for_slice!(@via[expr]; $expr; [$($ty)* $first]; $($rest)*)
+    };
+
     ($($rest:tt)*) => {
         compile_error!(stringify!($($rest)*));
     };
 }
+
+/// Implements std traits and comparisons for the given custom slice type in one invocation.
+///
+/// This is a shorthand for [`impl_std_traits_for_slice!`] and [`impl_cmp_for_slice!`] macros
+/// sharing the same `Std` and `Spec` parts.
+///
+/// # Usage
+///
+/// ```
+/// # #[repr(transparent)]
+/// # pub struct MyStr([u8]);
+/// # enum MyStrSpec {}
+/// # impl validated_slice::SliceSpec for MyStrSpec {
+/// #     type Custom = MyStr;
+/// #     type Inner = [u8];
+/// #     type Error = MyUtf8Error;
+/// #     fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+/// #         Ok(())
+/// #     }
+/// #     validated_slice::impl_slice_spec_methods! {
+/// #         field=0;
+/// #         methods=[
+/// #             as_inner,
+/// #             as_inner_mut,
+/// #             from_inner_unchecked,
+/// #             from_inner_unchecked_mut,
+/// #         ];
+/// #     }
+/// # }
+/// # #[derive(Debug)]
+/// # pub struct MyUtf8Error;
+/// validated_slice::impl_traits_for_slice! {
+///     // `Std` is omissible.
+///     Std {
+///         core: core,
+///         alloc: alloc,
+///     };
+///     Spec {
+///         spec: MyStrSpec,
+///         custom: MyStr,
+///         inner: [u8],
+///         error: MyUtf8Error,
+///     };
+///     // Same as the targets of `impl_std_traits_for_slice!`.
+///     // `Traits` is omissible.
+///     Traits {
+///         { AsRef<[u8]> };
+///         { TryFrom<&{Inner}> for &{Custom} };
+///         { Debug };
+///     };
+///     // Same as `base` field, `Cmp` part, and operand type pairs of `impl_cmp_for_slice!`.
+///     // `Cmp` is omissible.
+///     Cmp(base = Inner) { PartialEq, PartialOrd } {
+///         { ({Custom}), ({Custom}) };
+///         { ({Custom}), (&{Inner}), rev };
+///     };
+/// }
+/// ```
+///
+/// Spec fields other than `spec` can be omitted in the same way as the underlying macros.
+///
+/// [`impl_cmp_for_slice!`]: macro.impl_cmp_for_slice.html
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+#[macro_export]
+macro_rules! impl_traits_for_slice {
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_traits_for_slice! {
+            @full; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            $($rest)*
+        }
+    };
+    (
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::__with_default_std! {
+            $crate::impl_traits_for_slice;
+            head=[@full;];
+            lifetimes=[];
+            args=[$spec, $custom, $inner, $error];
+            $($rest)*
+        }
+    };
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_traits_for_slice! {
+            @full; ({$core, $alloc}, $spec, <$spec as $crate::SliceSpec>::Custom,
+                <$spec as $crate::SliceSpec>::Inner, <$spec as $crate::SliceSpec>::Error);
+            $($rest)*
+        }
+    };
+    (
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::__with_default_std! {
+            $crate::impl_traits_for_slice;
+            head=[@full;];
+            lifetimes=[];
+            args=[$spec, <$spec as $crate::SliceSpec>::Custom,
+                <$spec as $crate::SliceSpec>::Inner, <$spec as $crate::SliceSpec>::Error];
+            $($rest)*
+        }
+    };
+
+    (
+        @full; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        $(Traits { $($traits:tt)* };)?
+        $(Cmp(base = $base:ident) { $($cmp:ident),* } { $($pairs:tt)* };)?
+    ) => {
+        $(
+            $crate::impl_std_traits_for_slice! {
+                Std {
+                    core: $core,
+                    alloc: $alloc,
+                };
+                Spec {
+                    spec: $spec,
+                    custom: $custom,
+                    inner: $inner,
+                    error: $error,
+                };
+                $($traits)*
+            }
+        )?
+        $(
+            $crate::impl_cmp_for_slice! {
+                Std {
+                    core: $core,
+                    alloc: $alloc,
+                };
+                Spec {
+                    spec: $spec,
+                    custom: $custom,
+                    inner: $inner,
+                    base: $base,
+                };
+                Cmp { $($cmp),* };
+                $($pairs)*
+            }
+        )?
+    };
+
+    ($($rest:tt)*) => {
+        compile_error!(stringify!($($rest)*));
+    };
+}
+
+/// Implements inherent methods for the given custom slice type.
+///
+/// # Usage
+///
+/// ## Examples
+///
+/// ```
+/// /// Sorted `[u32]` slice.
+/// #[repr(transparent)]
+/// pub struct SortedU32Slice([u32]);
+///
+/// /// Spec for `SortedU32Slice` type.
+/// enum SortedU32SliceSpec {}
+///
+/// impl validated_slice::SliceSpec for SortedU32SliceSpec {
+///     // Custom slice type.
+///     type Custom = SortedU32Slice;
+///     // Backend type of `SortedU32Slice`.
+///     type Inner = [u32];
+///     // Unsorted error.
+///     type Error = UnsortedError;
+///
+///     // ... and methods.
+/// #   fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+/// #       if s.windows(2).all(|w| w[0] <= w[1]) {
+/// #           Ok(())
+/// #       } else {
+/// #           Err(UnsortedError)
+/// #       }
+/// #   }
+/// #   validated_slice::impl_slice_spec_methods! {
+/// #       field=0;
+/// #       methods=[
+/// #           as_inner,
+/// #           as_inner_mut,
+/// #           from_inner_unchecked,
+/// #           from_inner_unchecked_mut,
+/// #       ];
+/// #   }
+/// }
+/// # struct UnsortedError;
+///
+/// validated_slice::impl_methods_for_slice! {
+///     // `Std` is omissible.
+///     Std {
+///         // Module identifier of `core` crate.
+///         // Default is `std`.
+///         core: core,
+///         // Module identifier of `alloc` crate.
+///         // Default is `std`.
+///         alloc: alloc,
+///     };
+///     Spec {
+///         spec: SortedU32SliceSpec,
+///         custom: SortedU32Slice,
+///         inner: [u32],
+///         error: UnsortedError,
+///     };
+///     { pub fn get };
+///     /* ... and more methods you want! */
+/// }
+/// ```
+///
+/// The syntax of `Std` and `Spec` part is same as [`impl_std_traits_for_slice!`] macro, except
+/// that spec fields cannot be omitted, because inherent impls require the type name.
+///
+/// ## Supported methods
+///
+/// Each method is specified by `{ vis fn method_name };` format.
+/// `vis` is the visibility of the generated method, and it can be omitted (private).
+///
+/// Supported methods are:
+///
+/// * `{ fn get };`
+///     + `fn get<I>(&self, index: I) -> Option<&<I as SliceIndex<{Inner}>>::Output>`.
+///     + This delegates to `get` method of `{Inner}`, so the inner type should be a slice or `str`.
+/// * `{ fn subslice };`
+///     + `fn subslice<I>(&self, index: I) -> Option<&{Custom}>`
+///       where `{Spec}: SliceClosedSpec` and `I: SliceIndex<{Inner}, Output = {Inner}>`.
+///     + The sub-slice is not validated, since `{Spec}` is closed under sub-slicing.
+/// * `{ fn first };`, `{ fn last };`
+///     + `fn first(&self) -> &T` where `{Spec}: NonEmptySpec`.
+///       `last` has the same signature.
+///     + The inner type should be a slice `[T]`.
+/// * `{ fn first_char };`, `{ fn last_char };`
+///     + `fn first_char(&self) -> char` where `{Spec}: NonEmptySpec`.
+///       `last_char` has the same signature.
+///     + The inner type should be `str`.
+/// * `{ fn try_modify };`, `{ fn try_modify(region) };`
+///     + `fn try_modify<F, R>(&mut self, index: usize, f: F) -> Result<R, {Error}>`
+///       where `F: FnOnce(&mut T) -> R` and `T` is the element type.
+///     + This modifies the element at the given index by `f`, and then validates the slice.
+///       If the validation failed, the element is restored and the error is returned.
+///     + `{ fn try_modify };` validates the whole slice by `SliceSpec::validate`.
+///     + `{ fn try_modify(region) };` validates the slice by
+///       `RevalidateRegionSpec::revalidate_region` with the region `index..(index + 1)`, and
+///       requires `{Spec}: RevalidateRegionSpec`.
+///     + `f` is called with a clone of the element, so the element type should implement `Clone`.
+///     + This panics if the index is out of bounds.
+///     + If `f` or the validation panics, the element is left unchanged, so the slice is never
+///       observed in the invalid state even after the panic is caught.
+/// * `{ fn as_ptr };`
+///     + `fn as_ptr(&self) -> *const c_char` where `{Inner}: AsRef<CStr>`.
+///     + This is intended for `CStr`-backed custom slice types to be passed to FFI functions.
+/// * `{ fn as_bytes };`, `{ fn as_bytes(name = method_name) };`
+///     + `fn as_bytes(&self) -> &[u8]` where `{Inner}: AsRef<[u8]>`.
+/// * `{ fn as_raw };`, `{ fn as_raw(name = method_name) };`
+///     + `fn as_raw(&self) -> &{Inner}`.
+///     + These give access to the raw representation without `AsRef` impls for the custom type.
+///     + The method name can be changed by `name = method_name`, for example
+///       `{ pub fn as_raw(name = as_str) };`.
+/// * `{ fn len };`, `{ fn is_empty };`
+///     + `fn len(&self) -> usize` and `fn is_empty(&self) -> bool`.
+///     + The inner type should have the inherent methods with the same names, such as `str` and
+///       `[T]`.
+/// * `{ fn chars };`, `{ fn char_indices };`, `{ fn bytes };`
+///     + `fn chars(&self) -> Chars<'_>`, `fn char_indices(&self) -> CharIndices<'_>`, and
+///       `fn bytes(&self) -> Bytes<'_>`.
+///     + The inner type should be `str`.
+/// * `{ fn iter };`
+///     + `fn iter<'a>(&'a self) -> <&'a {Inner} as IntoIterator>::IntoIter`.
+/// * `{ fn forward(str) };`, `{ fn forward(slice) };`
+///     + Presets of the read-only methods above, for custom types without `Deref` impls.
+///     + `forward(str)` generates `len`, `is_empty`, `chars`, `char_indices`, and `bytes`.
+///     + `forward(slice)` generates `len`, `is_empty`, and `iter`.
+/// * `{ fn new_unchecked };`
+///     + `unsafe fn new_unchecked(s: &{Inner}) -> &{Custom}`.
+///     + The caller should ensure that `{Spec}::validate(s)` returns `Ok(())`.
+///       The generated document states this with the actual spec type.
+/// * `{ fn new };`
+///     + `fn new(s: &{Inner}) -> Result<&{Custom}, {Error}>`.
+///     + This validates the inner slice, as `TryFrom<&{Inner}> for &{Custom}` does.
+/// * `{ fn constructors(vis = visibility) };`, `{ fn constructors };`
+///     + Preset of the constructors `new`, `new_unchecked`, and `from_proof`.
+///     + The constructors are generated with the given visibility, for example
+///       `{ fn constructors(vis = pub(crate)) };`.
+///       `{ pub fn constructors };` is same as `{ fn constructors(vis = pub) };`.
+///     + This is useful to control which construction surface is exported, when the field of
+///       the custom slice type is private.
+/// * `{ fn contains };`, `{ fn starts_with };`, `{ fn ends_with };`
+///     + `fn contains<N>(&self, needle: N) -> bool` where `N: Needle<{Inner}>`.
+///       `starts_with` and `ends_with` have the same signature.
+///     + The needle can be the inner slice, a character, a closure, or the custom slice (with
+///       `{ Needle<{Inner}> for &{Custom} };` of [`impl_std_traits_for_slice!`]).
+///       See [`Needle`] trait for details.
+/// * `{ fn find };`
+///     + `fn find<N>(&self, needle: N) -> Option<usize>` where `N: Needle<{Inner}>`.
+///     + This returns the index of the first match of the needle.
+///       For `str`, the index is a byte position.
+/// * `{ fn strip_prefix };`, `{ fn strip_suffix };`
+///     + `fn strip_prefix<N>(&self, affix: N) -> Option<&{Custom}>`
+///       where `N: Needle<{Inner}>` and `{Spec}: SliceClosedSpec`.
+///       `strip_suffix` has the same signature.
+///     + The remainder is not validated again, since `{Spec}` is closed under sub-slicing.
+///     + The needle reports only the length of the match, and the remainder is created by
+///       indexing the inner slice. These panic if the reported length is out of range.
+/// * `{ fn strip_prefix(revalidate) };`, `{ fn strip_suffix(revalidate) };`
+///     + `fn strip_prefix<N>(&self, affix: N) -> Option<Result<&{Custom}, {Error}>>`
+///       where `N: Needle<{Inner}>`.
+///       `strip_suffix` has the same signature.
+///     + The remainder is validated, so `{Spec}` is not required to implement
+///       `SliceClosedSpec`.
+/// * `{ fn eq_inner };`
+///     + `fn eq_inner(&self, other: &{Inner}) -> bool` where `{Inner}: PartialEq`.
+/// * `{ fn eq_ignore_ascii_case };`
+///     + `fn eq_ignore_ascii_case(&self, other: &{Inner}) -> bool`.
+///     + The inner type should be `str` or `[u8]`.
+///     + These are lightweight alternatives to `PartialEq` impls between the custom slice and the
+///       inner slice, and are handy in `matches!` guards and iterator filters.
+/// * `{ fn eq_exact };`
+///     + `fn eq_exact(&self, other: &Self) -> bool` where `{Inner}: PartialEq`.
+///     + This compares the inner slices exactly, even if `PartialEq` of the custom slice is
+///       implemented by the folded comparison (`base: Folded` of [`impl_cmp_for_slice!`]).
+/// * `{ fn from_proof };`
+///     + `fn from_proof(proof: Validated<'_, {Spec}>) -> &{Custom}`.
+///     + The proof is created by [`SliceSpec::check`] or [`Validated::new`], so that the custom
+///       slice can be created without `unsafe` codes.
+/// * `{ fn display_base64 };` (requires `base64` feature)
+///     + `fn display_base64(&self) -> Base64Display<'_>` where `{Inner}: AsRef<[u8]>`.
+///     + The returned [`Base64Display`] displays the bytes in base64 with the standard alphabet.
+/// * `{ fn alloc_validated };` (requires `bumpalo` feature)
+///     + `fn alloc_validated<'b>(bump: &'b Bump, s: &{Inner}) -> Result<&'b {Custom}, {Error}>`.
+///     + This validates the slice and copies it into the arena.
+///     + The inner type should be `str` or `[T]` where `T: Copy`.
+/// * `{ fn alloc_in };` (requires `bumpalo` feature)
+///     + `fn alloc_in<'b>(&self, bump: &'b Bump) -> &'b {Custom}`.
+///     + This copies the slice into the arena without validation.
+/// * `{ fn windows };`, `{ fn chunks };`
+///     + `fn windows(&self, size: usize) -> SubSlices<'_, {Spec}, Windows<'_, T>>`
+///       where `{Spec}: SliceClosedSpec`.
+///       `chunks` has the same signature, except that the inner iterator is `Chunks<'_, T>`.
+///     + The inner type should be a slice `[T]`.
+///     + The iterator yields `&{Custom}` without validation, since `{Spec}` is closed under
+///       sub-slicing.
+///     + These panic if `size` is 0.
+/// * `{ fn binary_search };`
+///     + `fn binary_search(&self, x: &T) -> Result<usize, usize>` where `T: Ord`.
+///     + The inner type should be a slice `[T]`.
+/// * `{ fn binary_search_by };`
+///     + `fn binary_search_by<'a, F>(&'a self, f: F) -> Result<usize, usize>`
+///       where `F: FnMut(&'a T) -> Ordering`.
+///     + The inner type should be a slice `[T]`.
+/// * `{ fn contains_sorted };`
+///     + `fn contains_sorted(&self, x: &T) -> bool` where `{Spec}: SortedSliceSpec` and `T: Ord`.
+///     + This uses binary search, so the spec should guarantee that the elements are sorted.
+///     + The inner type should be a slice `[T]`.
+/// * `{ fn from_ptr };`
+///     + `unsafe fn from_ptr<'a>(ptr: *const c_char) -> Result<&'a {Custom}, {Error}>`
+///       where `&'a {Inner}: From<&'a CStr>`.
+///     + This creates a `CStr` by `CStr::from_ptr`, and then validates it.
+///     + The caller should satisfy the safety condition of `CStr::from_ptr`.
+/// * `{ fn try_arc_from };`
+///     + `fn try_arc_from<'a>(s: &'a {Inner}) -> Result<Arc<{Custom}>, {Error}>`
+///       where `Arc<{Inner}>: From<&'a {Inner}>`.
+/// * `{ fn try_rc_from };`
+///     + `fn try_rc_from<'a>(s: &'a {Inner}) -> Result<Rc<{Custom}>, {Error}>`
+///       where `Rc<{Inner}>: From<&'a {Inner}>`.
+///     + These allocate the reference-counted slice once, and then validate it.
+///     + These are provided as inherent methods instead of `TryFrom` impls, because
+///       `TryFrom<&{Inner}> for Arc<{Custom}>` cannot be implemented for a foreign `{Inner}`
+///       due to the orphan rule.
+/// * `{ fn try_from_reporting };`
+///     + `fn try_from_reporting(s: &{Inner}) -> Result<&{Custom}, Vec<{Error}>>`.
+///     + This validates the inner slice, and returns all the violations reported by
+///       `SliceSpec::validate_all` if it is invalid.
+///     + The returned vector is not empty, since the error by `SliceSpec::validate` is used if
+///       `SliceSpec::validate_all` reports nothing.
+/// * `{ fn make_mut_arc };`
+///     + `fn make_mut_arc(this: &mut Arc<{Custom}>) -> &mut {Custom}`
+///       where `Arc<{Inner}>: for<'b> From<&'b {Inner}>`.
+/// * `{ fn make_mut_rc };`
+///     + `fn make_mut_rc(this: &mut Rc<{Custom}>) -> &mut {Custom}`
+///       where `Rc<{Inner}>: for<'b> From<&'b {Inner}>`.
+///     + These work like `Arc::make_mut` and `Rc::make_mut`: if the reference-counted slice is
+///       shared, the buffer is cloned (without revalidation) and `this` is replaced with the
+///       clone.
+///     + The returned `&mut {Custom}` only allows the validity-preserving mutations, such as
+///       `try_modify`, so the edited value is always revalidated.
+/// * `{ fn weak_arc };`, `{ fn weak_rc };`
+///     + `weak_arc` generates `fn downgrade_arc(this: &Arc<{Custom}>) -> sync::Weak<{Custom}>`
+///       and `fn upgrade_arc(weak: &sync::Weak<{Custom}>) -> Option<Arc<{Custom}>>`.
+///     + `weak_rc` generates `fn downgrade_rc(this: &Rc<{Custom}>) -> rc::Weak<{Custom}>` and
+///       `fn upgrade_rc(weak: &rc::Weak<{Custom}>) -> Option<Rc<{Custom}>>`.
+///     + These are handy for caches which hold the shared custom slices weakly.
+///       `From<&Arc<{Custom}>> for Weak<{Custom}>` cannot be implemented due to the orphan rule,
+///       but `Arc::downgrade` and `Weak::upgrade` also work as usual.
+///
+/// [`Base64Display`]: struct.Base64Display.html
+/// [`impl_cmp_for_slice!`]: macro.impl_cmp_for_slice.html
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+/// [`Needle`]: trait.Needle.html
+/// [`SliceSpec::check`]: trait.SliceSpec.html#method.check
+/// [`Validated::new`]: struct.Validated.html#method.new
+#[macro_export]
+macro_rules! impl_methods_for_slice {
+    (
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty,
+        };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $(
+            $crate::__with_default_std! {
+                $crate::impl_methods_for_slice;
+                head=[@impl;];
+                lifetimes=[];
+                args=[$spec, $custom, $inner, $error];
+                rest=[$($rest)*];
+            }
+        )*
+    };
+
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty,
+        };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $(
+            $crate::impl_methods_for_slice! {
+                @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+                rest=[$($rest)*];
+            }
+        )*
+    };
+
+    // get
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn get ];
+    ) => {
+        impl $custom {
+            /// Returns a reference to an element or subslice of the inner slice, or `None` if the
+            /// index is out of bounds.
+            #[inline]
+            $vis fn get<I>(
+                &self,
+                index: I,
+            ) -> $core::option::Option<&<I as $core::slice::SliceIndex<$inner>>::Output>
+            where
+                I: $core::slice::SliceIndex<$inner>,
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).get(index)
+            }
+        }
+    };
+
+    // subslice
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn subslice ];
+    ) => {
+        impl $custom {
+            /// Returns a sub-slice as the custom slice, or `None` if the index is out of bounds.
+            ///
+            /// The sub-slice is not validated, since the spec is closed under sub-slicing.
+            #[inline]
+            $vis fn subslice<I>(&self, index: I) -> $core::option::Option<&Self>
+            where
+                $spec: $crate::SliceClosedSpec,
+                I: $core::slice::SliceIndex<$inner, Output = $inner>,
+            {
+                let sub = <$spec as $crate::SliceSpec>::as_inner(self).get(index)?;
+                Some(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(sub)` returns `Ok(())`.
+                    //     + This is ensured by `$spec: SliceClosedSpec`, because `sub` is a
+                    //       sub-slice of the valid slice.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $spec, sub)
+                })
+            }
+        }
+    };
+
+    // first, last, first_char, last_char
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn first ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [nonempty]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn first -> { first() }: &<$inner as $core::ops::Index<usize>>::Output ];
+            doc="Returns the first element of the slice.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn last ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [nonempty]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn last -> { last() }: &<$inner as $core::ops::Index<usize>>::Output ];
+            doc="Returns the last element of the slice.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn first_char ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [nonempty]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn first_char -> { chars().next() }: char ];
+            doc="Returns the first character of the string.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn last_char ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [nonempty]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn last_char -> { chars().next_back() }: char ];
+            doc="Returns the last character of the string.";
+        }
+    };
+    (
+        @impl [nonempty]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn $method:ident -> { $($getter:tt)* }: $ret:ty ];
+        doc=$doc:literal;
+    ) => {
+        impl $custom {
+            #[doc = $doc]
+            ///
+            /// This never fails, since the spec guarantees that the slice is not empty.
+            #[inline]
+            $vis fn $method(&self) -> $ret
+            where
+                $spec: $crate::NonEmptySpec,
+            {
+                match <$spec as $crate::SliceSpec>::as_inner(self).$($getter)* {
+                    Some(v) => v,
+                    None => unreachable!(concat!(
+                        "`", stringify!($spec), "` implements `NonEmptySpec` but the slice is empty"
+                    )),
+                }
+            }
+        }
+    };
+
+    // try_modify
+    (
+        @impl; $params:tt;
+        rest=[ $vis:vis fn try_modify ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @try_modify[validate]; $params; $vis
+        }
+    };
+    (
+        @impl; $params:tt;
+        rest=[ $vis:vis fn try_modify(region) ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @try_modify[region]; $params; $vis
+        }
+    };
+    (@try_modify_check[validate]; $spec:ty, $s:expr, $edited:expr) => {
+        <$spec as $crate::SliceSpec>::validate($s)
+    };
+    (@try_modify_check[region]; $spec:ty, $s:expr, $edited:expr) => {
+        <$spec as $crate::RevalidateRegionSpec>::revalidate_region($s, $edited)
+    };
+    (
+        @try_modify[$check:ident]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty,
+            $error:ty);
+        $vis:vis
+    ) => {
+        impl $custom {
+            /// Modifies the element at the given index, and validates the result.
+            ///
+            /// If the validation failed, the element is restored and the error is returned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the index is out of bounds.
+            ///
+            /// If `f` or the revalidation panics, the element is left unchanged.
+            $vis fn try_modify<F, R>(&mut self, index: usize, f: F) -> $core::result::Result<R, $error>
+            where
+                $inner: $core::ops::IndexMut<usize>,
+                <$inner as $core::ops::Index<usize>>::Output: $core::clone::Clone + $core::marker::Sized,
+                F: $core::ops::FnOnce(&mut <$inner as $core::ops::Index<usize>>::Output) -> R,
+            {
+                // Call `f` with a clone, so that the slice is kept valid even if `f` panics.
+                let mut new = <$spec as $crate::SliceSpec>::as_inner(self)[index].clone();
+                let ret = f(&mut new);
+                // The slice is kept valid when the mutable borrow ends, because the guard restores
+                // the element unless the validation succeeded, even if the validation panics.
+                let guard = $crate::__private::ElementRestoreGuard::replace(
+                    <$spec as $crate::SliceSpec>::as_inner_mut(self),
+                    index,
+                    new,
+                );
+                $crate::impl_methods_for_slice!(
+                    @try_modify_check[$check]; $spec, guard.slice(), index..(index + 1)
+                )?;
+                guard.commit();
+                Ok(ret)
+            }
+        }
+    };
+
+    // as_ptr
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn as_ptr ];
+    ) => {
+        impl $custom {
+            /// Returns the inner pointer to the C string.
+            #[inline]
+            $vis fn as_ptr(&self) -> *const $core::ffi::c_char
+            where
+                $inner: $core::convert::AsRef<$core::ffi::CStr>,
+            {
+                let inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                $core::convert::AsRef::<$core::ffi::CStr>::as_ref(inner).as_ptr()
+            }
+        }
+    };
+
+    // as_bytes, as_raw
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn as_bytes ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn as_bytes(name = as_bytes) ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn as_bytes(name = $name:ident) ];
+    ) => {
+        impl $custom {
+            /// Returns the byte representation of the slice.
+            #[inline]
+            $vis fn $name(&self) -> &[u8]
+            where
+                $inner: $core::convert::AsRef<[u8]>,
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).as_ref()
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn as_raw ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn as_raw(name = as_raw) ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn as_raw(name = $name:ident) ];
+    ) => {
+        impl $custom {
+            /// Returns the inner slice.
+            #[inline]
+            $vis fn $name(&self) -> &$inner {
+                <$spec as $crate::SliceSpec>::as_inner(self)
+            }
+        }
+    };
+
+    // forward(str), forward(slice), len, is_empty, chars, char_indices, bytes, iter
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn forward(str) ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn len ];
+        }
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn is_empty ];
+        }
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn chars ];
+        }
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn char_indices ];
+        }
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn bytes ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn forward(slice) ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn len ];
+        }
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn is_empty ];
+        }
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn iter ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn len ];
+    ) => {
+        impl $custom {
+            /// Returns the length of the inner slice.
+            #[inline]
+            $vis fn len(&self) -> usize
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).len()
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn is_empty ];
+    ) => {
+        impl $custom {
+            /// Returns `true` if the inner slice is empty.
+            #[inline]
+            $vis fn is_empty(&self) -> bool
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).is_empty()
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn chars ];
+    ) => {
+        impl $custom {
+            /// Returns an iterator over the characters of the string.
+            #[inline]
+            $vis fn chars(&self) -> $core::str::Chars<'_>
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).chars()
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn char_indices ];
+    ) => {
+        impl $custom {
+            /// Returns an iterator over the characters of the string and their byte positions.
+            #[inline]
+            $vis fn char_indices(&self) -> $core::str::CharIndices<'_>
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).char_indices()
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn bytes ];
+    ) => {
+        impl $custom {
+            /// Returns an iterator over the bytes of the string.
+            #[inline]
+            $vis fn bytes(&self) -> $core::str::Bytes<'_>
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).bytes()
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn iter ];
+    ) => {
+        impl $custom {
+            /// Returns an iterator over the elements of the slice.
+            #[inline]
+            $vis fn iter<'a>(&'a self) -> <&'a $inner as $core::iter::IntoIterator>::IntoIter
+            where
+                &'a $inner: $core::iter::IntoIterator,
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).into_iter()
+            }
+        }
+    };
+
+    // new_unchecked
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn new_unchecked ];
+    ) => {
+        impl $custom {
+            /// Creates a reference to the custom slice without any validation.
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!(
+                "`<", stringify!($spec), " as SliceSpec>::validate(s)` should return `Ok(())`."
+            )]
+            ///
+            /// Users and generated codes may rely on the validity of custom slices.
+            #[inline]
+            $vis unsafe fn new_unchecked(s: &$inner) -> &Self {
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the caller.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $spec, s)
+                }
+            }
+        }
+    };
+
+    // new
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn new ];
+    ) => {
+        impl $custom {
+            /// Creates a reference to the custom slice after validation.
+            #[inline]
+            $vis fn new(s: &$inner) -> $core::result::Result<&Self, $error> {
+                $crate::Validated::<$spec>::new(s).map($crate::Validated::into_custom)
+            }
+        }
+    };
+
+    // constructors
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn constructors ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ fn constructors(vis = $vis) ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ fn constructors(vis = $ctor_vis:vis) ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $ctor_vis fn new ];
+        }
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $ctor_vis fn new_unchecked ];
+        }
+        $crate::impl_methods_for_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $ctor_vis fn from_proof ];
+        }
+    };
+
+    // contains, starts_with, ends_with
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn contains ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [search]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn contains -> is_contained_in ];
+            doc="Returns `true` if the inner slice contains the given slice.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn starts_with ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [search]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn starts_with -> is_prefix_of ];
+            doc="Returns `true` if the inner slice starts with the given slice.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn ends_with ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [search]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn ends_with -> is_suffix_of ];
+            doc="Returns `true` if the inner slice ends with the given slice.";
+        }
+    };
+    (
+        @impl [search]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn $method:ident -> $search:ident ];
+        doc=$doc:literal;
+    ) => {
+        impl $custom {
+            #[doc = $doc]
+            ///
+            /// The needle can be any type which implements `Needle` for the inner slice type, such
+            /// as the inner slice, characters, and closures.
+            #[inline]
+            $vis fn $method<N>(&self, mut needle: N) -> bool
+            where
+                N: $crate::Needle<$inner>,
+            {
+                let inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                $crate::Needle::$search(&mut needle, inner)
+            }
+        }
+    };
+
+    // find
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn find ];
+    ) => {
+        impl $custom {
+            /// Returns the index of the first match of the given needle in the inner slice.
+            ///
+            /// The needle can be any type which implements `Needle` for the inner slice type, such
+            /// as the inner slice, characters, and closures.
+            #[inline]
+            $vis fn find<N>(&self, mut needle: N) -> $core::option::Option<usize>
+            where
+                N: $crate::Needle<$inner>,
+            {
+                let inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                $crate::Needle::find_in(&mut needle, inner).map(|range| range.start)
+            }
+        }
+    };
+
+    // strip_prefix, strip_suffix
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn strip_prefix $(($mode:ident))? ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [strip $($mode)?]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn strip_prefix -> (match_prefix_len, strip_prefix_len) ];
+            doc="Returns the custom slice with the given prefix removed.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn strip_suffix $(($mode:ident))? ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [strip $($mode)?]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn strip_suffix -> (match_suffix_len, strip_suffix_len) ];
+            doc="Returns the custom slice with the given suffix removed.";
+        }
+    };
+    (
+        @impl [strip]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn $method:ident -> ($search:ident, $strip:ident) ];
+        doc=$doc:literal;
+    ) => {
+        impl $custom {
+            #[doc = $doc]
+            ///
+            /// Returns `None` if the slice does not have the given affix.
+            /// The affix can be any type which implements `Needle` for the inner slice type, such
+            /// as the inner slice, characters, and closures.
+            #[inline]
+            $vis fn $method<N>(&self, mut affix: N) -> $core::option::Option<&Self>
+            where
+                N: $crate::Needle<$inner>,
+                $spec: $crate::SliceClosedSpec,
+                $inner: $crate::SliceLen
+                    + $core::ops::Index<$core::ops::RangeFrom<usize>, Output = $inner>
+                    + $core::ops::Index<$core::ops::RangeTo<usize>, Output = $inner>,
+            {
+                let inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                let len = $crate::Needle::$search(&mut affix, inner)?;
+                let rest = $crate::__private::$strip(inner, len);
+                Some(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(rest)` returns `Ok(())`.
+                    //     + This is ensured by `$spec: SliceClosedSpec`, because `rest` is a
+                    //       sub-slice of the valid slice created by indexing `inner`. The needle
+                    //       only reports the length of the match.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $spec, rest)
+                })
+            }
+        }
+    };
+    (
+        @impl [strip revalidate]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn $method:ident -> ($search:ident, $strip:ident) ];
+        doc=$doc:literal;
+    ) => {
+        impl $custom {
+            #[doc = $doc]
+            ///
+            /// Returns `None` if the slice does not have the given affix, and returns `Some(Err(_))`
+            /// if the remainder is not valid.
+            /// The affix can be any type which implements `Needle` for the inner slice type, such
+            /// as the inner slice, characters, and closures.
+            #[inline]
+            $vis fn $method<N>(
+                &self,
+                mut affix: N,
+            ) -> $core::option::Option<$core::result::Result<&Self, $error>>
+            where
+                N: $crate::Needle<$inner>,
+                $inner: $crate::SliceLen
+                    + $core::ops::Index<$core::ops::RangeFrom<usize>, Output = $inner>
+                    + $core::ops::Index<$core::ops::RangeTo<usize>, Output = $inner>,
+            {
+                let inner = <$spec as $crate::SliceSpec>::as_inner(self);
+                let len = $crate::Needle::$search(&mut affix, inner)?;
+                let rest = $crate::__private::$strip(inner, len);
+                if let Err(e) = <$spec as $crate::SliceSpec>::validate(rest) {
+                    return Some(Err(e));
+                }
+                Some(Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(rest)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $spec, rest)
+                }))
+            }
+        }
+    };
+
+    // eq_inner, eq_ignore_ascii_case, eq_exact
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn eq_inner ];
+    ) => {
+        impl $custom {
+            /// Returns `true` if the inner slice is equal to the given slice.
+            #[inline]
+            $vis fn eq_inner(&self, other: &$inner) -> bool
+            where
+                $inner: $core::cmp::PartialEq,
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self) == other
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn eq_ignore_ascii_case ];
+    ) => {
+        impl $custom {
+            /// Returns `true` if the inner slice is equal to the given slice, ignoring the ASCII
+            /// case.
+            #[inline]
+            $vis fn eq_ignore_ascii_case(&self, other: &$inner) -> bool {
+                <$spec as $crate::SliceSpec>::as_inner(self).eq_ignore_ascii_case(other)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn eq_exact ];
+    ) => {
+        impl $custom {
+            /// Returns `true` if the inner slices are exactly equal.
+            ///
+            /// This ignores the custom comparison such as `base: Folded`.
+            #[inline]
+            $vis fn eq_exact(&self, other: &Self) -> bool
+            where
+                $inner: $core::cmp::PartialEq,
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self)
+                    == <$spec as $crate::SliceSpec>::as_inner(other)
+            }
+        }
+    };
+
+    // from_proof
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn from_proof ];
+    ) => {
+        impl $custom {
+            /// Creates the custom slice from the proof of the validity.
+            #[inline]
+            $vis fn from_proof(proof: $crate::Validated<'_, $spec>) -> &Self {
+                proof.into_custom()
+            }
+        }
+    };
+
+    // display_base64
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn display_base64 ];
+    ) => {
+        $crate::__impl_base64! {
+            @display_base64; ({$core, $alloc}, $spec, $custom, $inner, $error); $vis
+        }
+    };
+
+    // alloc_validated, alloc_in
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn alloc_validated ];
+    ) => {
+        $crate::__impl_bumpalo! {
+            @alloc_validated; ({$core, $alloc}, $spec, $custom, $inner, $error); $vis
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn alloc_in ];
+    ) => {
+        $crate::__impl_bumpalo! {
+            @alloc_in; ({$core, $alloc}, $spec, $custom, $inner, $error); $vis
+        }
+    };
+
+    // windows, chunks
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn windows ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [subslices]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn windows -> Windows ];
+            doc="Returns an iterator over all contiguous windows of length `size`.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn chunks ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [subslices]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn chunks -> Chunks ];
+            doc="Returns an iterator over `size` elements of the slice at a time.";
+        }
+    };
+    (
+        @impl [subslices]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn $method:ident -> $iter:ident ];
+        doc=$doc:literal;
+    ) => {
+        impl $custom {
+            #[doc = $doc]
+            ///
+            /// The items are the custom slices.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `size` is 0.
+            #[inline]
+            $vis fn $method(
+                &self,
+                size: usize,
+            ) -> $crate::SubSlices<
+                '_,
+                $spec,
+                $core::slice::$iter<'_, <$inner as $core::ops::Index<usize>>::Output>,
+            >
+            where
+                $spec: $crate::SliceClosedSpec,
+            {
+                let iter = <$spec as $crate::SliceSpec>::as_inner(self).$method(size);
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())` for every item `s`.
+                    //     + This is ensured by `$spec: SliceClosedSpec`, because the items are
+                    //       sub-slices of the valid slice.
+                    $crate::SubSlices::new_unchecked(iter)
+                }
+            }
+        }
+    };
+
+    // binary_search, binary_search_by, contains_sorted
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn binary_search ];
+    ) => {
+        impl $custom {
+            /// Binary searches the slice for the given element.
+            ///
+            /// See `binary_search` method of the slice for details.
+            #[inline]
+            $vis fn binary_search(
+                &self,
+                x: &<$inner as $core::ops::Index<usize>>::Output,
+            ) -> $core::result::Result<usize, usize>
+            where
+                <$inner as $core::ops::Index<usize>>::Output: $core::cmp::Ord,
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).binary_search(x)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn binary_search_by ];
+    ) => {
+        impl $custom {
+            /// Binary searches the slice with the comparator function.
+            ///
+            /// See `binary_search_by` method of the slice for details.
+            #[inline]
+            $vis fn binary_search_by<'a, F>(&'a self, f: F) -> $core::result::Result<usize, usize>
+            where
+                F: $core::ops::FnMut(
+                    &'a <$inner as $core::ops::Index<usize>>::Output,
+                ) -> $core::cmp::Ordering,
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).binary_search_by(f)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn contains_sorted ];
+    ) => {
+        impl $custom {
+            /// Returns `true` if the slice contains the given element.
+            ///
+            /// This takes O(log n) time, since the elements are sorted.
+            #[inline]
+            $vis fn contains_sorted(&self, x: &<$inner as $core::ops::Index<usize>>::Output) -> bool
+            where
+                $spec: $crate::SortedSliceSpec,
+                <$inner as $core::ops::Index<usize>>::Output: $core::cmp::Ord,
+            {
+                <$spec as $crate::SliceSpec>::as_inner(self).binary_search(x).is_ok()
+            }
+        }
+    };
+
+    // from_ptr
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn from_ptr ];
+    ) => {
+        impl $custom {
+            /// Creates a reference from the raw C string, and validates it.
+            ///
+            /// # Safety
+            ///
+            /// Safety condition is same as `CStr::from_ptr`.
+            $vis unsafe fn from_ptr<'a>(
+                ptr: *const $core::ffi::c_char,
+            ) -> $core::result::Result<&'a Self, $error>
+            where
+                &'a $inner: $core::convert::From<&'a $core::ffi::CStr>,
+            {
+                let inner = <&'a $inner>::from(unsafe { $core::ffi::CStr::from_ptr(ptr) });
+                <$spec as $crate::SliceSpec>::validate(inner)?;
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $spec, inner)
+                })
+            }
+        }
+    };
+
+    // try_arc_from, try_rc_from
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn try_arc_from ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [smartptr]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn try_arc_from -> $alloc::sync::Arc ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn try_rc_from ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [smartptr]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn try_rc_from -> $alloc::rc::Rc ];
+        }
+    };
+    (
+        @impl [smartptr]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn $method:ident -> $($smartptr:ident)::* ];
+    ) => {
+        impl $custom {
+            /// Validates the inner slice, and creates a reference-counted custom slice from it.
+            $vis fn $method<'a>(
+                s: &'a $inner,
+            ) -> $core::result::Result<$($smartptr)::* <Self>, $error>
+            where
+                $($smartptr)::* <$inner>: $core::convert::From<&'a $inner>,
+            {
+                // Validate the converted slice, since the conversion is not trusted to keep the
+                // content.
+                let buf = $($smartptr)::* ::<$inner>::from(s);
+                <$spec as $crate::SliceSpec>::validate(&*buf)?;
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())` for the inner slice `s`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    //     + This ensures that the memory layout of `into_raw(buf)` is also valid
+                    //       as `$($smartptr)::* <$custom>`.
+                    $($smartptr)::* ::<Self>::from_raw(
+                        $($smartptr)::* ::<$inner>::into_raw(buf) as *const Self
+                    )
+                })
+            }
+        }
+    };
+
+    // try_from_reporting
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn try_from_reporting ];
+    ) => {
+        impl $custom {
+            /// Validates the inner slice, and returns all the violations if it is invalid.
+            $vis fn try_from_reporting(
+                s: &$inner,
+            ) -> $core::result::Result<&Self, $alloc::vec::Vec<$error>> {
+                if let Err(e) = <$spec as $crate::SliceSpec>::validate(s) {
+                    let mut errors = <$spec as $crate::SliceSpec>::validate_all(s);
+                    if errors.is_empty() {
+                        errors.push(e);
+                    }
+                    return Err(errors);
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $spec, s)
+                })
+            }
+        }
+    };
+
+    // make_mut_arc, make_mut_rc
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn make_mut_arc ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [make_mut]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn make_mut_arc -> $alloc::sync::Arc ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn make_mut_rc ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [make_mut]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn make_mut_rc -> $alloc::rc::Rc ];
+        }
+    };
+    (
+        @impl [make_mut]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn $method:ident -> $($smartptr:ident)::* ];
+    ) => {
+        impl $custom {
+            /// Returns a mutable reference to the custom slice, cloning the buffer if it is shared.
+            $vis fn $method(this: &mut $($smartptr)::* <Self>) -> &mut Self
+            where
+                $($smartptr)::* <$inner>: for<'b> $core::convert::From<&'b $inner>,
+            {
+                if $($smartptr)::* ::get_mut(this).is_none() {
+                    let buf = $($smartptr)::* ::<$inner>::from(
+                        <$spec as $crate::SliceSpec>::as_inner(this),
+                    );
+                    *this = unsafe {
+                        // This is safe only when all of the conditions below are met:
+                        //
+                        // * `$spec::validate(buf)` returns `Ok(())`.
+                        //     + This is ensured since `buf` is a clone of the valid custom slice.
+                        // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                        //     + This ensures that the memory layout of `into_raw(buf)` is also
+                        //       valid as `$($smartptr)::* <$custom>`.
+                        $($smartptr)::* ::<Self>::from_raw(
+                            $($smartptr)::* ::<$inner>::into_raw(buf) as *const Self
+                        )
+                    };
+                }
+                match $($smartptr)::* ::get_mut(this) {
+                    Some(v) => v,
+                    None => unreachable!("The buffer should be unique after cloning"),
+                }
+            }
+        }
+    };
+
+    // weak_arc, weak_rc
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn weak_arc ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [weak]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn (downgrade_arc, upgrade_arc) -> $alloc::sync ];
+            ptr=Arc;
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn weak_rc ];
+    ) => {
+        $crate::impl_methods_for_slice! {
+            @impl [weak]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ $vis fn (downgrade_rc, upgrade_rc) -> $alloc::rc ];
+            ptr=Rc;
+        }
+    };
+    (
+        @impl [weak]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $vis:vis fn ($downgrade:ident, $upgrade:ident) -> $($module:ident)::* ];
+        ptr=$ptr:ident;
+    ) => {
+        impl $custom {
+            /// Creates a weak pointer to the shared custom slice.
+            #[inline]
+            $vis fn $downgrade(this: &$($module)::*::$ptr<Self>) -> $($module)::*::Weak<Self> {
+                $($module)::*::$ptr::downgrade(this)
+            }
+
+            /// Upgrades the weak pointer to the shared custom slice.
+            ///
+            /// Returns `None` if the custom slice has already been dropped.
+            #[inline]
+            $vis fn $upgrade(
+                weak: &$($module)::*::Weak<Self>,
+            ) -> $core::option::Option<$($module)::*::$ptr<Self>> {
+                weak.upgrade()
+            }
+        }
+    };
+
+    // Fallback.
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $($rest:tt)* ];
+    ) => {
+        compile_error!(concat!("Unsupported method: ", stringify!($($rest)*)));
+    };
+}
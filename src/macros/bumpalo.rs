@@ -0,0 +1,41 @@
+//! Macros for `bumpalo` crate integration.
+
+/// Implements methods to allocate the custom slice in the `bumpalo` arena.
+///
+/// This is used internally by `impl_methods_for_slice!` macro.
+#[cfg(feature = "bumpalo")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_bumpalo {
+    (@alloc_validated; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty); $vis:vis) => {
+        impl $custom {
+            /// Validates the inner slice, and copies it into the arena.
+            #[inline]
+            $vis fn alloc_validated<'b>(
+                bump: &'b $crate::__private::bumpalo::Bump,
+                s: &$inner,
+            ) -> $core::result::Result<&'b Self, $error> {
+                $crate::bumpalo_adapter::alloc_validated::<$spec>(bump, s)
+            }
+        }
+    };
+    (@alloc_in; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty); $vis:vis) => {
+        impl $custom {
+            /// Copies the slice into the arena without validation.
+            #[inline]
+            $vis fn alloc_in<'b>(&self, bump: &'b $crate::__private::bumpalo::Bump) -> &'b Self {
+                $crate::bumpalo_adapter::alloc_custom::<$spec>(bump, self)
+            }
+        }
+    };
+}
+
+/// Emits a compile error, because `bumpalo` feature is disabled.
+#[cfg(not(feature = "bumpalo"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_bumpalo {
+    ($($rest:tt)*) => {
+        compile_error!("`bumpalo` methods require `bumpalo` feature of `validated-slice` crate");
+    };
+}
@@ -0,0 +1,849 @@
+//! Macros for custom `Cow`-like types.
+
+/// Implements std traits for the given custom `Cow`-like type.
+///
+/// To implement `PartialEq` and `PartialOrd`, use [`impl_cmp_for_cow_slice!`] macro.
+///
+/// # Usage
+///
+/// ## Examples
+///
+/// Assume you have custom `str` and `String` types, `AsciiStr` and `AsciiString`.
+/// Then you can define `Cow<'a, AsciiStr>`-like type as below:
+///
+/// ```ignore
+/// /// ASCII string which is borrowed or owned.
+/// // Don't derive comparison traits and `Hash`, because derived impls distinguish variants.
+/// // Use the macros instead.
+/// pub enum AsciiCowStr<'a> {
+///     /// Borrowed string.
+///     Borrowed(&'a AsciiStr),
+///     /// Owned string.
+///     Owned(AsciiString),
+/// }
+///
+/// validated_slice::impl_std_traits_for_cow_slice! {
+///     // `Std` is omissible.
+///     Std {
+///         // Module identifier of `core` crate.
+///         // Default is `std`.
+///         core: core,
+///         // Module identifier of `alloc` crate.
+///         // Default is `std`.
+///         alloc: alloc,
+///     };
+///     Spec {
+///         // Spec of the owned custom slice type.
+///         spec: AsciiStringSpec,
+///         // Identifier of the `Cow`-like type.
+///         custom: AsciiCowStr,
+///         owned_custom: AsciiString,
+///         slice_custom: AsciiStr,
+///     };
+///     { Deref<Target = {SliceCustom}> };
+///     { From<&{SliceCustom}> };
+///     { From<{OwnedCustom}> };
+///     /* ... and more traits you want! */
+/// }
+/// ```
+///
+/// ## `Cow`-like type
+///
+/// The `Cow`-like type should be an enum which has exactly one lifetime parameter and exactly two
+/// variants, `Borrowed(&'a {SliceCustom})` and `Owned({OwnedCustom})`.
+/// The `custom` field should be the identifier of the type (without the lifetime parameter), and
+/// the type should be usable by that name at the macro invocation site.
+///
+/// ## Core and alloc
+///
+/// Same as [`impl_std_traits_for_owned_slice!`] macro.
+///
+/// ## Type names
+///
+/// As type name, you can use `{Custom}`, `{OwnedCustom}`, and `{SliceCustom}` instead of a real
+/// type name.
+///
+/// ## Supported trait impls
+///
+/// **NOTE**: To implemente `PartialEq` and `PartialOrd`, use `impl_cmp_for_cow_slice!` macro.
+///
+/// Each trait impl is specified by `{ TraitName<TyParams> for TyImplTarget };` format.
+/// `<TyParams>` part and `for TyImplTarget` part is optional.
+///
+/// Default impl target is `{Custom}`.
+/// Explicit `for {Custom}` is also accepted, and it is same as omitting `for TyImplTarget` part.
+///
+/// Supported trait impls are:
+///
+/// * `std::borrow`
+///     + `{ Borrow<{SliceCustom}> };`
+/// * `std::clone`
+///     + `{ Clone };`
+///         - This requires `{OwnedCustom}: Clone`.
+/// * `std::cmp`
+///     + `{ Eq };`
+///     + `{ Ord };`
+///         - This requires `{SliceCustom}: Ord`.
+/// * `std::convert`
+///     + `{ AsRef<{SliceCustom}> };`
+///     + `{ AsRef<any_ty> };`
+///         - This requires `{SliceCustom}: AsRef<any_ty>`.
+///     + `{ From<&{SliceCustom}> };`
+///     + `{ From<{OwnedCustom}> };`
+///     + `{ From<Cow<{SliceCustom}>> };`
+///         - This requires `{SliceCustom}: ToOwned<Owned = {OwnedCustom}>`.
+///     + `{ From<{Custom}> for Cow<{SliceCustom}> };`
+///         - This requires `{SliceCustom}: ToOwned<Owned = {OwnedCustom}>`.
+///     + `{ From<{Custom}> for {OwnedCustom} };`
+///         - This requires `{OwnedCustom}: From<&{SliceCustom}>`.
+/// * `std::default`
+///     + `{ Default };`
+///         - This creates `Borrowed` variant, and requires `&{SliceCustom}: Default`.
+/// * `std::fmt`
+///     + `{ Debug };`
+///     + `{ Debug(name = "Name") };`
+///         - Formats as a tuple struct `Name(..)` with the inner slice of `{SliceCustom}`
+///           as its field.
+///     + `{ Debug(with = path::to::fn) };`
+///         - The function should have the type
+///           `fn(&<SliceSpec as SliceSpec>::Inner, &mut Formatter<'_>) -> fmt::Result`.
+///     + `{ Display };`
+///     + `{ Display(with = path::to::fn) };`
+///         - The function should have the type
+///           `fn(&<SliceSpec as SliceSpec>::Inner, &mut Formatter<'_>) -> fmt::Result`.
+///     + Note that `{ Debug };` and `{ Display };` redirect to trait impls for `{SliceCustom}`.
+/// * `std::hash`
+///     + `{ Hash };`
+///         - This redirects to trait impls for `{SliceCustom}`, so borrowed and owned values are
+///           hashed in the same way.
+///     + `{ Hash(with = path::to::fn) };`
+///         - The function should have the type
+///           `fn<H: Hasher>(&<SliceSpec as SliceSpec>::Inner, &mut H)`.
+///         - Use this together with custom comparison (`base: Custom`), so that `Hash` stays
+///           consistent with `PartialEq`.
+/// * `std::ops`
+///     + `{ Deref<Target = {SliceCustom}> };`
+///
+/// [`impl_cmp_for_cow_slice!`]: macro.impl_cmp_for_cow_slice.html
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+#[macro_export]
+macro_rules! impl_std_traits_for_cow_slice {
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ident,
+            owned_custom: $owned_custom:ty,
+            slice_custom: $slice_custom:ty,
+        };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $(
+            $crate::impl_std_traits_for_cow_slice! {
+                @impl; ({$core, $alloc}, $spec, $custom, $owned_custom,
+                    <$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom);
+                rest=[$($rest)*];
+            }
+        )*
+    };
+
+    (
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ident,
+            owned_custom: $owned_custom:ty,
+            slice_custom: $slice_custom:ty,
+        };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $(
+            $crate::__with_default_std! {
+                $crate::impl_std_traits_for_cow_slice;
+                head=[@impl;];
+                lifetimes=[];
+                args=[$spec, $custom, $owned_custom, <$spec as $crate::OwnedSliceSpec>::SliceSpec,
+                    $slice_custom];
+                rest=[$($rest)*];
+            }
+        )*
+    };
+
+    // std::borrow::Borrow
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Borrow<{SliceCustom}> ];
+    ) => {
+        impl $core::borrow::Borrow<$slice_custom> for $custom<'_> {
+            #[inline]
+            fn borrow(&self) -> &$slice_custom {
+                $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self)
+            }
+        }
+    };
+
+    // std::clone::Clone
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Clone ];
+    ) => {
+        impl $core::clone::Clone for $custom<'_> {
+            #[inline]
+            fn clone(&self) -> Self {
+                match self {
+                    $custom::Borrowed(s) => $custom::Borrowed(*s),
+                    $custom::Owned(o) => $custom::Owned($core::clone::Clone::clone(o)),
+                }
+            }
+        }
+    };
+
+    // std::cmp::Eq
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Eq ];
+    ) => {
+        impl $core::cmp::Eq for $custom<'_> {}
+    };
+
+    // std::cmp::Ord
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Ord ];
+    ) => {
+        impl $core::cmp::Ord for $custom<'_> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> $core::cmp::Ordering {
+                <$slice_custom as $core::cmp::Ord>::cmp(
+                    $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self),
+                    $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, other),
+                )
+            }
+        }
+    };
+
+    // std::convert::AsRef
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ AsRef<{SliceCustom}> ];
+    ) => {
+        impl $core::convert::AsRef<$slice_custom> for $custom<'_> {
+            #[inline]
+            fn as_ref(&self) -> &$slice_custom {
+                $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ AsRef<$param:ty> ];
+    ) => {
+        impl $core::convert::AsRef<$param> for $custom<'_>
+        where
+            $slice_custom: $core::convert::AsRef<$param>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &$param {
+                $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self)
+                    .as_ref()
+            }
+        }
+    };
+
+    // std::convert::From
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ From<&{SliceCustom}> ];
+    ) => {
+        impl<'a> $core::convert::From<&'a $slice_custom> for $custom<'a> {
+            #[inline]
+            fn from(s: &'a $slice_custom) -> Self {
+                $custom::Borrowed(s)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ From<{OwnedCustom}> ];
+    ) => {
+        impl $core::convert::From<$owned_custom> for $custom<'_> {
+            #[inline]
+            fn from(s: $owned_custom) -> Self {
+                $custom::Owned(s)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ From<Cow<{SliceCustom}>> ];
+    ) => {
+        impl<'a> $core::convert::From<$alloc::borrow::Cow<'a, $slice_custom>> for $custom<'a> {
+            #[inline]
+            fn from(s: $alloc::borrow::Cow<'a, $slice_custom>) -> Self {
+                match s {
+                    $alloc::borrow::Cow::Borrowed(s) => $custom::Borrowed(s),
+                    $alloc::borrow::Cow::Owned(o) => $custom::Owned(o),
+                }
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ From<{Custom}> for Cow<{SliceCustom}> ];
+    ) => {
+        impl<'a> $core::convert::From<$custom<'a>> for $alloc::borrow::Cow<'a, $slice_custom> {
+            #[inline]
+            fn from(s: $custom<'a>) -> Self {
+                match s {
+                    $custom::Borrowed(s) => $alloc::borrow::Cow::Borrowed(s),
+                    $custom::Owned(o) => $alloc::borrow::Cow::Owned(o),
+                }
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ From<{Custom}> for {OwnedCustom} ];
+    ) => {
+        impl<'a> $core::convert::From<$custom<'a>> for $owned_custom {
+            #[inline]
+            fn from(s: $custom<'a>) -> Self {
+                match s {
+                    $custom::Borrowed(s) => <$owned_custom as $core::convert::From<&'a $slice_custom>>::from(s),
+                    $custom::Owned(o) => o,
+                }
+            }
+        }
+    };
+
+    // std::default::Default
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Default ];
+    ) => {
+        impl<'a> $core::default::Default for $custom<'a>
+        where
+            &'a $slice_custom: $core::default::Default,
+        {
+            #[inline]
+            fn default() -> Self {
+                $custom::Borrowed($core::default::Default::default())
+            }
+        }
+    };
+
+    // std::fmt::Debug
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Debug ];
+    ) => {
+        impl $core::fmt::Debug for $custom<'_>
+        where
+            $slice_custom: $core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let slice: &$slice_custom =
+                    $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self);
+                <$slice_custom as $core::fmt::Debug>::fmt(slice, f)
+            }
+        }
+    };
+
+    // std::fmt::Debug with a wrapper name
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Debug(name = $name:expr) ];
+    ) => {
+        impl $core::fmt::Debug for $custom<'_>
+        where
+            <$slice_spec as $crate::SliceSpec>::Inner: $core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let slice: &$slice_custom =
+                    $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self);
+                let inner = <$slice_spec as $crate::SliceSpec>::as_inner(slice);
+                f.debug_tuple($name).field(&inner).finish()
+            }
+        }
+    };
+
+    // std::fmt::Debug with a custom formatting function
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Debug(with = $fmt:path) ];
+    ) => {
+        impl $core::fmt::Debug for $custom<'_> {
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let slice: &$slice_custom =
+                    $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self);
+                $fmt(<$slice_spec as $crate::SliceSpec>::as_inner(slice), f)
+            }
+        }
+    };
+
+    // std::fmt::Display
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Display ];
+    ) => {
+        impl $core::fmt::Display for $custom<'_>
+        where
+            $slice_custom: $core::fmt::Display,
+        {
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let slice: &$slice_custom =
+                    $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self);
+                <$slice_custom as $core::fmt::Display>::fmt(slice, f)
+            }
+        }
+    };
+
+    // std::fmt::Display with a custom formatting function
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Display(with = $fmt:path) ];
+    ) => {
+        impl $core::fmt::Display for $custom<'_> {
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let slice: &$slice_custom =
+                    $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self);
+                $fmt(<$slice_spec as $crate::SliceSpec>::as_inner(slice), f)
+            }
+        }
+    };
+
+    // std::hash::Hash
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Hash ];
+    ) => {
+        impl $core::hash::Hash for $custom<'_>
+        where
+            $slice_custom: $core::hash::Hash,
+        {
+            #[inline]
+            fn hash<H: $core::hash::Hasher>(&self, state: &mut H) {
+                let slice: &$slice_custom =
+                    $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self);
+                <$slice_custom as $core::hash::Hash>::hash(slice, state)
+            }
+        }
+    };
+
+    // std::hash::Hash with a custom hash function
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Hash(with = $hash:path) ];
+    ) => {
+        impl $core::hash::Hash for $custom<'_> {
+            #[inline]
+            fn hash<H: $core::hash::Hasher>(&self, state: &mut H) {
+                let slice: &$slice_custom =
+                    $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self);
+                $hash(<$slice_spec as $crate::SliceSpec>::as_inner(slice), state)
+            }
+        }
+    };
+
+    // std::ops::Deref
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ Deref<Target = {SliceCustom}> ];
+    ) => {
+        impl $core::ops::Deref for $custom<'_> {
+            type Target = $slice_custom;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, self)
+            }
+        }
+    };
+
+    // Helpers.
+
+    // Converts `&$custom` into `&$slice_custom`.
+    (@conv:as_slice, $spec:ty, $slice_spec:ty, $custom:ident, $cow_ref:expr) => {
+        match $cow_ref {
+            $custom::Borrowed(s) => *s,
+            $custom::Owned(o) => unsafe {
+                // This is safe only when all of the conditions below are met:
+                //
+                // * `$spec::validate(s)` returns `Ok(())`.
+                //     + This is ensured when `o` is constructed.
+                // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                $crate::impl_std_traits_for_owned_slice!(@conv:as_slice, $spec, $slice_spec, o)
+            },
+        }
+    };
+
+    // Explicit `for {Custom}`, which is same as the default impl target.
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty);
+        rest=[ $($rest:tt)* ];
+    ) => {
+        $crate::impl_std_traits_for_cow_slice! {
+            @strip_for_custom; ({$core, $alloc}, $spec, $custom, $owned_custom, $slice_spec, $slice_custom);
+            target=[]; rest=[$($rest)*];
+        }
+    };
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[for {Custom}];
+    ) => {
+        $crate::impl_std_traits_for_cow_slice! {
+            @impl; $params;
+            rest=[$($target)*];
+        }
+    };
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[$first:tt $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_cow_slice! {
+            @strip_for_custom; $params;
+            target=[$($target)* $first]; rest=[$($rest)*];
+        }
+    };
+
+    // Fallback.
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[];
+    ) => {
+        compile_error!(concat!("Unsupported target: ", stringify!($($target)*)));
+    };
+}
+
+/// Implements `PartialEq` and `PartialOrd` for the given custom `Cow`-like type.
+///
+/// # Usage
+///
+/// ## Examples
+///
+/// ```ignore
+/// validated_slice::impl_cmp_for_cow_slice! {
+///     // `Std` is omissible.
+///     Std {
+///         // Module identifier of `core` crate.
+///         // Default is `std`.
+///         core: core,
+///         // Module identifier of `alloc` crate.
+///         // Default is `std`.
+///         alloc: alloc,
+///     };
+///     Spec {
+///         spec: AsciiStringSpec,
+///         custom: AsciiCowStr,
+///         owned_custom: AsciiString,
+///         slice_custom: AsciiStr,
+///         slice_inner: str,
+///         base: Inner,
+///     };
+///     Cmp { PartialEq, PartialOrd };
+///     { ({Custom}), ({Custom}) };
+///     { ({Custom}), ({OwnedCustom}), rev };
+///     { ({Custom}), (&{SliceCustom}), rev };
+///     { ({Custom}), (&{SliceInner}), rev };
+///     /* ... and more pairs! */
+/// }
+/// ```
+///
+/// The syntax of `Std` and `Spec` part is very similar to [`impl_std_traits_for_cow_slice!`] macro,
+/// and the syntax of `base`, `Cmp`, and operand type pairs are same as
+/// [`impl_cmp_for_owned_slice!`] macro.
+///
+/// ## Supported types
+///
+/// * `{Custom}`
+/// * `&{Custom}`
+/// * `{OwnedCustom}`
+/// * `&{OwnedCustom}`
+/// * `{SliceCustom}`
+/// * `&{SliceCustom}`
+/// * `Cow<{SliceCustom}>`
+/// * `{SliceInner}`
+/// * `&{SliceInner}`
+/// * `Cow<{SliceInner}>`
+/// * ... and arbitrary types
+///
+/// Note that, with `base: Custom`, `{SliceInner}` and its variants are not supported (because it
+/// does not make sense).
+///
+/// [`impl_std_traits_for_cow_slice!`]: macro.impl_std_traits_for_cow_slice.html
+/// [`impl_cmp_for_owned_slice!`]: macro.impl_cmp_for_owned_slice.html
+#[macro_export]
+macro_rules! impl_cmp_for_cow_slice {
+    (
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ident,
+            owned_custom: $owned_custom:ty,
+            slice_custom: $slice_custom:ty,
+            slice_inner: $slice_inner:ty,
+            base: $base:ident,
+        };
+        Cmp { $($cmp_targets:ident),* };
+        $($rest:tt)*
+    ) => {
+        $crate::__with_default_std! {
+            $crate::impl_cmp_for_cow_slice;
+            head=[];
+            std_block;
+            Spec {
+                spec: $spec,
+                custom: $custom,
+                owned_custom: $owned_custom,
+                slice_custom: $slice_custom,
+                slice_inner: $slice_inner,
+                base: $base,
+            };
+            Cmp { $($cmp_targets),* };
+            $($rest)*
+        }
+    };
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ident,
+            owned_custom: $owned_custom:ty,
+            slice_custom: $slice_custom:ty,
+            slice_inner: $slice_inner:ty,
+            base: $base:ident,
+        };
+        Cmp { $($cmp_targets:ident),* };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_cmp_for_cow_slice! {
+            @cmps; ({$core, $alloc}, $spec, $custom, $owned_custom,
+                <$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner, $base);
+            Cmp { $($cmp_targets),* };
+            $($rest)*
+        }
+    };
+
+    (
+        @cmps; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        Cmp { $cmp:ident $(, $more:ident)* };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_cmp_for_cow_slice! {
+            @pairs[$cmp]; ({$core, $alloc}, $spec, $custom, $owned_custom, $slice_spec,
+                $slice_custom, $slice_inner, $base);
+            $($rest)*
+        }
+        $crate::impl_cmp_for_cow_slice! {
+            @cmps; ({$core, $alloc}, $spec, $custom, $owned_custom, $slice_spec, $slice_custom,
+                $slice_inner, $base);
+            Cmp { $($more),* };
+            $($rest)*
+        }
+    };
+    (
+        @cmps; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        Cmp {};
+        $($rest:tt)*
+    ) => {};
+    (
+        @pairs[$cmp:ident]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident,
+            $owned_custom:ty, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        $({ ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
+    ) => {
+        $(
+            $crate::impl_cmp_for_cow_slice! {
+                @impl[$cmp]; ({$core, $alloc}, $spec, $custom, $owned_custom, $slice_spec,
+                    $slice_custom, $slice_inner, $base);
+                { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
+            }
+        )*
+    };
+
+    (
+        @impl[$cmp:ident]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        { ($($lhs:tt)*), ($($rhs:tt)*), rev };
+    ) => {
+        $crate::impl_cmp_for_cow_slice! {
+            @impl[$cmp]; ({$core, $alloc}, $spec, $custom, $owned_custom, $slice_spec,
+                $slice_custom, $slice_inner, $base);
+            { ($($lhs)*), ($($rhs)*) };
+        }
+        $crate::impl_cmp_for_cow_slice! {
+            @impl[$cmp]; ({$core, $alloc}, $spec, $custom, $owned_custom, $slice_spec,
+                $slice_custom, $slice_inner, $base);
+            { ($($rhs)*), ($($lhs)*) };
+        }
+    };
+    (
+        @impl[PartialEq]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        { ($($lhs:tt)*), ($($rhs:tt)*) };
+    ) => {
+        impl $core::cmp::PartialEq<
+            $crate::impl_cmp_for_cow_slice!(@type; ({$core, $alloc}, $custom, $owned_custom, $slice_custom, $slice_inner); { $($rhs)* })
+        > for $crate::impl_cmp_for_cow_slice!(@type; ({$core, $alloc}, $custom, $owned_custom, $slice_custom, $slice_inner); { $($lhs)* })
+        {
+            #[inline]
+            fn eq(&self, other: &$crate::impl_cmp_for_cow_slice!(@type; ({$core, $alloc}, $custom, $owned_custom, $slice_custom, $slice_inner); { $($rhs)* }))
+                -> bool
+            {
+                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialEq]; ($slice_spec, $slice_custom, $slice_inner, $base))(
+                    $crate::impl_cmp_for_cow_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $slice_spec, $slice_custom, $slice_inner); { $($lhs)* }; self),
+                    $crate::impl_cmp_for_cow_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $slice_spec, $slice_custom, $slice_inner); { $($rhs)* }; other),
+                )
+            }
+        }
+    };
+    (
+        @impl[PartialOrd]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $owned_custom:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        { ($($lhs:tt)*), ($($rhs:tt)*) };
+    ) => {
+        impl $core::cmp::PartialOrd<
+            $crate::impl_cmp_for_cow_slice!(@type; ({$core, $alloc}, $custom, $owned_custom, $slice_custom, $slice_inner); { $($rhs)* })
+        > for $crate::impl_cmp_for_cow_slice!(@type; ({$core, $alloc}, $custom, $owned_custom, $slice_custom, $slice_inner); { $($lhs)* })
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &$crate::impl_cmp_for_cow_slice!(@type; ({$core, $alloc}, $custom, $owned_custom, $slice_custom, $slice_inner); { $($rhs)* }))
+                -> $core::option::Option<$core::cmp::Ordering>
+            {
+                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialOrd]; ($slice_spec, $slice_custom, $slice_inner, $base))(
+                    $crate::impl_cmp_for_cow_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $slice_spec, $slice_custom, $slice_inner); { $($lhs)* }; self),
+                    $crate::impl_cmp_for_cow_slice!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $slice_spec, $slice_custom, $slice_inner); { $($rhs)* }; other),
+                )
+            }
+        }
+    };
+
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { {Custom} }) => {
+        $custom<'_>
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { &{Custom} }) => {
+        &$custom<'_>
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { {OwnedCustom} }) => {
+        $owned_custom
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { &{OwnedCustom} }) => {
+        &$owned_custom
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { {SliceCustom} }) => {
+        $slice_custom
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { &{SliceCustom} }) => {
+        &$slice_custom
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { Cow<{SliceCustom}> }) => {
+        $alloc::borrow::Cow<'_, $slice_custom>
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { {SliceInner} }) => {
+        $slice_inner
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { &{SliceInner} }) => {
+        &$slice_inner
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { Cow<{SliceInner}> }) => {
+        $alloc::borrow::Cow<'_, $slice_inner>
+    };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ident, $owned_custom:ty, $slice_custom:ty, $slice_inner:ty); { $ty:ty }) => {
+        $ty
+    };
+
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { {Custom} }; $expr:expr) => {
+        $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, $expr)
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { &{Custom} }; $expr:expr) => {
+        $crate::impl_std_traits_for_cow_slice!(@conv:as_slice, $spec, $slice_spec, $custom, *$expr)
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { {OwnedCustom} }; $expr:expr) => {
+        unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `$spec::validate(s)` returns `Ok(())`.
+            //     + This is ensured when `$expr` is constructed.
+            // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+            $crate::impl_std_traits_for_owned_slice!(@conv:as_slice, $spec, $slice_spec, $expr)
+        }
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { &{OwnedCustom} }; $expr:expr) => {
+        unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `$spec::validate(s)` returns `Ok(())`.
+            //     + This is ensured when `$expr` is constructed.
+            // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+            $crate::impl_std_traits_for_owned_slice!(@conv:as_slice, $spec, $slice_spec, *$expr)
+        }
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { {SliceCustom} }; $expr:expr) => {
+        $expr
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { &{SliceCustom} }; $expr:expr) => {
+        *$expr
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { Cow<{SliceCustom}> }; $expr:expr) => {
+        &**$expr
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { $ty:ty }; $expr:expr) => {
+        $core::convert::AsRef::<$slice_custom>::as_ref($expr)
+    };
+
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { {SliceInner} }; $expr:expr) => {
+        $expr
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { &{SliceInner} }; $expr:expr) => {
+        *$expr
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { Cow<{SliceInner}> }; $expr:expr) => {
+        &**$expr
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { {$($ty:tt)*} }; $expr:expr) => {
+        <$slice_spec as $crate::SliceSpec>::as_inner(
+            $crate::impl_cmp_for_cow_slice!(@expr[Custom]; ({$core, $alloc}, $spec, $custom, $slice_spec, $slice_custom, $slice_inner); { {$($ty)*} }; $expr)
+        )
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { &{$($ty:tt)*} }; $expr:expr) => {
+        <$slice_spec as $crate::SliceSpec>::as_inner(
+            $crate::impl_cmp_for_cow_slice!(@expr[Custom]; ({$core, $alloc}, $spec, $custom, $slice_spec, $slice_custom, $slice_inner); { &{$($ty)*} }; $expr)
+        )
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { Cow<{SliceCustom}> }; $expr:expr) => {
+        <$slice_spec as $crate::SliceSpec>::as_inner(&**$expr)
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ident, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty); { $ty:ty }; $expr:expr) => {
+        $core::convert::AsRef::<$slice_inner>::as_ref($expr)
+    };
+
+    ($($rest:tt)*) => {
+        compile_error!(stringify!($($rest)*));
+    };
+}
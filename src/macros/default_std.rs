@@ -0,0 +1,97 @@
+//! Defaults of `core` and `alloc` crates for the macros.
+//!
+//! When `Std { .. };` is omitted, the macros use the `core` and `alloc` crates re-exported from
+//! this crate, so the expansion does not depend on the features of this crate or on the
+//! `extern crate` declarations of the downstream crate.
+//!
+//! The re-exports are imported under the names `__core` and `__alloc` in an anonymous constant,
+//! and the generated impls are put in it.
+
+/// Invokes the given macro with the default `core` and `alloc` crates.
+///
+/// * `$mac; head=[..]; lifetimes=[..]; args=[..]; rest..` invokes
+///   `$mac! { head.. ({__core, __alloc lifetimes..}, args..); rest.. }`.
+/// * `$mac; head=[..]; std_block; rest..` invokes
+///   `$mac! { head.. Std { core: __core, alloc: __alloc, }; rest.. }`.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_default_std {
+    (
+        $($mac:ident)::+;
+        head=[$($head:tt)*];
+        lifetimes=[$($lt:tt)*];
+        args=[$($args:tt)*];
+        $($rest:tt)*
+    ) => {
+        const _: () = {
+            #[allow(unused_imports)]
+            use $crate::__private::{alloc as __alloc, core as __core};
+
+            $($mac)::+! {
+                $($head)* ({__core, __alloc $($lt)*}, $($args)*); $($rest)*
+            }
+        };
+    };
+    ($($mac:ident)::+; head=[$($head:tt)*]; std_block; $($rest:tt)*) => {
+        const _: () = {
+            #[allow(unused_imports)]
+            use $crate::__private::{alloc as __alloc, core as __core};
+
+            $($mac)::+! {
+                $($head)*
+                Std {
+                    core: __core,
+                    alloc: __alloc,
+                };
+                $($rest)*
+            }
+        };
+    };
+}
+
+/// Invokes the given macro with the default `core` and `alloc` crates.
+///
+/// * `$mac; head=[..]; lifetimes=[..]; args=[..]; rest..` invokes
+///   `$mac! { head.. ({__core, alloc lifetimes..}, args..); rest.. }`.
+/// * `$mac; head=[..]; std_block; rest..` invokes
+///   `$mac! { head.. Std { core: __core, alloc: alloc, }; rest.. }`.
+///
+/// Without `alloc` feature, `alloc` crate is not re-exported, and the targets which require it
+/// use the `alloc` crate declared by the downstream crate.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_default_std {
+    (
+        $($mac:ident)::+;
+        head=[$($head:tt)*];
+        lifetimes=[$($lt:tt)*];
+        args=[$($args:tt)*];
+        $($rest:tt)*
+    ) => {
+        const _: () = {
+            #[allow(unused_imports)]
+            use $crate::__private::core as __core;
+
+            $($mac)::+! {
+                $($head)* ({__core, alloc $($lt)*}, $($args)*); $($rest)*
+            }
+        };
+    };
+    ($($mac:ident)::+; head=[$($head:tt)*]; std_block; $($rest:tt)*) => {
+        const _: () = {
+            #[allow(unused_imports)]
+            use $crate::__private::core as __core;
+
+            $($mac)::+! {
+                $($head)*
+                Std {
+                    core: __core,
+                    alloc: alloc,
+                };
+                $($rest)*
+            }
+        };
+    };
+}
@@ -0,0 +1,37 @@
+//! Macros for `defmt` crate integration.
+
+/// Implements `defmt::Format` for the given custom type.
+///
+/// This is used internally by `impl_std_traits_for{,_owned}_slice!` macros.
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_defmt {
+    (
+        ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $custom:ty, $inner:ty);
+        |$this:ident| $as_inner:expr
+    ) => {
+        impl<$($lt $(: $bound)?),*> $crate::__private::defmt::Format for $custom
+        where
+            $inner: $crate::__private::defmt::Format,
+        {
+            #[inline]
+            fn format(&self, f: $crate::__private::defmt::Formatter<'_>) {
+                let $this = self;
+                <$inner as $crate::__private::defmt::Format>::format($as_inner, f)
+            }
+        }
+    };
+}
+
+/// Emits a compile error, because `defmt` feature is disabled.
+#[cfg(not(feature = "defmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_defmt {
+    ($($rest:tt)*) => {
+        compile_error!(
+            "`defmt::Format` target requires `defmt` feature of `validated-slice` crate"
+        );
+    };
+}
@@ -0,0 +1,179 @@
+//! Macros for `diesel` crate integration.
+
+/// Implements `diesel` traits for the given custom owned slice type.
+///
+/// This is used internally by `impl_std_traits_for_owned_slice!` macro.
+#[cfg(feature = "diesel")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_diesel {
+    (
+        @AsExpression<$st:ty>; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty,
+            $error:ty, $slice_spec:ty, $slice_inner:ty);
+    ) => {
+        $crate::__impl_diesel! { @as_expression; $st, $spec, $custom, $inner, $slice_inner }
+        $crate::__impl_diesel! {
+            @as_expression; $crate::__private::diesel::sql_types::Nullable<$st>, $spec, $custom,
+                $inner, $slice_inner
+        }
+
+        impl<DB>
+            $crate::__private::diesel::serialize::ToSql<
+                $crate::__private::diesel::sql_types::Nullable<$st>,
+                DB,
+            > for $custom
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            $custom: $crate::__private::diesel::serialize::ToSql<$st, DB>,
+        {
+            #[inline]
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut $crate::__private::diesel::serialize::Output<'b, '_, DB>,
+            ) -> $crate::__private::diesel::serialize::Result {
+                <$custom as $crate::__private::diesel::serialize::ToSql<$st, DB>>::to_sql(self, out)
+            }
+        }
+    };
+    (@as_expression; $st:ty, $spec:ty, $custom:ty, $inner:ty, $slice_inner:ty) => {
+        // Delegate to the expressions of the inner types, so that only public APIs of
+        // `diesel` are used.
+        impl $crate::__private::diesel::expression::AsExpression<$st> for $custom
+        where
+            $inner: $crate::__private::diesel::expression::AsExpression<$st>,
+        {
+            type Expression =
+                <$inner as $crate::__private::diesel::expression::AsExpression<$st>>::Expression;
+
+            #[inline]
+            fn as_expression(self) -> Self::Expression {
+                let inner = <$spec as $crate::OwnedSliceSpec>::into_inner(self);
+                <$inner as $crate::__private::diesel::expression::AsExpression<$st>>::as_expression(
+                    inner,
+                )
+            }
+        }
+
+        impl<'a> $crate::__private::diesel::expression::AsExpression<$st> for &'a $custom
+        where
+            &'a $slice_inner: $crate::__private::diesel::expression::AsExpression<$st>,
+        {
+            type Expression = <&'a $slice_inner as $crate::__private::diesel::expression::AsExpression<
+                $st,
+            >>::Expression;
+
+            #[inline]
+            fn as_expression(self) -> Self::Expression {
+                let slice_inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                <&'a $slice_inner as $crate::__private::diesel::expression::AsExpression<
+                    $st,
+                >>::as_expression(slice_inner)
+            }
+        }
+
+        impl<'a, 'b> $crate::__private::diesel::expression::AsExpression<$st> for &'b &'a $custom
+        where
+            &'a $slice_inner: $crate::__private::diesel::expression::AsExpression<$st>,
+        {
+            type Expression = <&'a $slice_inner as $crate::__private::diesel::expression::AsExpression<
+                $st,
+            >>::Expression;
+
+            #[inline]
+            fn as_expression(self) -> Self::Expression {
+                let slice_inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(*self);
+                <&'a $slice_inner as $crate::__private::diesel::expression::AsExpression<
+                    $st,
+                >>::as_expression(slice_inner)
+            }
+        }
+    };
+    (
+        @ToSql<$st:ty>; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty,
+            $error:ty, $slice_spec:ty, $slice_inner:ty);
+    ) => {
+        impl<DB> $crate::__private::diesel::serialize::ToSql<$st, DB> for $custom
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            $custom: $core::fmt::Debug,
+            $slice_inner: $crate::__private::diesel::serialize::ToSql<$st, DB>,
+        {
+            #[inline]
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut $crate::__private::diesel::serialize::Output<'b, '_, DB>,
+            ) -> $crate::__private::diesel::serialize::Result {
+                let slice_inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                <$slice_inner as $crate::__private::diesel::serialize::ToSql<$st, DB>>::to_sql(
+                    slice_inner,
+                    out,
+                )
+            }
+        }
+    };
+    (
+        @FromSql<$st:ty>; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty,
+            $error:ty, $slice_spec:ty, $slice_inner:ty);
+    ) => {
+        impl<DB> $crate::__private::diesel::deserialize::FromSql<$st, DB> for $custom
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            $inner: $crate::__private::diesel::deserialize::FromSql<$st, DB>,
+            $error: $core::error::Error + $core::marker::Send + $core::marker::Sync + 'static,
+        {
+            fn from_sql(
+                bytes: <DB as $crate::__private::diesel::backend::Backend>::RawValue<'_>,
+            ) -> $crate::__private::diesel::deserialize::Result<Self> {
+                let inner =
+                    <$inner as $crate::__private::diesel::deserialize::FromSql<$st, DB>>::from_sql(
+                        bytes,
+                    )?;
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner),
+                ) {
+                    let e = <$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner);
+                    return Err($alloc::boxed::Box::new(e));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+    (
+        @Queryable; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty,
+            $error:ty, $slice_spec:ty, $slice_inner:ty);
+    ) => {
+        impl<DB, ST> $crate::__private::diesel::deserialize::Queryable<ST, DB> for $custom
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            ST: $crate::__private::diesel::sql_types::SingleValue,
+            $custom: $crate::__private::diesel::deserialize::FromSql<ST, DB>,
+        {
+            type Row = Self;
+
+            #[inline]
+            fn build(row: Self) -> $crate::__private::diesel::deserialize::Result<Self> {
+                Ok(row)
+            }
+        }
+    };
+    (@$target:ident $($rest:tt)*) => {
+        compile_error!(concat!("Unsupported target: diesel::", stringify!($target)));
+    };
+}
+
+/// Emits a compile error, because `diesel` feature is disabled.
+#[cfg(not(feature = "diesel"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_diesel {
+    ($($rest:tt)*) => {
+        compile_error!("`diesel::*` targets require `diesel` feature of `validated-slice` crate");
+    };
+}
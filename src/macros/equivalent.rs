@@ -0,0 +1,54 @@
+//! Macros for `equivalent` crate integration.
+
+/// Implements `equivalent::{Comparable, Equivalent}` for the given query type.
+///
+/// This is used internally by `impl_std_traits_for_owned_slice!` macro.
+#[cfg(feature = "equivalent")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_equivalent {
+    (
+        @Equivalent; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $custom:ty, $query:ty, $slice_inner:ty);
+        |$this:ident| $as_slice_inner:expr;
+        |$key:ident| $key_as_slice_inner:expr
+    ) => {
+        impl<$($lt $(: $bound)?),*> $crate::__private::equivalent::Equivalent<$custom> for $query
+        where
+            $slice_inner: $core::cmp::PartialEq,
+        {
+            #[inline]
+            fn equivalent(&self, $key: &$custom) -> bool {
+                let $this = self;
+                *$as_slice_inner == *$key_as_slice_inner
+            }
+        }
+    };
+    (
+        @Comparable; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $custom:ty, $query:ty, $slice_inner:ty);
+        |$this:ident| $as_slice_inner:expr;
+        |$key:ident| $key_as_slice_inner:expr
+    ) => {
+        impl<$($lt $(: $bound)?),*> $crate::__private::equivalent::Comparable<$custom> for $query
+        where
+            $slice_inner: $core::cmp::Ord,
+        {
+            #[inline]
+            fn compare(&self, $key: &$custom) -> $core::cmp::Ordering {
+                let $this = self;
+                $core::cmp::Ord::cmp($as_slice_inner, $key_as_slice_inner)
+            }
+        }
+    };
+}
+
+/// Emits a compile error, because `equivalent` feature is disabled.
+#[cfg(not(feature = "equivalent"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_equivalent {
+    ($($rest:tt)*) => {
+        compile_error!(
+            "`equivalent` targets require `equivalent` feature of `validated-slice` crate"
+        );
+    };
+}
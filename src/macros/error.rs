@@ -0,0 +1,101 @@
+//! Macros for validation error types.
+
+/// Implements common traits and methods for the validation error type.
+///
+/// The error type should be a struct with `valid_up_to: usize` field, which is the length of the
+/// valid prefix of the input.
+///
+/// # Examples
+///
+/// ```
+/// /// ASCII string validation error.
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// pub struct AsciiError {
+///     /// Byte position of the first invalid byte.
+///     valid_up_to: usize,
+/// }
+///
+/// validated_slice::impl_validation_error! {
+///     Error {
+///         ty: AsciiError,
+///         message: "non-ASCII character found",
+///     };
+/// }
+///
+/// let e = AsciiError { valid_up_to: 3 };
+/// assert_eq!(e.valid_up_to(), 3);
+/// assert_eq!(e.to_string(), "non-ASCII character found at byte position 3");
+/// ```
+///
+/// ## Core and alloc
+///
+/// For no-std use, this macro should be called with `Std { core: core, alloc: alloc };` as
+/// [`impl_std_traits_for_slice!`] macro.
+/// The `Error` trait is taken from the `core` crate given by `Std { .. };`, so
+/// `core::error::Error` (available since Rust 1.81) is implemented with `core: core`, and
+/// `std::error::Error` (which is same trait as `core::error::Error`) is implemented with
+/// `core: std`.
+///
+/// ## Generated items
+///
+/// * `pub fn valid_up_to(&self) -> usize` inherent method.
+/// * `Display` trait impl.
+///     + The message is `"{message} at byte position {valid_up_to}"`.
+/// * `Error` trait impl.
+/// * [`SpannedError`] trait impl.
+///     + The span is the empty range at `valid_up_to`.
+///
+/// [`SpannedError`]: trait.SpannedError.html
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+#[macro_export]
+macro_rules! impl_validation_error {
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Error {
+            ty: $ty:ty,
+            message: $message:expr $(,)?
+        };
+    ) => {
+        impl $ty {
+            /// Returns the length of the valid prefix of the input.
+            #[inline]
+            pub fn valid_up_to(&self) -> usize {
+                self.valid_up_to
+            }
+        }
+
+        impl $core::fmt::Display for $ty {
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                write!(f, "{} at byte position {}", $message, self.valid_up_to)
+            }
+        }
+
+        impl $core::error::Error for $ty {}
+
+        impl $crate::SpannedError for $ty {
+            #[inline]
+            fn span(&self) -> $core::ops::Range<usize> {
+                self.valid_up_to..self.valid_up_to
+            }
+        }
+    };
+    (
+        Error {
+            ty: $ty:ty,
+            message: $message:expr $(,)?
+        };
+    ) => {
+        $crate::__with_default_std! {
+            $crate::impl_validation_error;
+            head=[];
+            std_block;
+            Error {
+                ty: $ty,
+                message: $message,
+            };
+        }
+    };
+}
@@ -0,0 +1,234 @@
+//! Macros for `nom` crate integration.
+
+/// Implements `nom` input traits for the given custom slice type.
+///
+/// This is used internally by `impl_std_traits_for_slice!` macro.
+#[cfg(feature = "nom")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_nom {
+    (
+        @AsBytes; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty);
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $crate::__private::nom::AsBytes for &'__a $custom
+        where
+            $inner: $crate::__private::nom::AsBytes,
+        {
+            #[inline]
+            fn as_bytes(&self) -> &[u8] {
+                <$inner as $crate::__private::nom::AsBytes>::as_bytes(
+                    <$spec as $crate::SliceSpec>::as_inner(self),
+                )
+            }
+        }
+    };
+    (
+        @Compare; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty);
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*, __T> $crate::__private::nom::Compare<__T> for &'__a $custom
+        where
+            &'__a $inner: $crate::__private::nom::Compare<__T>,
+        {
+            #[inline]
+            fn compare(&self, t: __T) -> $crate::__private::nom::CompareResult {
+                <&'__a $inner as $crate::__private::nom::Compare<__T>>::compare(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    t,
+                )
+            }
+
+            #[inline]
+            fn compare_no_case(&self, t: __T) -> $crate::__private::nom::CompareResult {
+                <&'__a $inner as $crate::__private::nom::Compare<__T>>::compare_no_case(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    t,
+                )
+            }
+        }
+    };
+    (
+        @FindSubstring; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty);
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*, __T> $crate::__private::nom::FindSubstring<__T> for &'__a $custom
+        where
+            &'__a $inner: $crate::__private::nom::FindSubstring<__T>,
+        {
+            #[inline]
+            fn find_substring(&self, substr: __T) -> $core::option::Option<usize> {
+                <&'__a $inner as $crate::__private::nom::FindSubstring<__T>>::find_substring(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    substr,
+                )
+            }
+        }
+    };
+    (
+        @FindToken; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty);
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*, __T> $crate::__private::nom::FindToken<__T> for &'__a $custom
+        where
+            &'__a $inner: $crate::__private::nom::FindToken<__T>,
+        {
+            #[inline]
+            fn find_token(&self, token: __T) -> bool {
+                <&'__a $inner as $crate::__private::nom::FindToken<__T>>::find_token(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    token,
+                )
+            }
+        }
+    };
+    (
+        @Input; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty);
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $crate::__private::nom::Input for &'__a $custom
+        where
+            &'__a $inner: $crate::__private::nom::Input,
+        {
+            type Item = <&'__a $inner as $crate::__private::nom::Input>::Item;
+            type Iter = <&'__a $inner as $crate::__private::nom::Input>::Iter;
+            type IterIndices = <&'__a $inner as $crate::__private::nom::Input>::IterIndices;
+
+            #[inline]
+            fn input_len(&self) -> usize {
+                <&'__a $inner as $crate::__private::nom::Input>::input_len(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                )
+            }
+
+            fn take(&self, index: usize) -> Self {
+                $crate::__private::assert_slice_closed_spec::<$spec>();
+                let inner = <&'__a $inner as $crate::__private::nom::Input>::take(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    index,
+                );
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(inner)` returns `Ok(())`.
+                    //     + This is ensured by `$spec: SliceClosedSpec` (asserted above),
+                    //       because `inner` is a sub-slice of the valid slice.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $spec, inner)
+                }
+            }
+
+            fn take_from(&self, index: usize) -> Self {
+                $crate::__private::assert_slice_closed_spec::<$spec>();
+                let inner = <&'__a $inner as $crate::__private::nom::Input>::take_from(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    index,
+                );
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(inner)` returns `Ok(())`.
+                    //     + This is ensured by `$spec: SliceClosedSpec` (asserted above),
+                    //       because `inner` is a sub-slice of the valid slice.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $spec, inner)
+                }
+            }
+
+            fn take_split(&self, index: usize) -> (Self, Self) {
+                $crate::__private::assert_slice_closed_spec::<$spec>();
+                let (suffix, prefix) = <&'__a $inner as $crate::__private::nom::Input>::take_split(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    index,
+                );
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(suffix)` and `$spec::validate(prefix)` return `Ok(())`.
+                    //     + This is ensured by `$spec: SliceClosedSpec` (asserted above),
+                    //       because `suffix` and `prefix` are sub-slices of the valid slice.
+                    // * Safety condition for `<$spec as $crate::SliceSpec>` is satisfied.
+                    (
+                        $crate::__from_inner_unchecked!(slice; $spec, suffix),
+                        $crate::__from_inner_unchecked!(slice; $spec, prefix),
+                    )
+                }
+            }
+
+            #[inline]
+            fn position<P>(&self, predicate: P) -> $core::option::Option<usize>
+            where
+                P: Fn(Self::Item) -> bool,
+            {
+                <&'__a $inner as $crate::__private::nom::Input>::position(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    predicate,
+                )
+            }
+
+            #[inline]
+            fn iter_elements(&self) -> Self::Iter {
+                <&'__a $inner as $crate::__private::nom::Input>::iter_elements(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                )
+            }
+
+            #[inline]
+            fn iter_indices(&self) -> Self::IterIndices {
+                <&'__a $inner as $crate::__private::nom::Input>::iter_indices(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                )
+            }
+
+            #[inline]
+            fn slice_index(
+                &self,
+                count: usize,
+            ) -> $core::result::Result<usize, $crate::__private::nom::Needed> {
+                <&'__a $inner as $crate::__private::nom::Input>::slice_index(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    count,
+                )
+            }
+        }
+    };
+    (
+        @Offset; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty);
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $crate::__private::nom::Offset for &'__a $custom
+        where
+            &'__a $inner: $crate::__private::nom::Offset,
+        {
+            #[inline]
+            fn offset(&self, second: &Self) -> usize {
+                <&'__a $inner as $crate::__private::nom::Offset>::offset(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                    &<$spec as $crate::SliceSpec>::as_inner(second),
+                )
+            }
+        }
+    };
+    (
+        @ParseTo; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty);
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*, __R> $crate::__private::nom::ParseTo<__R> for &'__a $custom
+        where
+            &'__a $inner: $crate::__private::nom::ParseTo<__R>,
+        {
+            #[inline]
+            fn parse_to(&self) -> $core::option::Option<__R> {
+                <&'__a $inner as $crate::__private::nom::ParseTo<__R>>::parse_to(
+                    &<$spec as $crate::SliceSpec>::as_inner(self),
+                )
+            }
+        }
+    };
+    (@$target:ident; $($rest:tt)*) => {
+        compile_error!(concat!("Unsupported target: nom::", stringify!($target)));
+    };
+}
+
+/// Emits a compile error, because `nom` feature is disabled.
+#[cfg(not(feature = "nom"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_nom {
+    ($($rest:tt)*) => {
+        compile_error!("`nom::*` targets require `nom` feature of `validated-slice` crate");
+    };
+}
@@ -96,6 +96,8 @@
 /// ## Core and alloc
 ///
 /// For `no_std` use, the macro uses custom `core` and `alloc` crate if given.
+/// If `Std { .. };` is omitted, the macro uses `core` and `alloc` crates re-exported from this
+/// crate (`alloc` requires `alloc` feature), regardless of the environment of the caller.
 /// You can support both nostd and non-nostd environment as below:
 ///
 /// ```ignore
@@ -116,6 +118,25 @@
 /// }
 /// ```
 ///
+/// ## Omitting spec fields
+///
+/// All fields except for `spec` can be omitted as `Spec { spec: MyStringSpec };`.
+/// In this case, they are resolved through the associated types of [`OwnedSliceSpec`] trait,
+/// such as `<MyStringSpec as OwnedSliceSpec>::SliceCustom`.
+/// Note that the spec type should be as visible as the custom type in this case, because
+/// associated types of the generated trait impls (such as `Deref::Target`) refer to it.
+///
+/// ## Lifetime parameters
+///
+/// If the custom types have lifetime parameters, declare them by `lifetimes: ['a],` field at the
+/// head of `Spec { .. }`, as [`impl_std_traits_for_slice!`] macro.
+/// In this case, spec fields cannot be omitted.
+///
+/// ## Type tag parameters
+///
+/// If the custom types have zero-sized tag type parameters (such as `NameBuf<T>`), declare them by
+/// `tags: [T],` field after `lifetimes`, as [`impl_std_traits_for_slice!`] macro.
+///
 /// ## Type names
 ///
 /// As type name, you can use `{Custom}` and `{Inner}` instead of a real type name.
@@ -133,8 +154,12 @@
 /// Each trait impl is specified by `{ TraitName<TyParams> for TyImplTarget };` format.
 /// `<TyParams>` part and `for TyImplTarget` part is optional.
 ///
-/// Default impl target is `{Custom}`, and it should NOT be specified explicitly.
-/// Explicit `for {Custom}` is not supported and will cause compile error.
+/// Default impl target is `{Custom}`.
+/// Explicit `for {Custom}` is also accepted, and it is same as omitting `for TyImplTarget` part.
+///
+/// Mutable targets (`{ AsMut<{SliceCustom}> }`, `{ BorrowMut<{SliceCustom}> }`, and
+/// `{ DerefMut<Target = {SliceCustom}> }`) require the spec to implement [`OwnedSliceMutSpec`].
+/// Other targets can be used with immutable owned inner types such as `Arc<str>`.
 ///
 /// Supported trait impls are:
 ///
@@ -151,34 +176,258 @@
 ///     + `{ AsRef<any_ty> };`
 ///     + `{ From<&{SliceInner}> };`
 ///     + `{ From<&{SliceCustom}> };`
+///     + `{ From<Cow<{SliceCustom}>> };`
+///         - This requires `{SliceCustom}: ToOwned<Owned = {Custom}>`.
+///         - The owned value is reused if the `Cow` is `Cow::Owned`.
 ///     + `{ From<{Inner}> };`
 ///     + `{ From<{Custom}> for {Inner} };`
+///     + `{ From<&{Custom}> for {Inner} };`
+///     + `{ From<&{SliceCustom}> for {Inner} };`
+///         - These require `{Inner}: From<&{SliceInner}>`, and clone the inner slice.
+///     + `{ From<&{Custom}> for &{SliceCustom} };`
+///         - This lets generic code bounded by `T: TryInto<&{SliceCustom}>` accept the owned
+///           custom value as well as `&{SliceInner}` (with `{ TryFrom<&{Inner}> for &{Custom} }`
+///           target of `impl_std_traits_for_slice!`) and `&{SliceCustom}`.
+///         - The reflexive conversion from `&{SliceCustom}` is provided by the blanket impls of
+///           std, so it has no target.
+///     + `{ From<{Custom}> for PathBuf };`
+///         - This requires `std`, and `PathBuf: From<{Inner}>`.
+///     + `{ From<{SliceError}> for {Error} };`
+///         - This converts the error by [`ConvertSliceErrorSpec::convert_slice_error()`], and
+///           requires `{Spec}: ConvertSliceErrorSpec`.
+///         - This is useful to use `?` for slice validation results in functions returning
+///           `{Error}`.
+///         - This conflicts with the blanket impl of std when `{SliceError}` and `{Error}` are the
+///           same type.
 ///     + `{ TryFrom<&{SliceInner}> };`
 ///     + `{ TryFrom<{Inner}> };`
+///     + `{ TryFrom<Cow<{SliceInner}>> };`
+///         - This requires `{SliceInner}: ToOwned<Owned = {Inner}>`.
+///         - The owned value is reused if the `Cow` is `Cow::Owned`.
+///     + `{ TryFrom<Vec<u8>> };`
+///         - This requires `{Inner}: From<String>`, and is intended for `String`-backed custom
+///           types.
+///         - This checks UTF-8 validity and then validates the string, and the error type is
+///           [`FromBytesError`]`<FromUtf8Error, {Error}>`.
 /// * `std::default`
 ///     + `{ Default };`
 ///         - Note that this redirects to trait impls for `{SliceCustom}`, rather than for `{Inner}`
 ///           or `{SliceInner}`.
 /// * `std::fmt`
 ///     + `{ Debug };`
+///     + `{ Debug(name = "Name") };`
+///         - Formats as a tuple struct `Name(..)` with the `{SliceInner}` as its field.
+///     + `{ Debug(with = path::to::fn) };`
+///         - The function should have the type `fn(&{SliceInner}, &mut Formatter<'_>) -> fmt::Result`.
 ///     + `{ Display };`
-///     + Note that these redirects to trait impls for `{SliceCustom}`, rather than for `{Inner}` or
-///       `{SliceInner}`.
+///     + `{ Display(with = path::to::fn) };`
+///         - The function should have the type `fn(&{SliceInner}, &mut Formatter<'_>) -> fmt::Result`.
+///     + `{ LowerHex };`, `{ UpperHex };`, `{ Binary };`, `{ Octal };`
+///     + Note that `{ Debug };`, `{ Display };`, and the traits above redirect to trait impls for
+///       `{SliceCustom}`, rather than for `{Inner}` or `{SliceInner}`.
+/// * `std::hash`
+///     + `{ Hash };`
+///         - This redirects to trait impls for `{SliceCustom}`, so borrowed and owned values are
+///           hashed in the same way.
+///     + `{ Hash(with = path::to::fn) };`
+///         - The function should have the type `fn<H: Hasher>(&{SliceInner}, &mut H)`.
+///         - Use this together with custom comparison (`base: Custom`), so that `Hash` stays
+///           consistent with `PartialEq`.
+///     + `{ !Hash };`
+///         - This implements nothing, and explicitly opts out of `Hash` delegation.
+///           See "Checks" section.
+/// * `std::cmp`
+///     + `{ Ord };`
+///         - This redirects to trait impls for `{SliceCustom}`, and requires
+///           `{Custom}: Eq + PartialOrd`.
+///     + `{ !Ord };`
+///         - This implements nothing, and explicitly opts out of `Ord` delegation.
+///           See "Checks" section.
 /// * `std::ops`
 ///     + `{ Deref<Target = {SliceCustom}> };`
 ///     + `{ DerefMut<Target = {SliceCustom}> };`
+///     + `{ Index<usize> };`
+///         - `Output` is same as `<{SliceInner} as Index<usize>>::Output`.
+///     + `{ Index<Range<usize>, Output = {SliceCustom}> };`
+///         - `Range` can be `Range`, `RangeFrom`, `RangeTo`, `RangeInclusive`, `RangeToInclusive`,
+///           and `RangeFull` (written as `Index<RangeFull, Output = {SliceCustom}>`).
+///         - This requires `{SliceSpec}: SliceClosedSpec`, so that the sub-slice is not
+///           validated.
+///     + `{ Index<Range<usize>, Output = {SliceCustom}>(revalidate) };`
+///         - This validates the sub-slice, and panics if it is invalid.
+///         - This is available for the specs which are not closed under sub-slicing.
 /// * `std::str`
 ///     + `{ FromStr };`
+///         - This requires `str: AsRef<{SliceInner}>` and `{Inner}: From<&str>`, so this is
+///           available for `str`, `OsStr`, and `Path` inner types.
+///         - `Err` is `{SliceError}`.
+///     + `{ FromStr(error = {Error}) };`
+///         - `Err` is `{Error}`, and the error is created by
+///           `OwnedSliceSpec::convert_validation_error`, as `{ TryFrom<{Inner}> }` does.
+///     + `{ FromStr(error = err_ty, map_err = map_fn) };`
+///         - `Err` is `err_ty`, and the error is created by `map_fn(e)` where `e` is
+///           `{SliceError}`.
+///         - For example, `{ FromStr(error = MyError, map_err = MyError::from) };` converts the
+///           error by `From<{SliceError}> for MyError`.
+/// * `validated_slice`
+///     + `{ ErasedValidatedSlice };`
+///         - This implements [`ErasedValidatedSlice`], and requires `{SliceInner}: AsRef<[u8]>`.
+/// * `diesel` (requires `diesel` feature)
+///     + `{ diesel::AsExpression<sql_ty> };`
+///         - `AsExpression<sql_ty>` and `AsExpression<Nullable<sql_ty>>` for `{Custom}`,
+///           `&{Custom}`, and `&&{Custom}`, and `ToSql<Nullable<sql_ty>, _>` for `{Custom}`.
+///         - The expressions are the ones of `{Inner}` and `&{SliceInner}`, so this requires
+///           `{Inner}: AsExpression<sql_ty>` and `&{SliceInner}: AsExpression<sql_ty>`.
+///     + `{ diesel::FromSql<sql_ty> };`
+///         - The loaded value is validated, and `{Error}` is returned on failure.
+///         - This requires `{Error}: core::error::Error + Send + Sync + 'static`.
+///     + `{ diesel::Queryable };`
+///         - `Queryable<st, _>` for single value SQL types `st` which `{Custom}` implements
+///           `FromSql<st, _>` for. This is what `#[derive(diesel::FromSqlRow)]` generates.
+///     + `{ diesel::ToSql<sql_ty> };`
+///         - `{SliceInner}` is used to serialize the value.
+///     + `sql_ty` is a SQL type such as `diesel::sql_types::Text` and
+///       `diesel::sql_types::Binary`.
+/// * `rand` (requires `rand` feature)
+///     + `{ rand::Distribution };`
+///         - `Distribution<{Custom}>` for `rand::distr::StandardUniform`.
+///         - This requires `$spec: GenerateSpec`.
+/// * `rkyv` (requires `rkyv` feature)
+///     + `{ rkyv::Archive };`
+///         - The archived form is same as the archived form of `Box<{SliceInner}>`.
+///     + `{ rkyv::Serialize };`
+///     + `{ rkyv::Deserialize };`
+///         - `Deserialize<{Custom}, _>` for the archived form of `Box<{SliceInner}>`.
+///         - The deserialized value is validated, and `{Error}` is returned on failure.
+/// * `defmt` (requires `defmt` feature)
+///     + `{ defmt::Format };`
+///         - This is delegated to `{SliceInner}`.
+/// * `schemars` (requires `schemars` feature)
+///     + `{ JsonSchema };`
+///         - The schema of `{Inner}` is used.
+///     + `{ JsonSchema(spec) };`
+///         - The schema of `{Inner}` is used, with `format` and `pattern` annotations given by
+///           [`JsonSchemaSpec`] impl of `{SliceSpec}`.
+/// * `ufmt` (requires `ufmt` feature)
+///     + `{ ufmt::uDebug };`
+///     + `{ ufmt::uDisplay };`
+///         - These are delegated to `{SliceInner}`.
+/// * `equivalent` (requires `equivalent` feature)
+///     + `{ equivalent::Equivalent<{Custom}> for {SliceCustom} };`
+///     + `{ equivalent::Equivalent<{Custom}> for {SliceInner} };`
+///     + `{ equivalent::Comparable<{Custom}> for {SliceCustom} };`
+///     + `{ equivalent::Comparable<{Custom}> for {SliceInner} };`
+///         - These compare the inner slices, and let `hashbrown` and `indexmap` maps keyed by
+///           `{Custom}` be looked up by `&{SliceCustom}` or `&{SliceInner}` without cloning.
+///         - `Hash` impls of `{Custom}` and the query type should be consistent.
+///         - These conflict with the blanket impls of `equivalent` crate when `{Custom}` implements
+///           `Borrow` to the query type, because the blanket impls already cover such cases.
+/// * `sqlx` (requires `sqlx` feature)
+///     + `{ sqlx::Type };`
+///     + `{ sqlx::Encode };`
+///         - `Encode::encode_by_ref()` encodes the borrowed `&{SliceInner}`, so this requires
+///           `&{SliceInner}: Encode` for any borrow lifetime. Note that this does not hold for
+///           SQLite, whose `&str` and `&[u8]` impls tie the borrow to the argument buffer.
+///     + `{ sqlx::Decode };`
+///         - The decoded value is validated, and `{Error}` is returned on failure.
+///
+/// ## Checks
+///
+/// Additional checks can be enabled by `Check { .. };` right after `Spec { .. };`, such as
+/// `Check { Borrow, Trusted };`.
+///
+/// * `Trusted`: Requires the spec and its slice spec to implement [`TrustedSpec`].
+/// * `NoPanic`: Rejects targets which panic on validation failure, unless the validation error
+///   type of the slice spec is `core::convert::Infallible`.
+///     + Such targets are `From<&{SliceInner}>`, `From<{Inner}>`,
+///       `Index<Range<usize>, Output = {SliceCustom}>(revalidate)`, and `rand::Distribution`.
+/// * `Borrow`: See below.
+///
+/// ### Borrow contract check
+///
+/// `Borrow<T>` requires `Hash`, `Eq`, and `Ord` of the borrowed and the owned values to be
+/// consistent, but this is easily overlooked.
+///
+/// With `Check { Borrow };`, the macro requires `Hash` and `Ord` to be also specified when
+/// `Borrow<..>` targets are specified.
+/// `Hash` is satisfied by `{ Hash };` or `{ Hash(with = ..) };`, and `Ord` is satisfied by
+/// `{ Ord };`.
+/// If the type should not implement them, opt out explicitly by `{ !Hash };` or `{ !Ord };`.
+/// Otherwise, the macro causes compile error.
+///
+/// ```ignore
+/// validated_slice::impl_std_traits_for_owned_slice! {
+///     Spec { /* ... */ };
+///     Check { Borrow };
+///     { Borrow<{SliceCustom}> };
+///     { Hash };
+///     { !Ord };
+///     /* ... */
+/// }
+/// ```
 ///
+/// ## Listing the generated impls
+///
+/// `@list(vis const NAME);` at the beginning defines a constant `NAME: &[&str]` which lists the
+/// trait impl targets, as [`impl_std_traits_for_slice!`] does.
+/// Opt-outs such as `{ !Hash };` are not listed.
+///
+/// [`ConvertSliceErrorSpec::convert_slice_error()`]: trait.ConvertSliceErrorSpec.html#tymethod.convert_slice_error
+/// [`ErasedValidatedSlice`]: trait.ErasedValidatedSlice.html
+/// [`FromBytesError`]: enum.FromBytesError.html
+/// [`JsonSchemaSpec`]: trait.JsonSchemaSpec.html
+/// [`OwnedSliceMutSpec`]: trait.OwnedSliceMutSpec.html
+/// [`OwnedSliceSpec`]: trait.OwnedSliceSpec.html
+/// [`TrustedSpec`]: trait.TrustedSpec.html
 /// [`impl_cmp_for_owned_slice!`]: macro.impl_cmp_for_owned_slice.html
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
 #[macro_export]
 macro_rules! impl_std_traits_for_owned_slice {
+    // Lists the targets as a constant.
+    (
+        @list($vis:vis const $name:ident);
+        $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! { $($rest)* }
+        $crate::impl_std_traits_for_owned_slice! {
+            @list_collect; ($vis, $name); []; $($rest)*
+        }
+    };
+    (
+        @list_collect; ($vis:vis, $name:ident); [$($acc:expr,)*]; $(;)?
+    ) => {
+        $vis const $name: &[&str] = &[$($acc),*];
+    };
+    (
+        @list_collect; $params:tt; [$($acc:expr,)*]; $_kw:ident { $($_fields:tt)* }; $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @list_collect; $params; [$($acc,)*]; $($rest)*
+        }
+    };
+    (
+        @list_collect; $params:tt; [$($acc:expr,)*]; { ! $($_target:tt)* } $(; $($rest:tt)*)?
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @list_collect; $params; [$($acc,)*]; $($($rest)*)?
+        }
+    };
+    (
+        @list_collect; $params:tt; [$($acc:expr,)*]; { $($entry:tt)* } $(; $($rest:tt)*)?
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @list_collect; $params; [$($acc,)* stringify!($($entry)*),]; $($($rest)*)?
+        }
+    };
+
     (
         Std {
             core: $core:ident,
             alloc: $alloc:ident,
         };
         Spec {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
             spec: $spec:ty,
             custom: $custom:ty,
             inner: $inner:ty,
@@ -189,18 +438,18 @@ macro_rules! impl_std_traits_for_owned_slice {
         };
         $({$($rest:tt)*});* $(;)?
     ) => {
-        $(
-            $crate::impl_std_traits_for_owned_slice! {
-                @impl; ({$core, $alloc}, $spec, $custom, $inner, $error,
-                    <$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner,
-                    $slice_error);
-                rest=[$($rest)*];
-            }
-        )*
+        $crate::impl_std_traits_for_owned_slice! {
+            @entries; ({$core, $alloc $($(, $lt)*)? $($(, $tag $(: $tag_bound)?)*)?}, $spec, $custom, $inner, $error,
+                <$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner,
+                $slice_error);
+            $({$($rest)*});*
+        }
     };
 
     (
         Spec {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
             spec: $spec:ty,
             custom: $custom:ty,
             inner: $inner:ty,
@@ -211,23 +460,276 @@ macro_rules! impl_std_traits_for_owned_slice {
         };
         $({$($rest:tt)*});* $(;)?
     ) => {
+        $crate::__with_default_std! {
+            $crate::impl_std_traits_for_owned_slice;
+            head=[@entries;];
+            lifetimes=[$($(, $lt)*)? $($(, $tag $(: $tag_bound)?)*)?];
+            args=[$spec, $custom, $inner, $error, <$spec as $crate::OwnedSliceSpec>::SliceSpec,
+                $slice_custom, $slice_inner, $slice_error];
+            $({$($rest)*});*
+        }
+    };
+    (@entries; $params:tt; $({$($rest:tt)*});* $(;)?) => {
         $(
             $crate::impl_std_traits_for_owned_slice! {
-                @impl; ({std, std}, $spec, $custom, $inner, $error,
-                    <$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner,
-                    $slice_error);
+                @impl; $params;
                 rest=[$($rest)*];
             }
         )*
     };
 
+    // Spec fields inferred from the spec type.
+    (
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::OwnedSliceSpec>::Custom,
+                inner: <$spec as $crate::OwnedSliceSpec>::Inner,
+                error: <$spec as $crate::OwnedSliceSpec>::Error,
+                slice_custom: <$spec as $crate::OwnedSliceSpec>::SliceCustom,
+                slice_inner: <$spec as $crate::OwnedSliceSpec>::SliceInner,
+                slice_error: <$spec as $crate::OwnedSliceSpec>::SliceError,
+            };
+            $($rest)*
+        }
+    };
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            Std {
+                core: $core,
+                alloc: $alloc,
+            };
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::OwnedSliceSpec>::Custom,
+                inner: <$spec as $crate::OwnedSliceSpec>::Inner,
+                error: <$spec as $crate::OwnedSliceSpec>::Error,
+                slice_custom: <$spec as $crate::OwnedSliceSpec>::SliceCustom,
+                slice_inner: <$spec as $crate::OwnedSliceSpec>::SliceInner,
+                slice_error: <$spec as $crate::OwnedSliceSpec>::SliceError,
+            };
+            $($rest)*
+        }
+    };
+
+    // Checks.
+    (
+        $(
+            Std {
+                core: $core:ident,
+                alloc: $alloc:ident,
+            };
+        )?
+        Spec $spec_fields:tt;
+        Check { $($check:ident),+ $(,)? };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check; [$($check),+]; $spec_fields; [$({$($rest)*})*];
+        }
+        $crate::impl_std_traits_for_owned_slice! {
+            $(
+                Std {
+                    core: $core,
+                    alloc: $alloc,
+                };
+            )?
+            Spec $spec_fields;
+            $({$($rest)*});*
+        }
+    };
+    (@check; [$check:ident $(, $checks:ident)*]; $spec_fields:tt; $entries:tt;) => {
+        $crate::impl_std_traits_for_owned_slice! { @check[$check]; $spec_fields; $entries; }
+        $crate::impl_std_traits_for_owned_slice! { @check; [$($checks),*]; $spec_fields; $entries; }
+    };
+    (@check; []; $spec_fields:tt; $entries:tt;) => {};
+    (@check[Trusted]; { spec: $spec:ty $(, $($_fields:tt)*)? }; $entries:tt;) => {
+        const _: fn() = $crate::__private::assert_trusted_owned_slice_spec::<$spec>;
+    };
+    (
+        @check[Trusted];
+        {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
+            spec: $spec:ty $(, $($_fields:tt)*)?
+        };
+        $entries:tt;
+    ) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_trusted<$($($lt,)*)? $($($tag $(: $tag_bound)?,)*)?>() {
+                $crate::__private::assert_trusted_owned_slice_spec::<$spec>();
+            }
+        };
+    };
+    (@check[Borrow]; $spec_fields:tt; [$($entries:tt)*];) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_borrow; borrow=[]; hash=[]; ord=[];
+            rest=[$($entries)*];
+        }
+    };
+    (@check[NoPanic]; $spec_fields:tt; [$($entries:tt)*];) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($entries)*];
+        }
+    };
+    (@check[$check:ident]; $spec_fields:tt; $entries:tt;) => {
+        compile_error!(concat!("Unsupported check: ", stringify!($check)));
+    };
+
+    // No-panic check.
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[{ From<&{SliceInner}> $(for {Custom})? } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic_assert; $spec_fields;
+        }
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[{ From<{Inner}> $(for {Custom})? } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic_assert; $spec_fields;
+        }
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[
+            { Index<$_range:ident<usize>, Output = {SliceCustom}>(revalidate) $(for {Custom})? }
+            $($rest:tt)*
+        ];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic_assert; $spec_fields;
+        }
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[{ rand::Distribution $(for {Custom})? } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic_assert; $spec_fields;
+        }
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_no_panic; $spec_fields:tt;
+        rest=[{ $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_no_panic; $spec_fields;
+            rest=[$($rest)*];
+        }
+    };
+    (@check_no_panic; $spec_fields:tt; rest=[];) => {};
+    (@check_no_panic_assert; { spec: $spec:ty $(, $($_fields:tt)*)? };) => {
+        const _: fn() = $crate::__private::assert_infallible_owned_slice_spec::<$spec>;
+    };
+    (
+        @check_no_panic_assert;
+        {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
+            spec: $spec:ty $(, $($_fields:tt)*)?
+        };
+    ) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_no_panic<$($($lt,)*)? $($($tag $(: $tag_bound)?,)*)?>() {
+                $crate::__private::assert_infallible_owned_slice_spec::<$spec>();
+            }
+        };
+    };
+
+    // Borrow contract check.
+    (
+        @check_borrow; borrow=[$($borrow:tt)*]; hash=$hash:tt; ord=$ord:tt;
+        rest=[{ Borrow $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_borrow; borrow=[$($borrow)* Borrow]; hash=$hash; ord=$ord;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_borrow; borrow=$borrow:tt; hash=[$($hash:tt)*]; ord=$ord:tt;
+        rest=[{ $(!)? Hash $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_borrow; borrow=$borrow; hash=[$($hash)* Hash]; ord=$ord;
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_borrow; borrow=$borrow:tt; hash=$hash:tt; ord=[$($ord:tt)*];
+        rest=[{ $(!)? Ord $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_borrow; borrow=$borrow; hash=$hash; ord=[$($ord)* Ord];
+            rest=[$($rest)*];
+        }
+    };
+    (
+        @check_borrow; borrow=$borrow:tt; hash=$hash:tt; ord=$ord:tt;
+        rest=[{ $($_target:tt)* } $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @check_borrow; borrow=$borrow; hash=$hash; ord=$ord;
+            rest=[$($rest)*];
+        }
+    };
+    (@check_borrow; borrow=[]; hash=$hash:tt; ord=$ord:tt; rest=[];) => {};
+    (@check_borrow; borrow=[$($borrow:tt)+]; hash=[$($hash:tt)*]; ord=[$($ord:tt)*]; rest=[];) => {
+        $crate::impl_std_traits_for_owned_slice! { @check_borrow_missing; Hash; [$($hash)*] }
+        $crate::impl_std_traits_for_owned_slice! { @check_borrow_missing; Ord; [$($ord)*] }
+    };
+    (@check_borrow_missing; $trait:ident; [$($found:tt)+]) => {};
+    (@check_borrow_missing; $trait:ident; []) => {
+        compile_error!(concat!(
+            "`Borrow` target requires `{ ", stringify!($trait), " }` to keep the `Borrow` contract, ",
+            "or `{ !", stringify!($trait), " }` to explicitly opt out"
+        ));
+    };
+
     // std::borrow::Borrow
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ Borrow<{SliceCustom}> ];
     ) => {
-        impl $core::borrow::Borrow<$slice_custom> for $custom {
+        impl<$($lt $(: $bound)?),*> $core::borrow::Borrow<$slice_custom> for $custom {
             #[inline]
             fn borrow(&self) -> &$slice_custom {
                 unsafe {
@@ -242,11 +744,11 @@ macro_rules! impl_std_traits_for_owned_slice {
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ Borrow<$param:ty> ];
     ) => {
-        impl $core::borrow::Borrow<$param> for $custom
+        impl<$($lt $(: $bound)?),*> $core::borrow::Borrow<$param> for $custom
         where
             $slice_inner: $core::borrow::Borrow<$param>,
         {
@@ -259,11 +761,11 @@ macro_rules! impl_std_traits_for_owned_slice {
 
     // std::borrow::BorrowMut
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ BorrowMut<{SliceCustom}> ];
     ) => {
-        impl $core::borrow::BorrowMut<$slice_custom> for $custom {
+        impl<$($lt $(: $bound)?),*> $core::borrow::BorrowMut<$slice_custom> for $custom {
             #[inline]
             fn borrow_mut(&mut self) -> &mut $slice_custom {
                 unsafe {
@@ -278,30 +780,30 @@ macro_rules! impl_std_traits_for_owned_slice {
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ BorrowMut<$param:ty> ];
     ) => {
-        impl $core::borrow::BorrowMut<$param> for $custom
+        impl<$($lt $(: $bound)?),*> $core::borrow::BorrowMut<$param> for $custom
         where
             $slice_inner: $core::borrow::BorrowMut<$param>,
         {
             #[inline]
             fn borrow_mut(&mut self) -> &mut $param {
-                <$spec as $crate::OwnedSliceSpec>::as_slice_inner_mut(self).borrow_mut()
+                <$spec as $crate::OwnedSliceMutSpec>::as_slice_inner_mut(self).borrow_mut()
             }
         }
     };
 
     // std::borrow::ToOwned
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ ToOwned<Owned = {Custom}> for {SliceCustom} ];
     ) => {
-        impl $alloc::borrow::ToOwned for $slice_custom
+        impl<$($lt $(: $bound)?),*> $alloc::borrow::ToOwned for $slice_custom
         where
-            for<'a> $inner: From<&'a $slice_inner>,
+            for<'__a> $inner: From<&'__a $slice_inner>,
         {
             type Owned = $custom;
 
@@ -313,7 +815,7 @@ macro_rules! impl_std_traits_for_owned_slice {
                     // * `$spec::validate(self)` returns `Ok(())`.
                     //     + This is ensured when `self` is created.
                     // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-                    <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
                 }
             }
         }
@@ -321,11 +823,11 @@ macro_rules! impl_std_traits_for_owned_slice {
 
     // std::convert::AsMut
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ AsMut<{SliceCustom}> ];
     ) => {
-        impl $core::convert::AsMut<$slice_custom> for $custom {
+        impl<$($lt $(: $bound)?),*> $core::convert::AsMut<$slice_custom> for $custom {
             #[inline]
             fn as_mut(&mut self) -> &mut $slice_custom {
                 unsafe {
@@ -340,28 +842,28 @@ macro_rules! impl_std_traits_for_owned_slice {
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ AsMut<$param:ty> ];
     ) => {
-        impl $core::convert::AsMut<$param> for $custom
+        impl<$($lt $(: $bound)?),*> $core::convert::AsMut<$param> for $custom
         where
             $slice_inner: $core::convert::AsMut<$param>,
         {
             #[inline]
-            fn as_mut(&self) -> &$param {
-                <$spec as $crate::OwnedSliceSpec>::as_slice_inner_mut(self).as_mut()
+            fn as_mut(&mut self) -> &mut $param {
+                <$spec as $crate::OwnedSliceMutSpec>::as_slice_inner_mut(self).as_mut()
             }
         }
     };
 
     // std::convert::AsRef
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ AsRef<{SliceCustom}> ];
     ) => {
-        impl $core::convert::AsRef<$slice_custom> for $custom {
+        impl<$($lt $(: $bound)?),*> $core::convert::AsRef<$slice_custom> for $custom {
             #[inline]
             fn as_ref(&self) -> &$slice_custom {
                 unsafe {
@@ -376,11 +878,11 @@ macro_rules! impl_std_traits_for_owned_slice {
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ AsRef<$param:ty> ];
     ) => {
-        impl $core::convert::AsRef<$param> for $custom
+        impl<$($lt $(: $bound)?),*> $core::convert::AsRef<$param> for $custom
         where
             $slice_inner: $core::convert::AsRef<$param>,
         {
@@ -393,42 +895,35 @@ macro_rules! impl_std_traits_for_owned_slice {
 
     // std::convert::From
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ From<&{SliceInner}> ];
     ) => {
-        impl<'a> $core::convert::From<&'a $slice_inner> for $custom
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a $slice_inner> for $custom
         where
-            $inner: From<&'a $slice_inner>,
+            $inner: From<&'__a $slice_inner>,
         {
-            fn from(s: &'a $slice_inner) -> Self {
-                assert!(
-                    <$slice_spec as $crate::SliceSpec>::validate(s).is_ok(),
-                    "Attempt to convert invalid data: `From<&{}> for {}`",
-                    stringify!($slice_inner), stringify!($custom)
-                );
-                let inner = <$inner>::from(s);
-                unsafe {
-                    // This is safe only when all of the conditions below are met:
-                    //
-                    // * `$spec::validate(s)` returns `Ok(())`.
-                    //     + This is ensured by the leading assert.
-                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-                    <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
+            fn from(s: &'__a $slice_inner) -> Self {
+                match $crate::Validated::<$slice_spec>::new(s) {
+                    Ok(valid) => valid.into_owned::<$spec>(),
+                    Err(_) => panic!(
+                        "Attempt to convert invalid data: `From<&{}> for {}`",
+                        stringify!($slice_inner), stringify!($custom)
+                    ),
                 }
             }
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ From<&{SliceCustom}> ];
     ) => {
-        impl<'a> $core::convert::From<&'a $slice_custom> for $custom
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a $slice_custom> for $custom
         where
-            $inner: From<&'a $slice_inner>,
+            $inner: From<&'__a $slice_inner>,
         {
-            fn from(s: &'a $slice_custom) -> Self {
+            fn from(s: &'__a $slice_custom) -> Self {
                 let inner = <$inner>::from(<$slice_spec as $crate::SliceSpec>::as_inner(s));
                 unsafe {
                     // This is safe only when all of the conditions below are met:
@@ -436,17 +931,47 @@ macro_rules! impl_std_traits_for_owned_slice {
                     // * `$spec::validate(s)` returns `Ok(())`.
                     //     + This is ensured when `s` is created.
                     // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-                    <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
                 }
             }
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ From<Cow<{SliceCustom}>> ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<$alloc::borrow::Cow<'__a, $slice_custom>> for $custom
+        where
+            $slice_custom: $alloc::borrow::ToOwned<Owned = $custom>,
+        {
+            #[inline]
+            fn from(s: $alloc::borrow::Cow<'__a, $slice_custom>) -> Self {
+                s.into_owned()
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ From<{SliceError}> for {Error} ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::convert::From<$slice_error> for $error
+        where
+            $spec: $crate::ConvertSliceErrorSpec,
+        {
+            #[inline]
+            fn from(e: $slice_error) -> Self {
+                <$spec as $crate::ConvertSliceErrorSpec>::convert_slice_error(e)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ From<{Inner}> ];
     ) => {
-        impl $core::convert::From<$inner> for $custom {
+        impl<$($lt $(: $bound)?),*> $core::convert::From<$inner> for $custom {
             fn from(inner: $inner) -> Self {
                 assert!(
                     <$slice_spec as $crate::SliceSpec>::validate(
@@ -461,109 +986,230 @@ macro_rules! impl_std_traits_for_owned_slice {
                     // * `$spec::validate(s)` returns `Ok(())`.
                     //     + This is ensured by the leading assert.
                     // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-                    <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
                 }
             }
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ From<{Custom}> for {Inner} ];
     ) => {
-        impl $core::convert::From<$custom> for $inner {
+        impl<$($lt $(: $bound)?),*> $core::convert::From<$custom> for $inner {
             fn from(custom: $custom) -> Self {
                 <$spec as $crate::OwnedSliceSpec>::into_inner(custom)
             }
         }
     };
-
-    // std::convert::TryFrom
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
-        rest=[ TryFrom<&{SliceInner}> ];
+        rest=[ From<&{Custom}> for {Inner} ];
     ) => {
-        impl<'a> $core::convert::TryFrom<&'a $slice_inner> for $custom
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a $custom> for $inner
         where
-            $inner: From<&'a $slice_inner>,
+            $inner: From<&'__a $slice_inner>,
         {
-            type Error = $slice_error;
-
-            fn try_from(s: &'a $slice_inner) -> $core::result::Result<Self, Self::Error> {
-                <$slice_spec as $crate::SliceSpec>::validate(s)?;
-                let inner = <$inner>::from(s);
-                Ok(unsafe {
-                    // This is safe only when all of the conditions below are met:
-                    //
-                    // * `$spec::validate(s)` returns `Ok(())`.
-                    //     + This is ensured by the leading `validate()?` call.
-                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-                    <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
-                })
+            #[inline]
+            fn from(custom: &'__a $custom) -> Self {
+                <$inner>::from(<$spec as $crate::OwnedSliceSpec>::as_slice_inner(custom))
             }
         }
     };
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
-        rest=[ TryFrom<{Inner}> ];
+        rest=[ From<&{Custom}> for &{SliceCustom} ];
     ) => {
-        impl $core::convert::TryFrom<$inner> for $custom {
-            type Error = $error;
-
-            fn try_from(inner: $inner) -> $core::result::Result<Self, Self::Error> {
-                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
-                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
-                ) {
-                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
-                }
-                Ok(unsafe {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a $custom> for &'__a $slice_custom {
+            #[inline]
+            fn from(custom: &'__a $custom) -> Self {
+                unsafe {
                     // This is safe only when all of the conditions below are met:
                     //
                     // * `$spec::validate(s)` returns `Ok(())`.
-                    //     + This is ensured by the leading `validate()?` call.
+                    //     + This is ensured when `custom` is constructed.
                     // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-                    <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
-                })
+                    $crate::impl_std_traits_for_owned_slice!(@conv:as_slice, $spec, $slice_spec, custom)
+                }
             }
         }
     };
-
-    // std::default::Default
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
-        rest=[ Default ];
+        rest=[ From<&{SliceCustom}> for {Inner} ];
     ) => {
-        impl $core::default::Default for $custom
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::From<&'__a $slice_custom> for $inner
         where
-            for<'a> &'a $slice_custom: $core::default::Default,
-            $inner: $core::convert::From<$inner>,
+            $inner: From<&'__a $slice_inner>,
         {
-            fn default() -> Self {
-                let slice = <&$slice_custom>::default();
-                let slice_inner = <$slice_spec as $crate::SliceSpec>::as_inner(slice);
-                let inner = <$inner>::from(slice_inner);
-                unsafe {
-                    // This is safe only when all of the conditions below are met:
-                    //
-                    // * `$spec::validate(s)` returns `Ok(())`.
-                    //     + This is ensured by `<&$slice_custom>::default()`.
-                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-                    <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
-                }
+            #[inline]
+            fn from(s: &'__a $slice_custom) -> Self {
+                <$inner>::from(<$slice_spec as $crate::SliceSpec>::as_inner(s))
             }
         }
     };
 
-    // std::fmt::Debug
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
-        rest=[ Debug ];
+        rest=[ From<{Custom}> for PathBuf ];
     ) => {
-        impl $core::fmt::Debug for $custom
+        impl<$($lt $(: $bound)?),*> $core::convert::From<$custom> for ::std::path::PathBuf
+        where
+            ::std::path::PathBuf: $core::convert::From<$inner>,
+        {
+            #[inline]
+            fn from(custom: $custom) -> Self {
+                ::std::path::PathBuf::from(<$spec as $crate::OwnedSliceSpec>::into_inner(custom))
+            }
+        }
+    };
+
+    // std::convert::TryFrom
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ TryFrom<&{SliceInner}> ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::TryFrom<&'__a $slice_inner> for $custom
+        where
+            $inner: From<&'__a $slice_inner>,
+        {
+            type Error = $slice_error;
+
+            #[inline]
+            fn try_from(s: &'__a $slice_inner) -> $core::result::Result<Self, Self::Error> {
+                $crate::Validated::<$slice_spec>::new(s).map($crate::Validated::into_owned::<$spec>)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ TryFrom<{Inner}> ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::convert::TryFrom<$inner> for $custom {
+            type Error = $error;
+
+            fn try_from(inner: $inner) -> $core::result::Result<Self, Self::Error> {
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ TryFrom<Cow<{SliceInner}>> ];
+    ) => {
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::TryFrom<$alloc::borrow::Cow<'__a, $slice_inner>> for $custom
+        where
+            $slice_inner: $alloc::borrow::ToOwned<Owned = $inner>,
+        {
+            type Error = $error;
+
+            fn try_from(s: $alloc::borrow::Cow<'__a, $slice_inner>) -> $core::result::Result<Self, Self::Error> {
+                let inner = s.into_owned();
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ TryFrom<Vec<u8>> ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::convert::TryFrom<$alloc::vec::Vec<u8>> for $custom
+        where
+            $inner: $core::convert::From<$alloc::string::String>,
+        {
+            type Error = $crate::FromBytesError<$alloc::string::FromUtf8Error, $error>;
+
+            fn try_from(bytes: $alloc::vec::Vec<u8>) -> $core::result::Result<Self, Self::Error> {
+                let s = $alloc::string::String::from_utf8(bytes)
+                    .map_err($crate::FromBytesError::Utf8)?;
+                let inner = <$inner>::from(s);
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    return Err($crate::FromBytesError::Spec(
+                        <$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner),
+                    ));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+
+    // std::default::Default
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Default ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::default::Default for $custom
+        where
+            for<'__a> &'__a $slice_custom: $core::default::Default,
+            $inner: $core::convert::From<$inner>,
+        {
+            fn default() -> Self {
+                let slice = <&$slice_custom>::default();
+                let slice_inner = <$slice_spec as $crate::SliceSpec>::as_inner(slice);
+                let inner = <$inner>::from(slice_inner);
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by `<&$slice_custom>::default()`.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                }
+            }
+        }
+    };
+
+    // std::fmt::Debug
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Debug ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::Debug for $custom
         where
             $slice_custom: $core::fmt::Debug,
         {
@@ -582,13 +1228,114 @@ macro_rules! impl_std_traits_for_owned_slice {
         }
     };
 
+    // std::fmt::Debug with a wrapper name
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Debug(name = $name:expr) ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::Debug for $custom
+        where
+            $slice_inner: $core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                f.debug_tuple($name).field(&inner).finish()
+            }
+        }
+    };
+
+    // std::fmt::Debug with a custom formatting function
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Debug(with = $fmt:path) ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::Debug for $custom {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner: &$slice_inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                $fmt(inner, f)
+            }
+        }
+    };
+
+    // std::fmt::{LowerHex, UpperHex, Binary, Octal}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ LowerHex ];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @impl [fmt]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ LowerHex ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ UpperHex ];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @impl [fmt]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ UpperHex ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Binary ];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @impl [fmt]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ Binary ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Octal ];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @impl [fmt]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ Octal ];
+        }
+    };
+    (
+        @impl [fmt]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $trait:ident ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::$trait for $custom
+        where
+            $slice_custom: $core::fmt::$trait,
+        {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let slice = unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured when `self` is created.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::impl_std_traits_for_owned_slice!(@conv:as_slice, $spec, $slice_spec, self)
+                };
+                <$slice_custom as $core::fmt::$trait>::fmt(slice, f)
+            }
+        }
+    };
+
     // std::fmt::Display
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ Display ];
     ) => {
-        impl $core::fmt::Display for $custom
+        impl<$($lt $(: $bound)?),*> $core::fmt::Display for $custom
         where
             $slice_custom: $core::fmt::Display,
         {
@@ -607,13 +1354,110 @@ macro_rules! impl_std_traits_for_owned_slice {
         }
     };
 
+    // std::fmt::Display with a custom formatting function
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Display(with = $fmt:path) ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::fmt::Display for $custom {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner: &$slice_inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                $fmt(inner, f)
+            }
+        }
+    };
+
+    // std::hash::Hash
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Hash ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::hash::Hash for $custom
+        where
+            $slice_custom: $core::hash::Hash,
+        {
+            #[inline]
+            fn hash<H: $core::hash::Hasher>(&self, state: &mut H) {
+                let slice = unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured when `self` is created.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::impl_std_traits_for_owned_slice!(@conv:as_slice, $spec, $slice_spec, self)
+                };
+                <$slice_custom as $core::hash::Hash>::hash(slice, state)
+            }
+        }
+    };
+
+    // std::hash::Hash with a custom hash function
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Hash(with = $hash:path) ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::hash::Hash for $custom {
+            #[inline]
+            fn hash<H: $core::hash::Hasher>(&self, state: &mut H) {
+                let inner: &$slice_inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                $hash(inner, state)
+            }
+        }
+    };
+
+    // Explicit opt-out of `Hash`, checked by `Check { Borrow };`.
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ !Hash ];
+    ) => {};
+
+    // std::cmp::Ord
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Ord ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::cmp::Ord for $custom
+        where
+            $slice_custom: $core::cmp::Ord,
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> $core::cmp::Ordering {
+                let (lhs, rhs) = unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured when `self` and `other` are created.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    (
+                        $crate::impl_std_traits_for_owned_slice!(@conv:as_slice, $spec, $slice_spec, self),
+                        $crate::impl_std_traits_for_owned_slice!(@conv:as_slice, $spec, $slice_spec, other),
+                    )
+                };
+                <$slice_custom as $core::cmp::Ord>::cmp(lhs, rhs)
+            }
+        }
+    };
+
+    // Explicit opt-out of `Ord`, checked by `Check { Borrow };`.
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ !Ord ];
+    ) => {};
+
     // std::ops::Deref
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ Deref<Target = {SliceCustom}> ];
     ) => {
-        impl $core::ops::Deref for $custom {
+        impl<$($lt $(: $bound)?),*> $core::ops::Deref for $custom {
             type Target = $slice_custom;
 
             #[inline]
@@ -632,11 +1476,11 @@ macro_rules! impl_std_traits_for_owned_slice {
 
     // std::ops::DerefMut
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ DerefMut<Target = {SliceCustom}> ];
     ) => {
-        impl $core::ops::DerefMut for $custom {
+        impl<$($lt $(: $bound)?),*> $core::ops::DerefMut for $custom {
             #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
                 unsafe {
@@ -651,42 +1495,208 @@ macro_rules! impl_std_traits_for_owned_slice {
         }
     };
 
-    // std::str::FromStr
+    // std::ops::Index
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
-        rest=[ FromStr ];
+        rest=[ Index<usize> ];
     ) => {
-        impl $core::str::FromStr for $custom {
-            type Err = $slice_error;
+        impl<$($lt $(: $bound)?),*> $core::ops::Index<usize> for $custom {
+            type Output = <$slice_inner as $core::ops::Index<usize>>::Output;
+
+            #[inline]
+            fn index(&self, index: usize) -> &Self::Output {
+                &<$spec as $crate::OwnedSliceSpec>::as_slice_inner(self)[index]
+            }
+        }
+    };
 
-            fn from_str(s: &str) -> $core::result::Result<Self, Self::Err> {
-                // Currently, `$slice_inner` should be `str` for simplicity.
-                // This restriction will be loosened in future.
-                struct EnsureTraitBound
-                where
-                    $slice_spec: $crate::SliceSpec<Inner = str>, {}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Index<RangeFull, Output = {SliceCustom}> $(($check:ident))? ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::ops::Index<$core::ops::RangeFull> for $custom {
+            type Output = $slice_custom;
 
-                <$slice_spec as $crate::SliceSpec>::validate(s)?;
-                let inner = <$inner>::from(s);
-                Ok(unsafe {
+            #[inline]
+            fn index(&self, _: $core::ops::RangeFull) -> &Self::Output {
+                let s = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                unsafe {
                     // This is safe only when all of the conditions below are met:
                     //
-                    // * `$spec::validate(s)` returns `Ok(())`.
-                    //     + This is ensured by the leading `validate()?` call.
-                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-                    <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
-                })
+                    // * `$slice_spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured since `s` is the whole slice of the valid value.
+                    // * Safety condition for `<$slice_spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $slice_spec, s)
+                }
             }
         }
-        /*
-        impl<'a> $core::convert::TryFrom<&'a $slice_inner> for $custom
-        where
-            $inner: From<&'a $slice_inner>,
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Index<$range:ident<usize>, Output = {SliceCustom}> ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::ops::Index<$core::ops::$range<usize>> for $custom
+        where
+            $slice_spec: $crate::SliceClosedSpec,
+            $slice_inner: $core::ops::Index<$core::ops::$range<usize>, Output = $slice_inner>,
+        {
+            type Output = $slice_custom;
+
+            #[inline]
+            fn index(&self, index: $core::ops::$range<usize>) -> &Self::Output {
+                let sub = &<$spec as $crate::OwnedSliceSpec>::as_slice_inner(self)[index];
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$slice_spec::validate(sub)` returns `Ok(())`.
+                    //     + This is ensured by `$slice_spec: SliceClosedSpec`, because `sub` is a
+                    //       sub-slice of the valid slice.
+                    // * Safety condition for `<$slice_spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $slice_spec, sub)
+                }
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ Index<$range:ident<usize>, Output = {SliceCustom}>(revalidate) ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::ops::Index<$core::ops::$range<usize>> for $custom
+        where
+            $slice_inner: $core::ops::Index<$core::ops::$range<usize>, Output = $slice_inner>,
+        {
+            type Output = $slice_custom;
+
+            #[inline]
+            fn index(&self, index: $core::ops::$range<usize>) -> &Self::Output {
+                let sub = &<$spec as $crate::OwnedSliceSpec>::as_slice_inner(self)[index];
+                assert!(
+                    <$slice_spec as $crate::SliceSpec>::validate(sub).is_ok(),
+                    "the sub-slice is invalid as the custom slice type"
+                );
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$slice_spec::validate(sub)` returns `Ok(())`.
+                    //     + This is ensured by the leading assertion.
+                    // * Safety condition for `<$slice_spec as $crate::SliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(slice; $slice_spec, sub)
+                }
+            }
+        }
+    };
+
+    // validated_slice::ErasedValidatedSlice
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ ErasedValidatedSlice ];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $crate::ErasedValidatedSlice for $custom
+        where
+            $slice_inner: $core::convert::AsRef<[u8]>,
+        {
+            #[inline]
+            fn as_bytes(&self) -> &[u8] {
+                <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self).as_ref()
+            }
+
+            #[inline]
+            fn type_name(&self) -> &'static str {
+                $core::any::type_name::<$custom>()
+            }
+
+            #[inline]
+            fn revalidate(&self) -> bool {
+                <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self),
+                )
+                .is_ok()
+            }
+        }
+    };
+
+    // std::str::FromStr
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ FromStr ];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @impl [from_str]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            err=[$slice_error];
+            map_err=[|e, s| e];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ FromStr(error = {Error}) ];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @impl [from_str]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            err=[$error];
+            map_err=[|e, s| <$spec as $crate::OwnedSliceSpec>::convert_validation_error(
+                e,
+                <$inner>::from(s),
+            )];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ FromStr(error = $err:ty, map_err = $map_err:expr) ];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @impl [from_str]; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            err=[$err];
+            map_err=[|e, s| ($map_err)(e)];
+        }
+    };
+    (
+        @impl [from_str]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        err=[$err:ty];
+        map_err=[|$e:ident, $s:ident| $map_err:expr];
+    ) => {
+        impl<$($lt $(: $bound)?),*> $core::str::FromStr for $custom
+        where
+            str: $core::convert::AsRef<$slice_inner>,
+            $inner: for<'__a> $core::convert::From<&'__a str>,
+        {
+            type Err = $err;
+
+            fn from_str($s: &str) -> $core::result::Result<Self, Self::Err> {
+                <$slice_spec as $crate::SliceSpec>::validate(
+                    $core::convert::AsRef::<$slice_inner>::as_ref($s),
+                )
+                .map_err(|$e| $map_err)?;
+                let inner = <$inner>::from($s);
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+        /*
+        impl<'__a $(, $lt $(: $bound)?)*> $core::convert::TryFrom<&'__a $slice_inner> for $custom
+        where
+            $inner: From<&'__a $slice_inner>,
         {
             type Error = $slice_error;
 
-            fn try_from(s: &'a $slice_inner) -> $core::result::Result<Self, Self::Error> {
+            fn try_from(s: &'__a $slice_inner) -> $core::result::Result<Self, Self::Error> {
                 <$slice_spec as $crate::SliceSpec>::validate(s)?;
                 let inner = <$inner>::from(s);
                 Ok(unsafe {
@@ -695,35 +1705,185 @@ macro_rules! impl_std_traits_for_owned_slice {
                     // * `$spec::validate(s)` returns `Ok(())`.
                     //     + This is ensured by the leading `validate()?` call.
                     // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-                    <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
                 })
             }
         }
         */
     };
 
+    // diesel::{AsExpression, FromSql, Queryable, ToSql}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ diesel::$target:ident $(<$st:ty>)? ];
+    ) => {
+        $crate::__impl_diesel! {
+            @$target $(<$st>)?; ({$core, $alloc}, $spec, $custom, $inner, $error, $slice_spec,
+                $slice_inner);
+        }
+    };
+
+    // rand::distr::Distribution
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ rand::$target:ident ];
+    ) => {
+        $crate::__impl_rand! {
+            @$target; ({$core, $alloc}, $spec, $custom, $inner, $slice_spec);
+        }
+    };
+
+    // rkyv::{Archive, Deserialize, Serialize}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ rkyv::$target:ident ];
+    ) => {
+        $crate::__impl_rkyv! {
+            @$target; ({$core, $alloc}, $spec, $custom, $inner, $slice_spec, $slice_inner);
+        }
+    };
+
+    // defmt::Format
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ defmt::Format ];
+    ) => {
+        $crate::__impl_defmt! {
+            ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $slice_inner);
+            |this| <$spec as $crate::OwnedSliceSpec>::as_slice_inner(this)
+        }
+    };
+
+    // ufmt::{uDebug, uDisplay}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ ufmt::$trait:ident ];
+    ) => {
+        $crate::__impl_ufmt! {
+            ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $slice_inner);
+            $trait;
+            |this| <$spec as $crate::OwnedSliceSpec>::as_slice_inner(this)
+        }
+    };
+
+    // schemars::JsonSchema
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ JsonSchema ];
+    ) => {
+        $crate::__impl_json_schema! {
+            ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $inner);
+            spec=[]
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ JsonSchema(spec) ];
+    ) => {
+        $crate::__impl_json_schema! {
+            ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $inner);
+            spec=[$slice_spec]
+        }
+    };
+
+    // equivalent::{Comparable, Equivalent}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ equivalent::$target:ident<{Custom}> for {SliceCustom} ];
+    ) => {
+        $crate::__impl_equivalent! {
+            @$target; ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $slice_custom, $slice_inner);
+            |this| <$slice_spec as $crate::SliceSpec>::as_inner(this);
+            |key| <$spec as $crate::OwnedSliceSpec>::as_slice_inner(key)
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ equivalent::$target:ident<{Custom}> for {SliceInner} ];
+    ) => {
+        $crate::__impl_equivalent! {
+            @$target; ({$core, $alloc $(, $lt $(: $bound)?)*}, $custom, $slice_inner, $slice_inner);
+            |this| this;
+            |key| <$spec as $crate::OwnedSliceSpec>::as_slice_inner(key)
+        }
+    };
+
+    // sqlx::{Decode, Encode, Type}
+    (
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ sqlx::$target:ident ];
+    ) => {
+        $crate::__impl_sqlx! {
+            @$target; ({$core, $alloc}, $spec, $custom, $inner, $error, $slice_spec, $slice_inner);
+        }
+    };
+
     // Helpers.
 
     // Converts `&$custom` into `&$slice_custom`.
     (@conv:as_slice, $spec:ty, $slice_spec:ty, $owned_ref:expr) => {
-        <$slice_spec as $crate::SliceSpec>::from_inner_unchecked(
+        $crate::__from_inner_unchecked!(
+            slice;
+            $slice_spec,
             <$spec as $crate::OwnedSliceSpec>::as_slice_inner($owned_ref)
         )
     };
     // Converts `&mut $custom` into `&mut $slice_custom`.
     (@conv:as_mut_slice, $spec:ty, $slice_spec:ty, $owned_ref:expr) => {
-        <$slice_spec as $crate::SliceSpec>::from_inner_unchecked_mut(
-            <$spec as $crate::OwnedSliceSpec>::as_slice_inner_mut($owned_ref)
+        $crate::__from_inner_unchecked!(
+            slice_mut;
+            $slice_spec,
+            <$spec as $crate::OwnedSliceMutSpec>::as_slice_inner_mut($owned_ref)
         )
     };
 
-    // Fallback.
+    // Explicit `for {Custom}`, which is same as the default impl target.
     (
-        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+        @impl; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
             $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
         rest=[ $($rest:tt)* ];
     ) => {
-        compile_error!(concat!("Unsupported target: ", stringify!($($rest)*)));
+        $crate::impl_std_traits_for_owned_slice! {
+            @strip_for_custom; ({$core, $alloc $(, $lt $(: $bound)?)*}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            target=[]; rest=[$($rest)*];
+        }
+    };
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[for {Custom}];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @impl; $params;
+            rest=[$($target)*];
+        }
+    };
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[$first:tt $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_owned_slice! {
+            @strip_for_custom; $params;
+            target=[$($target)* $first]; rest=[$($rest)*];
+        }
+    };
+
+    // Fallback.
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[];
+    ) => {
+        compile_error!(concat!("Unsupported target: ", stringify!($($target)*)));
     };
 }
 
@@ -766,6 +1926,8 @@ macro_rules! impl_std_traits_for_owned_slice {
 /// ## Core and alloc
 ///
 /// For `no_std` use, the macro uses custom `core` and `alloc` crate if given.
+/// If `Std { .. };` is omitted, the macro uses `core` and `alloc` crates re-exported from this
+/// crate (`alloc` requires `alloc` feature), regardless of the environment of the caller.
 /// You can support both nostd and non-nostd environment as below:
 ///
 /// ```ignore
@@ -794,9 +1956,21 @@ macro_rules! impl_std_traits_for_owned_slice {
 ///
 /// The syntax of `Spec` part is very similar to [`impl_std_traits_for_owned_slice!`] macro.
 ///
-/// As `base` field, specify `Custom` or `Inner` to decide which comparison should be used
-/// internally.
+/// As `base` field, specify `Custom`, `Inner`, `Folded`, or `ConstTime` to decide which comparison
+/// should be used internally.
 /// If you don't define custom comparison, use `base: Inner`.
+/// With `base: Folded`, the slice inner values are compared by [`FoldedCmpSpec`] of the slice spec
+/// type.
+/// With `base: ConstTime`, the slice inner values are compared by [`ConstTimeEqSpec`] of the slice
+/// spec type. This supports only `Cmp { PartialEq }`.
+///
+/// Fields except for `spec` and `base` can be omitted as
+/// `Spec { spec: MyStringSpec, base: Inner };`.
+/// In this case, they are resolved through the associated types of [`OwnedSliceSpec`] trait.
+///
+/// Lifetime parameters and type tag parameters of the custom types can be declared by
+/// `lifetimes: ['a],` and `tags: [T],` fields at the head of `Spec { .. }`, as
+/// [`impl_std_traits_for_owned_slice!`] macro.
 ///
 /// ## Traits to implement
 ///
@@ -809,24 +1983,37 @@ macro_rules! impl_std_traits_for_owned_slice {
 /// Comparisons are implemented between two types, so you should provide list of pairs to implement
 /// comparison.
 ///
-/// Supported syntaxes are: `{ (lhs_ty), (rhs_ty) };` and `{ (lhs_ty), (rhs_ty), rev };`.
+/// Supported syntaxes are: `{ (lhs_ty), (rhs_ty) };`, `{ (lhs_ty), (rhs_ty), rev };`, and
+/// `{ (lhs_ty), (rhs_ty), rev_only };`.
 ///
 /// Parentheses around types are not omittable.
 ///
 /// With `, rev`, the macro implements not only `PartialXx<rhs_ty> for lhs_ty`, but also
 /// `PartialXx<lhs_ty> for rhs_ty`.
 ///
+/// With `, rev_only`, the macro implements only `PartialXx<lhs_ty> for rhs_ty`.
+/// This is useful when `PartialXx<rhs_ty> for lhs_ty` is already implemented elsewhere.
+///
+/// Lifetimes used only by the operand types can be declared per pair by the `for<'a>` prefix, such
+/// as `{ for<'a> ({Custom}), (Cow<'a, {Custom}>), rev };`.
+/// Declared lifetimes become parameters of the generated impls.
+///
 /// ## Type names
 ///
 /// `{Custom}`, `{Inner}`, `{SliceCustom}`, and `{SliceInner}` will be replaced to the custom slice
 /// type, its inner type, custom borrowed slice type, and its inner type.
 ///
-/// `&ty` and `Cow<ty>` are also supported.
+/// `&ty`, `Cow<ty>`, and `Cow<'a, ty>` are also supported.
 ///
 /// Note that in case you specify arbitrary types (other than `{Custom}`, `{Inner}`,
 /// `{SliceCustom}`, `{SliceInner}`, and its variations), that type should implement
 /// `AsRef<base_type>`.
 ///
+/// If the arbitrary type does not implement `AsRef<base_type>`, specify the projection function by
+/// `(ty via path::to::projection)` format, such as `(url::Url via url::Url::as_str)`.
+/// The projection function should have the type `fn(&ty) -> &base_type`, where `base_type` is
+/// `{SliceInner}` or `{SliceCustom}` depending on the `base` field.
+///
 /// ## Supported types
 ///
 /// * `{Custom}`
@@ -844,11 +2031,16 @@ macro_rules! impl_std_traits_for_owned_slice {
 /// Note that, with `base: Custom`, `{Inner}`, `{SliceInner}` and its variants are not supported
 /// (because it does not make sense).
 ///
+/// [`ConstTimeEqSpec`]: trait.ConstTimeEqSpec.html
+/// [`FoldedCmpSpec`]: trait.FoldedCmpSpec.html
+/// [`OwnedSliceSpec`]: trait.OwnedSliceSpec.html
 /// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
 #[macro_export]
 macro_rules! impl_cmp_for_owned_slice {
     (
         Spec {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
             spec: $spec:ty,
             custom: $custom:ty,
             inner: $inner:ty,
@@ -859,20 +2051,11 @@ macro_rules! impl_cmp_for_owned_slice {
         Cmp { $($cmp_targets:ident),* };
         $($rest:tt)*
     ) => {
-        $crate::impl_cmp_for_owned_slice! {
-            @full;
-            Std {
-                core: std,
-                alloc: std,
-            };
-            Spec {
-                spec: $spec,
-                custom: $custom,
-                inner: $inner,
-                slice_custom: $slice_custom,
-                slice_inner: $slice_inner,
-                base: $base,
-            };
+        $crate::__with_default_std! {
+            $crate::impl_cmp_for_owned_slice;
+            head=[@full;];
+            lifetimes=[$($(, $lt)*)? $($(, $tag $(: $tag_bound)?)*)?];
+            args=[$spec, $custom, $inner, $slice_custom, $slice_inner, $base];
             Cmp { $($cmp_targets),* };
             $($rest)*
         }
@@ -883,6 +2066,8 @@ macro_rules! impl_cmp_for_owned_slice {
             alloc: $alloc:ident,
         };
         Spec {
+            $(lifetimes: [$($lt:lifetime),* $(,)?],)?
+            $(tags: [$($tag:ident $(: $tag_bound:path)?),* $(,)?],)?
             spec: $spec:ty,
             custom: $custom:ty,
             inner: $inner:ty,
@@ -894,106 +2079,106 @@ macro_rules! impl_cmp_for_owned_slice {
         $($rest:tt)*
     ) => {
         $crate::impl_cmp_for_owned_slice! {
-            @full;
-            Std {
-                core: $core,
-                alloc: $alloc,
-            };
+            @full; ({$core, $alloc $($(, $lt)*)? $($(, $tag $(: $tag_bound)?)*)?}, $spec, $custom, $inner, $slice_custom, $slice_inner, $base);
+            Cmp { $($cmp_targets),* };
+            $($rest)*
+        }
+    };
+
+    // Spec fields inferred from the spec type.
+    (
+        Spec {
+            spec: $spec:ty,
+            base: $base:ident $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_cmp_for_owned_slice! {
             Spec {
                 spec: $spec,
-                custom: $custom,
-                inner: $inner,
-                slice_custom: $slice_custom,
-                slice_inner: $slice_inner,
+                custom: <$spec as $crate::OwnedSliceSpec>::Custom,
+                inner: <$spec as $crate::OwnedSliceSpec>::Inner,
+                slice_custom: <$spec as $crate::OwnedSliceSpec>::SliceCustom,
+                slice_inner: <$spec as $crate::OwnedSliceSpec>::SliceInner,
                 base: $base,
             };
-            Cmp { $($cmp_targets),* };
             $($rest)*
         }
     };
-
     (
-        @full;
         Std {
             core: $core:ident,
             alloc: $alloc:ident,
         };
         Spec {
             spec: $spec:ty,
-            custom: $custom:ty,
-            inner: $inner:ty,
-            slice_custom: $slice_custom:ty,
-            slice_inner: $slice_inner:ty,
-            base: $base:ident,
+            base: $base:ident $(,)?
         };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_cmp_for_owned_slice! {
+            Std {
+                core: $core,
+                alloc: $alloc,
+            };
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::OwnedSliceSpec>::Custom,
+                inner: <$spec as $crate::OwnedSliceSpec>::Inner,
+                slice_custom: <$spec as $crate::OwnedSliceSpec>::SliceCustom,
+                slice_inner: <$spec as $crate::OwnedSliceSpec>::SliceInner,
+                base: $base,
+            };
+            $($rest)*
+        }
+    };
+
+    (
+        @full; $params:tt;
         Cmp { PartialEq, PartialOrd };
-        $({ ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
+        $({ $(for<$($plt:lifetime),+>)? ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
     ) => {
         $(
             $crate::impl_cmp_for_owned_slice! {
-                @impl[PartialEq]; ({$core, $alloc}, $spec, $custom, $inner, $slice_custom, $slice_inner, $base);
+                @pair[PartialEq]; $params; [$($($plt),+)?];
                 { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
             }
             $crate::impl_cmp_for_owned_slice! {
-                @impl[PartialOrd]; ({$core, $alloc}, $spec, $custom, $inner, $slice_custom, $slice_inner, $base);
+                @pair[PartialOrd]; $params; [$($($plt),+)?];
                 { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
             }
         )*
     };
     (
-        @full;
-        Std {
-            core: $core:ident,
-            alloc: $alloc:ident,
-        };
-        Spec {
-            spec: $spec:ty,
-            custom: $custom:ty,
-            inner: $inner:ty,
-            slice_custom: $slice_custom:ty,
-            slice_inner: $slice_inner:ty,
-            base: $base:ident,
-        };
+        @full; $params:tt;
         Cmp { PartialEq };
-        $({ ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
+        $({ $(for<$($plt:lifetime),+>)? ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
     ) => {
         $(
             $crate::impl_cmp_for_owned_slice! {
-                @impl[PartialEq]; ({$core, $alloc}, $spec, $custom, $inner, $slice_custom, $slice_inner, $base);
+                @pair[PartialEq]; $params; [$($($plt),+)?];
                 { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
             }
         )*
     };
     (
-        @full;
-        Std {
-            core: $core:ident,
-            alloc: $alloc:ident,
-        };
-        Spec {
-            spec: $spec:ty,
-            custom: $custom:ty,
-            inner: $inner:ty,
-            slice_custom: $slice_custom:ty,
-            slice_inner: $slice_inner:ty,
-            base: $base:ident,
-        };
+        @full; $params:tt;
         Cmp { PartialOrd };
-        $({ ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
+        $({ $(for<$($plt:lifetime),+>)? ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
     ) => {
         $(
             $crate::impl_cmp_for_owned_slice! {
-                @impl[PartialOrd]; ({$core, $alloc}, $spec, $custom, $inner, $slice_custom, $slice_inner, $base);
+                @pair[PartialOrd]; $params; [$($($plt),+)?];
                 { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
             }
         )*
     };
 
     (
-        @impl[PartialEq]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        @impl[PartialEq]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
         { ($($lhs:tt)*), ($($rhs:tt)*) };
     ) => {
-        impl $core::cmp::PartialEq<
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialEq<
             $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* })
         > for $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($lhs)* })
         {
@@ -1001,7 +2186,7 @@ macro_rules! impl_cmp_for_owned_slice {
             fn eq(&self, other: &$crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* }))
                 -> bool
             {
-                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialEq]; ($slice_custom, $slice_inner, $base))(
+                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialEq]; (<$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner, $base))(
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($lhs)* }; self),
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($rhs)* }; other),
                 )
@@ -1009,10 +2194,10 @@ macro_rules! impl_cmp_for_owned_slice {
         }
     };
     (
-        @impl[PartialEq]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        @impl[PartialEq]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
         { ($($lhs:tt)*), ($($rhs:tt)*), rev };
     ) => {
-        impl $core::cmp::PartialEq<
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialEq<
             $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* })
         > for $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($lhs)* })
         {
@@ -1020,13 +2205,13 @@ macro_rules! impl_cmp_for_owned_slice {
             fn eq(&self, other: &$crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* }))
                 -> bool
             {
-                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialEq]; ($slice_custom, $slice_inner, $base))(
+                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialEq]; (<$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner, $base))(
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($lhs)* }; self),
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($rhs)* }; other),
                 )
             }
         }
-        impl $core::cmp::PartialEq<
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialEq<
             $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($lhs)* })
         > for $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* })
         {
@@ -1034,7 +2219,7 @@ macro_rules! impl_cmp_for_owned_slice {
             fn eq(&self, other: &$crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($lhs)* }))
                 -> bool
             {
-                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialEq]; ($slice_custom, $slice_inner, $base))(
+                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialEq]; (<$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner, $base))(
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($rhs)* }; self),
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($lhs)* }; other),
                 )
@@ -1042,10 +2227,10 @@ macro_rules! impl_cmp_for_owned_slice {
         }
     };
     (
-        @impl[PartialOrd]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        @impl[PartialOrd]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
         { ($($lhs:tt)*), ($($rhs:tt)*) };
     ) => {
-        impl $core::cmp::PartialOrd<
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialOrd<
             $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* })
         > for $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($lhs)* })
         {
@@ -1053,7 +2238,7 @@ macro_rules! impl_cmp_for_owned_slice {
             fn partial_cmp(&self, other: &$crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* }))
                 -> $core::option::Option<$core::cmp::Ordering>
             {
-                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialOrd]; ($slice_custom, $slice_inner, $base))(
+                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialOrd]; (<$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner, $base))(
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($lhs)* }; self),
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($rhs)* }; other),
                 )
@@ -1061,10 +2246,10 @@ macro_rules! impl_cmp_for_owned_slice {
         }
     };
     (
-        @impl[PartialOrd]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
+        @impl[PartialOrd]; ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $spec:ty, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty, $base:ident);
         { ($($lhs:tt)*), ($($rhs:tt)*), rev };
     ) => {
-        impl $core::cmp::PartialOrd<
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialOrd<
             $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* })
         > for $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($lhs)* })
         {
@@ -1072,13 +2257,13 @@ macro_rules! impl_cmp_for_owned_slice {
             fn partial_cmp(&self, other: &$crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* }))
                 -> $core::option::Option<$core::cmp::Ordering>
             {
-                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialOrd]; ($slice_custom, $slice_inner, $base))(
+                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialOrd]; (<$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner, $base))(
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($lhs)* }; self),
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($rhs)* }; other),
                 )
             }
         }
-        impl $core::cmp::PartialOrd<
+        impl<$($lt $(: $bound)?),*> $core::cmp::PartialOrd<
             $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($lhs)* })
         > for $crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($rhs)* })
         {
@@ -1086,7 +2271,7 @@ macro_rules! impl_cmp_for_owned_slice {
             fn partial_cmp(&self, other: &$crate::impl_cmp_for_owned_slice!(@type; ({$core, $alloc}, $custom, $inner, $slice_custom, $slice_inner); { $($lhs)* }))
                 -> $core::option::Option<$core::cmp::Ordering>
             {
-                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialOrd]; ($slice_custom, $slice_inner, $base))(
+                $crate::impl_cmp_for_owned_slice!(@cmp_fn[PartialOrd]; (<$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner, $base))(
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($rhs)* }; self),
                     $crate::impl_cmp_for_owned_slice!(@expr[$base]; ({$core, $alloc}, $spec, $slice_custom, $slice_inner); { $($lhs)* }; other),
                 )
@@ -1094,6 +2279,25 @@ macro_rules! impl_cmp_for_owned_slice {
         }
     };
 
+    (
+        @pair[$cmp:ident]; ({$core:ident, $alloc:ident $(, $lt:lifetime)* $(, $tag:ident $(: $tag_bound:path)?)*}, $($params:tt)*);
+        [$($plt:lifetime),*]; $pair:tt;
+    ) => {
+        $crate::impl_cmp_for_owned_slice! {
+            @impl[$cmp]; ({$core, $alloc $(, $lt)* $(, $plt)* $(, $tag $(: $tag_bound)?)*}, $($params)*);
+            $pair;
+        }
+    };
+    (
+        @impl[$cmp:ident]; $params:tt;
+        { ($($lhs:tt)*), ($($rhs:tt)*), rev_only };
+    ) => {
+        $crate::impl_cmp_for_owned_slice! {
+            @impl[$cmp]; $params;
+            { ($($rhs)*), ($($lhs)*) };
+        }
+    };
+
     (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty); { {Custom} }) => {
         $custom
     };
@@ -1109,6 +2313,9 @@ macro_rules! impl_cmp_for_owned_slice {
     (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty); { Cow<{SliceCustom}> }) => {
         $alloc::borrow::Cow<'_, $slice_custom>
     };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty); { Cow<$l:lifetime, {SliceCustom}> }) => {
+        $alloc::borrow::Cow<$l, $slice_custom>
+    };
     (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty); { {Inner} }) => {
         $inner
     };
@@ -1124,21 +2331,45 @@ macro_rules! impl_cmp_for_owned_slice {
     (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty); { Cow<{SliceInner}> }) => {
         $alloc::borrow::Cow<'_, $slice_inner>
     };
+    (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty); { Cow<$l:lifetime, {SliceInner}> }) => {
+        $alloc::borrow::Cow<$l, $slice_inner>
+    };
     (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty); { Cow<$ty:ty> }) => { &**$ty };
     (@type; ({$core:ident, $alloc:ident}, $custom:ty, $inner:ty, $slice_custom:ty, $slice_inner:ty); { $ty:ty }) => { $ty };
 
-    (@cmp_fn[PartialEq]; ($slice_custom:ty, $slice_inner:ty, Inner)) => {
+    (@cmp_fn[PartialEq]; ($slice_spec:ty, $slice_custom:ty, $slice_inner:ty, Inner)) => {
         <$slice_inner as core::cmp::PartialEq<$slice_inner>>::eq
     };
-    (@cmp_fn[PartialEq]; ($slice_custom:ty, $slice_inner:ty, Custom)) => {
+    (@cmp_fn[PartialEq]; ($slice_spec:ty, $slice_custom:ty, $slice_inner:ty, Custom)) => {
         <$slice_custom as core::cmp::PartialEq<$slice_custom>>::eq
     };
-    (@cmp_fn[PartialOrd]; ($slice_custom:ty, $slice_inner:ty, Inner)) => {
+    (@cmp_fn[PartialOrd]; ($slice_spec:ty, $slice_custom:ty, $slice_inner:ty, Inner)) => {
         <$slice_inner as core::cmp::PartialOrd<$slice_inner>>::partial_cmp
     };
-    (@cmp_fn[PartialOrd]; ($slice_custom:ty, $slice_inner:ty, Custom)) => {
+    (@cmp_fn[PartialOrd]; ($slice_spec:ty, $slice_custom:ty, $slice_inner:ty, Custom)) => {
         <$slice_custom as core::cmp::PartialOrd<$slice_custom>>::partial_cmp
     };
+    (@cmp_fn[PartialEq]; ($slice_spec:ty, $slice_custom:ty, $slice_inner:ty, Folded)) => {
+        <$slice_spec as $crate::FoldedCmpSpec>::eq_folded
+    };
+    (@cmp_fn[PartialOrd]; ($slice_spec:ty, $slice_custom:ty, $slice_inner:ty, Folded)) => {
+        $crate::__private::partial_cmp_folded::<$slice_spec>
+    };
+    (@cmp_fn[PartialEq]; ($slice_spec:ty, $slice_custom:ty, $slice_inner:ty, ConstTime)) => {
+        <$slice_spec as $crate::ConstTimeEqSpec>::eq_const_time
+    };
+    (@cmp_fn[PartialOrd]; ($slice_spec:ty, $slice_custom:ty, $slice_inner:ty, ConstTime)) => {
+        compile_error!("`base: ConstTime` supports only `PartialEq`")
+    };
+
+    // `base: Folded` compares the inner slices, as `base: Inner`.
+    (@expr[Folded]; $params:tt; $ty:tt; $expr:expr) => {
+        $crate::impl_cmp_for_owned_slice!(@expr[Inner]; $params; $ty; $expr)
+    };
+    // `base: ConstTime` compares the inner slices, as `base: Inner`.
+    (@expr[ConstTime]; $params:tt; $ty:tt; $expr:expr) => {
+        $crate::impl_cmp_for_owned_slice!(@expr[Inner]; $params; $ty; $expr)
+    };
 
     (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { {Custom} }; $expr:expr) => {
         <$spec as $crate::OwnedSliceSpec>::as_slice_inner($expr)
@@ -1158,6 +2389,9 @@ macro_rules! impl_cmp_for_owned_slice {
     (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { Cow<{SliceCustom}> }; $expr:expr) => {
         <<$spec as $crate::OwnedSliceSpec>::SliceSpec as $crate::SliceSpec>::as_inner(&**$expr)
     };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { Cow<$l:lifetime, {SliceCustom}> }; $expr:expr) => {
+        <<$spec as $crate::OwnedSliceSpec>::SliceSpec as $crate::SliceSpec>::as_inner(&**$expr)
+    };
     (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { {Inner} }; $expr:expr) => {
         <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner($expr)
     };
@@ -1176,6 +2410,9 @@ macro_rules! impl_cmp_for_owned_slice {
     (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { Cow<{SliceInner}> }; $expr:expr) => {
         &**$expr
     };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { Cow<$l:lifetime, {SliceInner}> }; $expr:expr) => {
+        &**$expr
+    };
     (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { $ty:ty }; $expr:expr) => {
         $core::convert::AsRef::<$inner>::as_ref($expr)
     };
@@ -1187,7 +2424,9 @@ macro_rules! impl_cmp_for_owned_slice {
             // * `$spec::validate(s)` returns `Ok(())`.
             //     + This is ensured when `$expr` is constructed.
             // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-            <<$spec as $crate::OwnedSliceSpec>::SliceSpec as $crate::SliceSpec>::from_inner_unchecked(
+            $crate::__from_inner_unchecked!(
+                slice;
+                <$spec as $crate::OwnedSliceSpec>::SliceSpec,
                 <$spec as $crate::OwnedSliceSpec>::as_slice_inner($expr)
             )
         }
@@ -1199,7 +2438,9 @@ macro_rules! impl_cmp_for_owned_slice {
             // * `$spec::validate(s)` returns `Ok(())`.
             //     + This is ensured when `$expr` is constructed.
             // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-            <<$spec as $crate::OwnedSliceSpec>::SliceSpec as $crate::SliceSpec>::from_inner_unchecked(
+            $crate::__from_inner_unchecked!(
+                slice;
+                <$spec as $crate::OwnedSliceSpec>::SliceSpec,
                 <$spec as $crate::OwnedSliceSpec>::as_slice_inner(*$expr)
             )
         }
@@ -1211,7 +2452,9 @@ macro_rules! impl_cmp_for_owned_slice {
             // * `$spec::validate(s)` returns `Ok(())`.
             //     + This is ensured when `$expr` is constructed.
             // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
-            <<$spec as $crate::OwnedSliceSpec>::SliceSpec as $crate::SliceSpec>::from_inner_unchecked(
+            $crate::__from_inner_unchecked!(
+                slice;
+                <$spec as $crate::OwnedSliceSpec>::SliceSpec,
                 <$spec as $crate::OwnedSliceSpec>::as_slice_inner(&**$expr)
             )
         }
@@ -1225,11 +2468,1562 @@ macro_rules! impl_cmp_for_owned_slice {
     (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { Cow<{SliceCustom}> }; $expr:expr) => {
         &**$expr
     };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { Cow<$l:lifetime, {SliceCustom}> }; $expr:expr) => {
+        &**$expr
+    };
     (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { $ty:ty }; $expr:expr) => {
         $core::convert::AsRef::<$custom>::as_ref($expr)
     };
 
+    // Operand types with projections: `(ty via path::to::projection)`.
+    (@type; $params:tt; { $($ty:tt)+ }) => {
+        $crate::impl_cmp_for_owned_slice!(@via[type]; []; $($ty)+)
+    };
+    (@expr[$base:ident]; $params:tt; { $($ty:tt)+ }; $expr:expr) => {
+        $crate::impl_cmp_for_owned_slice!(@via[expr]; $expr; []; $($ty)+)
+    };
+    (@via[type]; [$($ty:tt)+]; via $proj:path) => { $($ty)+ };
+    (@via[type]; [$($ty:tt)*]; $first:tt $($rest:tt)*) => {
+        $crate::impl_cmp_for_owned_slice!(@via[type]; [$($ty)* $first]; $($rest)*)
+    };
+    (@via[expr]; $expr:expr; [$($ty:tt)+]; via $proj:path) => { $proj($expr) };
+    (@via[expr]; $expr:expr; [$($ty:tt)*]; $first:tt $($rest:tt)*) => {
+        $crate::impl_cmp_for_owned_slice!(@via[expr]; $expr; [$($ty)* $first]; $($rest)*)
+    };
+
+    ($($rest:tt)*) => {
+        compile_error!(stringify!($($rest)*));
+    };
+}
+
+/// Implements std traits and comparisons for the given owned custom slice type in one invocation.
+///
+/// This is a shorthand for [`impl_std_traits_for_owned_slice!`] and [`impl_cmp_for_owned_slice!`]
+/// macros sharing the same `Std` and `Spec` parts.
+///
+/// # Usage
+///
+/// ```ignore
+/// validated_slice::impl_traits_for_owned_slice! {
+///     // `Std` is omissible.
+///     Std {
+///         core: core,
+///         alloc: alloc,
+///     };
+///     Spec {
+///         spec: MyStringSpec,
+///         custom: MyString,
+///         inner: Vec<u8>,
+///         error: MyFromUtf8Error,
+///         slice_custom: MyStr,
+///         slice_inner: [u8],
+///         slice_error: MyUtf8Error,
+///     };
+///     // Same as the targets of `impl_std_traits_for_owned_slice!`.
+///     // `Traits` is omissible.
+///     Traits {
+///         { Borrow<{SliceCustom}> };
+///         { TryFrom<{Inner}> };
+///     };
+///     // Same as `base` field, `Cmp` part, and operand type pairs of
+///     // `impl_cmp_for_owned_slice!`.
+///     // `Cmp` is omissible.
+///     Cmp(base = Inner) { PartialEq, PartialOrd } {
+///         { ({Custom}), ({SliceCustom}), rev };
+///     };
+/// }
+/// ```
+///
+/// Spec fields other than `spec` can be omitted in the same way as the underlying macros.
+///
+/// [`impl_cmp_for_owned_slice!`]: macro.impl_cmp_for_owned_slice.html
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+#[macro_export]
+macro_rules! impl_traits_for_owned_slice {
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty,
+            slice_custom: $slice_custom:ty,
+            slice_inner: $slice_inner:ty,
+            slice_error: $slice_error:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_traits_for_owned_slice! {
+            @full; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_custom, $slice_inner, $slice_error);
+            $($rest)*
+        }
+    };
+    (
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty,
+            slice_custom: $slice_custom:ty,
+            slice_inner: $slice_inner:ty,
+            slice_error: $slice_error:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::__with_default_std! {
+            $crate::impl_traits_for_owned_slice;
+            head=[@full;];
+            lifetimes=[];
+            args=[$spec, $custom, $inner, $error, $slice_custom, $slice_inner, $slice_error];
+            $($rest)*
+        }
+    };
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_traits_for_owned_slice! {
+            @full; ({$core, $alloc}, $spec, <$spec as $crate::OwnedSliceSpec>::Custom,
+                <$spec as $crate::OwnedSliceSpec>::Inner, <$spec as $crate::OwnedSliceSpec>::Error,
+                <$spec as $crate::OwnedSliceSpec>::SliceCustom,
+                <$spec as $crate::OwnedSliceSpec>::SliceInner,
+                <$spec as $crate::OwnedSliceSpec>::SliceError);
+            $($rest)*
+        }
+    };
+    (
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::__with_default_std! {
+            $crate::impl_traits_for_owned_slice;
+            head=[@full;];
+            lifetimes=[];
+            args=[$spec, <$spec as $crate::OwnedSliceSpec>::Custom,
+                <$spec as $crate::OwnedSliceSpec>::Inner, <$spec as $crate::OwnedSliceSpec>::Error,
+                <$spec as $crate::OwnedSliceSpec>::SliceCustom,
+                <$spec as $crate::OwnedSliceSpec>::SliceInner,
+                <$spec as $crate::OwnedSliceSpec>::SliceError];
+            $($rest)*
+        }
+    };
+
+    (
+        @full; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        $(Traits { $($traits:tt)* };)?
+        $(Cmp(base = $base:ident) { $($cmp:ident),* } { $($pairs:tt)* };)?
+    ) => {
+        $(
+            $crate::impl_std_traits_for_owned_slice! {
+                Std {
+                    core: $core,
+                    alloc: $alloc,
+                };
+                Spec {
+                    spec: $spec,
+                    custom: $custom,
+                    inner: $inner,
+                    error: $error,
+                    slice_custom: $slice_custom,
+                    slice_inner: $slice_inner,
+                    slice_error: $slice_error,
+                };
+                $($traits)*
+            }
+        )?
+        $(
+            $crate::impl_cmp_for_owned_slice! {
+                Std {
+                    core: $core,
+                    alloc: $alloc,
+                };
+                Spec {
+                    spec: $spec,
+                    custom: $custom,
+                    inner: $inner,
+                    slice_custom: $slice_custom,
+                    slice_inner: $slice_inner,
+                    base: $base,
+                };
+                Cmp { $($cmp),* };
+                $($pairs)*
+            }
+        )?
+    };
+
+    ($($rest:tt)*) => {
+        compile_error!(stringify!($($rest)*));
+    };
+}
+
+/// Implements std traits and comparisons for a pair of borrowed and owned custom slice types.
+///
+/// This takes the bodies of [`impl_traits_for_slice!`] and [`impl_traits_for_owned_slice!`]
+/// macros (without `Std` part) for the borrowed and owned types, and additionally implements
+/// impls between them.
+///
+/// # Usage
+///
+/// ```ignore
+/// validated_slice::impl_traits_for_slice_pair! {
+///     // `Std` is omissible.
+///     Std {
+///         core: core,
+///         alloc: alloc,
+///     };
+///     // Same as the body of `impl_traits_for_slice!`, without `Std` part.
+///     Slice {
+///         Spec {
+///             spec: MyStrSpec,
+///         };
+///         Traits {
+///             { Debug };
+///         };
+///         Cmp(base = Inner) { PartialEq, PartialOrd } {
+///             { ({Custom}), (&{Inner}), rev };
+///         };
+///     };
+///     // Same as the body of `impl_traits_for_owned_slice!`, without `Std` part.
+///     Owned {
+///         Spec {
+///             spec: MyStringSpec,
+///         };
+///         Traits {
+///             { Deref<Target = {SliceCustom}> };
+///         };
+///         Cmp(base = Inner) { PartialEq, PartialOrd } {
+///             { ({Custom}), ({SliceInner}), rev };
+///         };
+///     };
+/// }
+/// ```
+///
+/// ## Impls between the types
+///
+/// In addition to the given targets, the impls below are implemented automatically:
+///
+/// * `{ Borrow<{SliceCustom}> }`, `{ ToOwned<Owned = {Custom}> for {SliceCustom} }`, and
+///   `{ From<&{SliceCustom}> }` targets of [`impl_std_traits_for_owned_slice!`] macro.
+/// * If `Cmp` part is given for `Owned`, `{ ({Custom}), ({SliceCustom}), rev }`,
+///   `{ ({Custom}), (&{SliceCustom}), rev }`, and `{ ({Custom}), (Cow<{SliceCustom}>), rev }`
+///   pairs of [`impl_cmp_for_owned_slice!`] macro.
+///
+/// Do not specify them explicitly, or conflicting impls will be generated.
+///
+/// [`impl_cmp_for_owned_slice!`]: macro.impl_cmp_for_owned_slice.html
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+/// [`impl_traits_for_owned_slice!`]: macro.impl_traits_for_owned_slice.html
+/// [`impl_traits_for_slice!`]: macro.impl_traits_for_slice.html
+#[macro_export]
+macro_rules! impl_traits_for_slice_pair {
+    (
+        Slice { $($slice:tt)* };
+        Owned { $($owned:tt)* };
+    ) => {
+        $crate::__with_default_std! {
+            $crate::impl_traits_for_slice_pair;
+            head=[];
+            std_block;
+            Slice { $($slice)* };
+            Owned { $($owned)* };
+        }
+    };
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Slice { $($slice:tt)* };
+        Owned {
+            Spec $spec:tt;
+            $(Traits { $($traits:tt)* };)?
+            $(Cmp(base = $base:ident) { $($cmp:ident),* } { $($pairs:tt)* };)?
+        };
+    ) => {
+        $crate::impl_traits_for_slice! {
+            Std {
+                core: $core,
+                alloc: $alloc,
+            };
+            $($slice)*
+        }
+        $crate::impl_traits_for_owned_slice! {
+            Std {
+                core: $core,
+                alloc: $alloc,
+            };
+            Spec $spec;
+            Traits {
+                { Borrow<{SliceCustom}> };
+                { ToOwned<Owned = {Custom}> for {SliceCustom} };
+                { From<&{SliceCustom}> };
+                $($($traits)*)?
+            };
+            $(
+                Cmp(base = $base) { $($cmp),* } {
+                    { ({Custom}), ({SliceCustom}), rev };
+                    { ({Custom}), (&{SliceCustom}), rev };
+                    { ({Custom}), (Cow<{SliceCustom}>), rev };
+                    $($pairs)*
+                };
+            )?
+        }
+    };
+
     ($($rest:tt)*) => {
         compile_error!(stringify!($($rest)*));
     };
 }
+
+/// Implements inherent methods for the given owned custom slice type.
+///
+/// # Usage
+///
+/// ## Examples
+///
+/// ```ignore
+/// validated_slice::impl_methods_for_owned_slice! {
+///     // `Std` is omissible.
+///     Std {
+///         // Module identifier of `core` crate.
+///         // Default is `std`.
+///         core: core,
+///         // Module identifier of `alloc` crate.
+///         // Default is `std`.
+///         alloc: alloc,
+///     };
+///     Spec {
+///         spec: MyStringSpec,
+///         custom: MyString,
+///         inner: Vec<u8>,
+///         error: MyFromUtf8Error,
+///         slice_custom: MyStr,
+///         slice_inner: [u8],
+///         slice_error: MyUtf8Error,
+///     };
+///     { pub fn try_from_iter };
+///     /* ... and more methods you want! */
+/// }
+/// ```
+///
+/// The syntax of `Std` and `Spec` part is same as [`impl_std_traits_for_owned_slice!`] macro,
+/// except that spec fields cannot be omitted, because inherent impls require the type name.
+///
+/// ## Supported methods
+///
+/// Each method is specified by `{ vis fn method_name };` format.
+/// `vis` is the visibility of the generated method, and it can be omitted (private).
+///
+/// Supported methods are:
+///
+/// * `{ fn try_from_iter };`
+///     + `fn try_from_iter<I: IntoIterator>(iter: I) -> Result<{Custom}, {Error}>`
+///       where `{Inner}: FromIterator<I::Item>`.
+///     + This collects the items into `{Inner}`, and then validates it once.
+/// * `{ fn new_unchecked };`
+///     + `unsafe fn new_unchecked(inner: {Inner}) -> {Custom}`.
+///     + The caller should ensure that the slice spec validates the inner slice of `inner`.
+///       The generated document states this with the actual spec type.
+/// * `{ fn new };`
+///     + `fn new(inner: {Inner}) -> Result<{Custom}, {Error}>`.
+///     + This validates the inner slice of `inner`, as `TryFrom<{Inner}>` does.
+/// * `{ fn constructors(vis = visibility) };`, `{ fn constructors };`
+///     + Preset of the constructors `new` and `new_unchecked` with the given visibility, as
+///       [`impl_methods_for_slice!`] does.
+///     + `from_proof` is not included, since it requires `{Inner}: From<&{SliceInner}>`.
+/// * `{ fn as_bytes };`, `{ fn as_bytes(name = method_name) };`
+///     + `fn as_bytes(&self) -> &[u8]` where `{SliceInner}: AsRef<[u8]>`.
+/// * `{ fn as_raw };`, `{ fn as_raw(name = method_name) };`
+///     + `fn as_raw(&self) -> &{SliceInner}`.
+///     + The method name can be changed by `name = method_name`, as
+///       [`impl_methods_for_slice!`] does.
+/// * `{ fn mutate };`
+///     + `fn mutate(&mut self) -> MutationGuard<'_, {Spec}>` where `{Spec}: MutationHookSpec`.
+///     + The guard dereferences to `{SliceCustom}`, and calls the hooks of [`MutationHookSpec`]
+///       before and after the mutation.
+/// * `{ fn try_mutate };`
+///     + `fn try_mutate<F, R>(&mut self, f: F) -> Result<R, {Error}>`
+///       where `{Custom}: Clone` and `F: FnOnce(&mut {Inner}) -> R`.
+///     + This calls `f` with the snapshot of the inner value, and validates the result.
+///       If the validation succeeded, the value is replaced with the result.
+///     + If the validation failed, the value is kept unchanged, and the error is created by
+///       `OwnedSliceSpec::convert_validation_error` with the rejected inner value.
+///     + The value is kept unchanged also when `f` panics.
+/// * `{ fn sort };`, `{ fn sort_unstable };`, `{ fn dedup };`, `{ fn retain };`
+///     + `fn sort(&mut self)`, `fn sort_unstable(&mut self)`, `fn dedup(&mut self)`, and
+///       `fn retain<F>(&mut self, f: F)` where `F: FnMut(&T) -> bool`,
+///       `{SliceSpec}: OrderInsensitiveSpec`, and `{Inner}: Default`.
+///     + The inner type should be `Vec<T>`.
+///     + The result is not validated, since [`OrderInsensitiveSpec`] guarantees that reordering
+///       and removing the elements keep the value valid.
+/// * `{ fn sort(revalidate) };`, `{ fn sort_unstable(revalidate) };`, `{ fn dedup(revalidate) };`,
+///   `{ fn retain(revalidate) };`
+///     + Same as above, except that they return `Result<(), {Error}>` and require
+///       `{Custom}: Clone` instead of `OrderInsensitiveSpec`.
+///     + The operation is applied to a snapshot of the inner value, and the result is validated.
+///       If the validation failed, the value is kept unchanged and the error is returned.
+/// * `{ fn try_push };`, `{ fn try_insert };`, `{ fn try_extend };`
+///     + `fn try_push(&mut self, elem: T) -> Result<(), {SliceError}>`.
+///     + `fn try_insert(&mut self, index: usize, elem: T) -> Result<(), {SliceError}>`.
+///     + `fn try_extend<I>(&mut self, iter: I) -> Result<(), {SliceError}>`
+///       where `I: IntoIterator<Item = T>`.
+///     + `T` is the element type `<{SliceSpec} as ElementSpec>::Element`.
+///     + These require `{SliceSpec}: ElementSpec` and `{Inner}: Default`, and validate only the
+///       new elements.
+///     + The inner type is expected to be `Vec<T>`.
+///     + During the operation, the value is temporarily replaced with the one created from
+///       `{Inner}::default()`, and the owned custom value is recreated by
+///       `OwnedSliceSpec::from_inner_unchecked` after the operation.
+///       The value is recreated even if the operation panics, such as `try_insert` with an
+///       out-of-bounds index.
+///     + `try_extend` keeps the elements before the invalid one appended.
+/// * `{ fn from_normalized };`
+///     + `fn from_normalized<'a>(s: &'a {SliceInner}) -> Result<Cow<'a, {SliceCustom}>, {Error}>`
+///       where `{Spec}: NormalizeSpec` and `{SliceCustom}: ToOwned<Owned = {Custom}>`.
+///     + This returns `Cow::Borrowed` if `s` is valid, and otherwise normalizes `s` by
+///       [`NormalizeSpec::normalize`] and returns `Cow::Owned`.
+///     + The normalized value is validated, and the error is created by
+///       `OwnedSliceSpec::convert_validation_error` if it is still invalid.
+///     + This is provided as an inherent method instead of a `TryFrom` impl, because
+///       `TryFrom<&{SliceInner}> for Cow<'_, {SliceCustom}>` cannot be implemented due to the
+///       orphan rule.
+/// * `{ fn from_str_normalized };`
+///     + `fn from_str_normalized(s: &str) -> Result<{Custom}, {Error}>`
+///       where `{Spec}: NormalizeSpec`, `str: AsRef<{SliceInner}>`, and
+///       `{Inner}: From<&{SliceInner}>`.
+///     + This is a lenient alternative to `FromStr`: the string is normalized by
+///       [`NormalizeSpec::normalize`] if it is invalid, and then validated.
+///     + The string is validated only once if it is already valid.
+/// * `{ fn replace };`
+///     + `fn replace(&self, from: &{SliceCustom}, to: &{SliceCustom}) -> {Custom}`
+///       where `{SliceSpec}: SliceClosedSpec + ConcatClosedSpec` and `{Inner}: From<String>`.
+///     + This is generated for `{SliceCustom}`, and the inner slice type should be `str`.
+///     + The result is not validated, since the pieces of `self` and `to` are valid and
+///       [`ConcatClosedSpec`] guarantees that their concatenation is also valid.
+/// * `{ fn to_owned_with };`
+///     + `fn to_owned_with<F>(&self, f: F) -> Result<{Custom}, {Error}>`
+///       where `F: FnOnce(&{SliceInner}) -> {Inner}`.
+///     + This is generated for `{SliceCustom}`.
+///     + This transforms the inner slice by `f`, and validates the result.
+/// * `{ fn method_name(transform = path::to::fn) };`
+///     + `fn method_name(&self) -> Result<{Custom}, {Error}>`.
+///     + The function should have the type `fn(&{SliceInner}) -> {Inner}`, such as
+///       `str::to_ascii_lowercase`.
+///     + This is generated for `{SliceCustom}`.
+///     + This transforms the inner slice by the function, and validates the result.
+/// * `{ fn method_name(unsafe trusted_transform = path::to::fn) };`
+///     + `fn method_name(&self) -> {Custom}`.
+///     + Same as `transform`, but the result is not validated.
+///     + **Users are responsible** to ensure that the function always returns a valid value for
+///       valid input. Otherwise, the generated method may cause undefined behavior.
+///       With `paranoid` feature, the result is validated and the method panics on failure.
+/// * `{ fn from_proof };`
+///     + `fn from_proof(proof: Validated<'_, {SliceSpec}>) -> {Custom}`
+///       where `{Inner}: From<&{SliceInner}>`.
+///     + The proof is created by `SliceSpec::check` or `Validated::new`, and the slice is not
+///       validated again.
+/// * `{ fn try_from_cow };`
+///     + `fn try_from_cow<'a>(s: Cow<'a, {SliceInner}>) -> Result<Cow<'a, {SliceCustom}>, {Error}>`
+///       where `{SliceInner}: ToOwned<Owned = {Inner}>` and
+///       `{SliceCustom}: ToOwned<Owned = {Custom}>`.
+///     + This validates the value, and keeps it borrowed or owned.
+/// * `{ fn into_inner_cow };`
+///     + `fn into_inner_cow<'a>(s: Cow<'a, {SliceCustom}>) -> Cow<'a, {SliceInner}>`
+///       with the same bounds as `try_from_cow`.
+///     + These are provided as inherent methods, and compose with the `Cow` returned by
+///       lossy APIs such as `from_normalized`.
+///       `From` and `TryFrom` impls between `Cow`s cannot be implemented due to the orphan rule.
+/// * `{ fn try_from_vec };`
+///     + `fn try_from_vec(v: Vec<{Inner}>) -> Result<Vec<{Custom}>, BulkValidationError<{SliceError}, {Inner}>>`.
+///     + This validates all the elements first, and then converts them without reallocating the
+///       inner values.
+///     + If an invalid element is found, [`BulkValidationError`] with the index of the first
+///       invalid element and the input vector is returned.
+/// * `{ fn into_inner_vec };`
+///     + `fn into_inner_vec(v: Vec<{Custom}>) -> Vec<{Inner}>`.
+///     + This is the reverse of `try_from_vec`.
+/// * `{ fn try_arc_from_owned };`, `{ fn try_rc_from_owned };`
+///     + `fn try_arc_from_owned(inner: {Inner}) -> Result<Arc<{SliceCustom}>, {Error}>`
+///       where `Arc<{SliceInner}>: From<{Inner}>`.
+///       `try_rc_from_owned` has the same signature, except that it returns `Rc`.
+///     + These are generated as the methods of `{SliceCustom}`.
+///     + These convert the owned inner value into the reference-counted slice and validate it,
+///       without creating the owned custom value.
+///     + These require `{Error}: From<{SliceError}>`, since the invalid owned value is not
+///       available after the conversion.
+///     + These are provided as inherent methods instead of
+///       `TryFrom<{Inner}> for Arc<{SliceCustom}>` impls, because such impls cannot be implemented
+///       due to the orphan rule.
+/// * `{ fn into_raw };`
+///     + `fn into_raw(self) -> *mut c_char` where `{Inner}: Into<CString>`.
+///     + This transfers the ownership of the C string to the caller, as `CString::into_raw` does.
+/// * `{ fn from_raw };`
+///     + `unsafe fn from_raw(ptr: *mut c_char) -> Result<{Custom}, {Error}>`
+///       where `{Inner}: From<CString>`.
+///     + This retakes the ownership of the C string by `CString::from_raw`, and then validates it.
+///     + The caller should satisfy the safety condition of `CString::from_raw`.
+/// * `{ mod module_name(serde) };` (requires `serde` feature)
+///     + Defines a module `module_name` with `serialize` and `deserialize` functions, for
+///       `#[serde(with = "module_name")]` field attribute.
+///     + `serialize` serializes the value as `{SliceInner}`, and `deserialize` deserializes
+///       `{Inner}` and validates it.
+///       These require `{SliceInner}: Serialize`, `{Inner}: Deserialize<'de>`, and
+///       `{Error}: Display`.
+///     + This lets users choose the representation per field, instead of implementing
+///       `Serialize` and `Deserialize` for the custom type.
+///     + The module imports the items of the parent module by `use super::*`.
+///
+/// [`impl_std_traits_for_owned_slice!`]: macro.impl_std_traits_for_owned_slice.html
+/// [`BulkValidationError`]: struct.BulkValidationError.html
+/// [`ConcatClosedSpec`]: trait.ConcatClosedSpec.html
+/// [`MutationHookSpec`]: trait.MutationHookSpec.html
+/// [`OrderInsensitiveSpec`]: trait.OrderInsensitiveSpec.html
+/// [`NormalizeSpec::normalize`]: trait.NormalizeSpec.html#tymethod.normalize
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+#[macro_export]
+macro_rules! impl_methods_for_owned_slice {
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty,
+            slice_custom: $slice_custom:ty,
+            slice_inner: $slice_inner:ty,
+            slice_error: $slice_error:ty,
+        };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $(
+            $crate::impl_methods_for_owned_slice! {
+                @impl; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                    <$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner,
+                    $slice_error);
+                rest=[$($rest)*];
+            }
+        )*
+    };
+
+    (
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty,
+            slice_custom: $slice_custom:ty,
+            slice_inner: $slice_inner:ty,
+            slice_error: $slice_error:ty,
+        };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $(
+            $crate::impl_methods_for_owned_slice! {
+                @default; ($spec, $custom, $inner, $error,
+                    <$spec as $crate::OwnedSliceSpec>::SliceSpec, $slice_custom, $slice_inner,
+                    $slice_error);
+                rest=[$($rest)*];
+            }
+        )*
+    };
+    (
+        @default; ($($args:tt)*);
+        rest=[ $vis:vis mod $name:ident(serde) ];
+    ) => {
+        // The module is a named item, so it cannot be put in the anonymous constant of
+        // `__with_default_std!`. The module uses only `core`, which is always available.
+        $crate::impl_methods_for_owned_slice! {
+            @impl; ({core, alloc}, $($args)*);
+            rest=[$vis mod $name(serde)];
+        }
+    };
+    (@default; ($($args:tt)*); rest=[$($rest:tt)*];) => {
+        $crate::__with_default_std! {
+            $crate::impl_methods_for_owned_slice;
+            head=[@impl;];
+            lifetimes=[];
+            args=[$($args)*];
+            rest=[$($rest)*];
+        }
+    };
+
+    // try_from_iter
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn try_from_iter ];
+    ) => {
+        impl $custom {
+            /// Collects the items into the inner value, and validates it.
+            $vis fn try_from_iter<I>(iter: I) -> $core::result::Result<Self, $error>
+            where
+                I: $core::iter::IntoIterator,
+                $inner: $core::iter::FromIterator<<I as $core::iter::IntoIterator>::Item>,
+            {
+                let inner: $inner = $core::iter::FromIterator::from_iter(iter);
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+
+    // new_unchecked
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn new_unchecked ];
+    ) => {
+        impl $custom {
+            /// Creates the custom owned slice without any validation.
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!(
+                "`<", stringify!($slice_spec), " as SliceSpec>::validate(s)` should return ",
+                "`Ok(())` for the inner slice `s` of `inner`."
+            )]
+            ///
+            /// Users and generated codes may rely on the validity of custom slices.
+            #[inline]
+            $vis unsafe fn new_unchecked(inner: $inner) -> Self {
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the caller.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                }
+            }
+        }
+    };
+
+    // new
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn new ];
+    ) => {
+        impl $custom {
+            /// Creates the custom owned slice after validation.
+            #[inline]
+            $vis fn new(inner: $inner) -> $core::result::Result<Self, $error> {
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner),
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(
+                        e, inner,
+                    ));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$slice_spec::validate(s)` returns `Ok(())` for the inner slice `s`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+
+    // constructors
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn constructors ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ fn constructors(vis = $vis) ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ fn constructors(vis = $ctor_vis:vis) ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $ctor_vis fn new ];
+        }
+        $crate::impl_methods_for_owned_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $ctor_vis fn new_unchecked ];
+        }
+    };
+
+    // as_bytes, as_raw
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn as_bytes ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $vis fn as_bytes(name = as_bytes) ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn as_bytes(name = $name:ident) ];
+    ) => {
+        impl $custom {
+            /// Returns the byte representation of the slice.
+            #[inline]
+            $vis fn $name(&self) -> &[u8]
+            where
+                $slice_inner: $core::convert::AsRef<[u8]>,
+            {
+                <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self).as_ref()
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn as_raw ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $vis fn as_raw(name = as_raw) ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn as_raw(name = $name:ident) ];
+    ) => {
+        impl $custom {
+            /// Returns the inner slice.
+            #[inline]
+            $vis fn $name(&self) -> &$slice_inner {
+                <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self)
+            }
+        }
+    };
+
+    // mutate
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn mutate ];
+    ) => {
+        impl $custom {
+            /// Returns a guard to mutate the value through the borrowed custom slice.
+            #[inline]
+            $vis fn mutate(&mut self) -> $crate::MutationGuard<'_, $spec>
+            where
+                $spec: $crate::MutationHookSpec,
+            {
+                $crate::MutationGuard::new(self)
+            }
+        }
+    };
+
+    // try_mutate
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn try_mutate ];
+    ) => {
+        impl $custom {
+            /// Modifies the inner value, and validates the result.
+            ///
+            /// `f` is called with the snapshot of the inner value.
+            /// If the validation failed, the value is kept unchanged and the error is returned.
+            $vis fn try_mutate<F, R>(&mut self, f: F) -> $core::result::Result<R, $error>
+            where
+                $custom: $core::clone::Clone,
+                F: $core::ops::FnOnce(&mut $inner) -> R,
+            {
+                // Call `f` with a snapshot, so that the value is kept valid even if `f` panics.
+                let mut inner =
+                    <$spec as $crate::OwnedSliceSpec>::into_inner($core::clone::Clone::clone(self));
+                let ret = f(&mut inner);
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner),
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(
+                        e, inner,
+                    ));
+                }
+                *self = unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$slice_spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                };
+                Ok(ret)
+            }
+        }
+    };
+
+    // sort, sort_unstable, dedup, retain
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn sort $(($mode:ident))? ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl [reorder $($mode)?]; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $vis fn sort<>(&mut self) where [] ];
+            body=|inner| inner.sort();
+            doc="Sorts the elements.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn sort_unstable $(($mode:ident))? ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl [reorder $($mode)?]; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $vis fn sort_unstable<>(&mut self) where [] ];
+            body=|inner| inner.sort_unstable();
+            doc="Sorts the elements, but might not preserve the order of equal elements.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn dedup $(($mode:ident))? ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl [reorder $($mode)?]; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $vis fn dedup<>(&mut self) where [] ];
+            body=|inner| inner.dedup();
+            doc="Removes consecutive repeated elements.";
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn retain $(($mode:ident))? ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl [reorder $($mode)?]; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $vis fn retain<F>(&mut self, f: F) where [
+                F: $core::ops::FnMut(&<$slice_inner as $core::ops::Index<usize>>::Output) -> bool,
+            ] ];
+            body=|inner| inner.retain(f);
+            doc="Retains only the elements specified by the predicate.";
+        }
+    };
+    (
+        @impl [reorder]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[
+            $vis:vis fn $method:ident<$($gen:ident)?>(&mut self $(, $arg:ident: $arg_ty:ty)?)
+            where [$($bounds:tt)*]
+        ];
+        body=|$var:ident| $body:expr;
+        doc=$doc:literal;
+    ) => {
+        impl $custom {
+            #[doc = $doc]
+            ///
+            /// The result is not validated, since the spec is insensitive to the order of the
+            /// elements.
+            #[inline]
+            $vis fn $method<$($gen)?>(&mut self $(, $arg: $arg_ty)?)
+            where
+                $slice_spec: $crate::OrderInsensitiveSpec,
+                $inner: $core::default::Default,
+                $($bounds)*
+            {
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * The inner value is kept valid.
+                    //     + This is ensured by `$slice_spec: OrderInsensitiveSpec`, because the
+                    //       operation only reorders or removes the elements.
+                    $crate::__private::modify_owned_inner::<$spec, _>(self, |$var| $body)
+                }
+            }
+        }
+    };
+    (
+        @impl [reorder revalidate]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty,
+            $inner:ty, $error:ty, $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[
+            $vis:vis fn $method:ident<$($gen:ident)?>(&mut self $(, $arg:ident: $arg_ty:ty)?)
+            where [$($bounds:tt)*]
+        ];
+        body=|$var:ident| $body:expr;
+        doc=$doc:literal;
+    ) => {
+        impl $custom {
+            #[doc = $doc]
+            ///
+            /// The result is validated, and if the validation failed, the value is kept unchanged
+            /// and the error is returned.
+            $vis fn $method<$($gen)?>(
+                &mut self
+                $(, $arg: $arg_ty)?
+            ) -> $core::result::Result<(), $error>
+            where
+                $custom: $core::clone::Clone,
+                $($bounds)*
+            {
+                let mut $var =
+                    <$spec as $crate::OwnedSliceSpec>::into_inner($core::clone::Clone::clone(self));
+                $body;
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&$var),
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(
+                        e, $var,
+                    ));
+                }
+                *self = unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$slice_spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, $var)
+                };
+                Ok(())
+            }
+        }
+    };
+
+    // try_push, try_insert, try_extend
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn try_push ];
+    ) => {
+        impl $custom {
+            /// Validates the element, and appends it to the back of the slice.
+            ///
+            /// Only the new element is validated.
+            #[inline]
+            $vis fn try_push(
+                &mut self,
+                elem: <$slice_spec as $crate::ElementSpec>::Element,
+            ) -> $core::result::Result<(), $slice_error>
+            where
+                $slice_spec: $crate::ElementSpec,
+                $inner: $core::default::Default,
+            {
+                <$slice_spec as $crate::ElementSpec>::validate_element(&elem)?;
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * The inner value is kept valid.
+                    //     + This is ensured by `$slice_spec: ElementSpec`, because the pushed
+                    //       element is valid.
+                    $crate::__private::modify_owned_inner::<$spec, _>(self, |inner| inner.push(elem));
+                }
+                Ok(())
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn try_insert ];
+    ) => {
+        impl $custom {
+            /// Validates the element, and inserts it at the given index.
+            ///
+            /// Only the new element is validated.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than the length.
+            #[inline]
+            $vis fn try_insert(
+                &mut self,
+                index: usize,
+                elem: <$slice_spec as $crate::ElementSpec>::Element,
+            ) -> $core::result::Result<(), $slice_error>
+            where
+                $slice_spec: $crate::ElementSpec,
+                $inner: $core::default::Default,
+            {
+                <$slice_spec as $crate::ElementSpec>::validate_element(&elem)?;
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * The inner value is kept valid.
+                    //     + This is ensured by `$slice_spec: ElementSpec`, because the inserted
+                    //       element is valid.
+                    $crate::__private::modify_owned_inner::<$spec, _>(self, |inner| {
+                        inner.insert(index, elem)
+                    });
+                }
+                Ok(())
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn try_extend ];
+    ) => {
+        impl $custom {
+            /// Validates the elements, and appends them to the back of the slice.
+            ///
+            /// Only the new elements are validated.
+            /// If an invalid element is found, the elements before it are kept appended, and the
+            /// error is returned.
+            $vis fn try_extend<I>(&mut self, iter: I) -> $core::result::Result<(), $slice_error>
+            where
+                $slice_spec: $crate::ElementSpec,
+                $inner: $core::default::Default,
+                I: $core::iter::IntoIterator<Item = <$slice_spec as $crate::ElementSpec>::Element>,
+            {
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * The inner value is kept valid.
+                    //     + This is ensured by `$slice_spec: ElementSpec`, because only valid
+                    //       elements are pushed.
+                    $crate::__private::modify_owned_inner::<$spec, _>(self, |inner| {
+                        for elem in iter {
+                            <$slice_spec as $crate::ElementSpec>::validate_element(&elem)?;
+                            inner.push(elem);
+                        }
+                        Ok(())
+                    })
+                }
+            }
+        }
+    };
+
+    // from_normalized
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn from_normalized ];
+    ) => {
+        impl $custom {
+            /// Borrows the slice if it is valid, or creates the normalized owned value otherwise.
+            $vis fn from_normalized<'a>(
+                s: &'a $slice_inner,
+            ) -> $core::result::Result<$alloc::borrow::Cow<'a, $slice_custom>, $error>
+            where
+                $spec: $crate::NormalizeSpec,
+                $slice_custom: $alloc::borrow::ToOwned<Owned = $custom>,
+            {
+                if <$slice_spec as $crate::SliceSpec>::validate(s).is_ok() {
+                    return Ok($alloc::borrow::Cow::Borrowed(unsafe {
+                        // This is safe only when all of the conditions below are met:
+                        //
+                        // * `$slice_spec::validate(s)` returns `Ok(())`.
+                        //     + This is ensured by the leading `validate()` call.
+                        // * Safety condition for `<$slice_spec as $crate::SliceSpec>` is satisfied.
+                        $crate::__from_inner_unchecked!(slice; $slice_spec, s)
+                    }));
+                }
+                let inner = <$spec as $crate::NormalizeSpec>::normalize(s);
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
+                }
+                Ok($alloc::borrow::Cow::Owned(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the `validate()` call above.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                }))
+            }
+        }
+    };
+
+    // from_str_normalized
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn from_str_normalized ];
+    ) => {
+        impl $custom {
+            /// Parses the string leniently, normalizing it if it is not valid.
+            ///
+            /// This is a lenient alternative to `FromStr`.
+            $vis fn from_str_normalized(s: &str) -> $core::result::Result<Self, $error>
+            where
+                $spec: $crate::NormalizeSpec,
+                str: $core::convert::AsRef<$slice_inner>,
+                for<'a> $inner: $core::convert::From<&'a $slice_inner>,
+            {
+                let s: &$slice_inner = $core::convert::AsRef::<$slice_inner>::as_ref(s);
+                let inner = match <$slice_spec as $crate::SliceSpec>::validate(s) {
+                    Ok(()) => <$inner>::from(s),
+                    Err(_) => <$spec as $crate::NormalizeSpec>::normalize(s),
+                };
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the `validate()` call above.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+
+    // replace, to_owned_with
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn replace ];
+    ) => {
+        impl $slice_custom {
+            /// Replaces all matches of `from` with `to`, and returns the owned value.
+            ///
+            /// The result is not validated, since it is the concatenation of the valid pieces.
+            $vis fn replace(&self, from: &$slice_custom, to: &$slice_custom) -> $custom
+            where
+                $slice_spec: $crate::SliceClosedSpec + $crate::ConcatClosedSpec,
+                $inner: $core::convert::From<$alloc::string::String>,
+            {
+                let inner = <$slice_spec as $crate::SliceSpec>::as_inner(self).replace(
+                    <$slice_spec as $crate::SliceSpec>::as_inner(from),
+                    <$slice_spec as $crate::SliceSpec>::as_inner(to),
+                );
+                let inner = <$inner as $core::convert::From<$alloc::string::String>>::from(inner);
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(inner)` returns `Ok(())`.
+                    //     + The pieces of `self` between the matches are valid, since
+                    //       `$slice_spec` is closed under sub-slicing.
+                    //     + `to` is valid, since it is `$slice_custom`.
+                    //     + Their concatenation is valid, since `$slice_spec` is closed under
+                    //       concatenation.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                }
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn to_owned_with ];
+    ) => {
+        impl $slice_custom {
+            /// Creates the owned value by transforming the inner slice, and validates the result.
+            $vis fn to_owned_with<F>(&self, f: F) -> $core::result::Result<$custom, $error>
+            where
+                F: $core::ops::FnOnce(&$slice_inner) -> $inner,
+            {
+                let inner = f(<$slice_spec as $crate::SliceSpec>::as_inner(self));
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the `validate()` call above.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+
+    // Transforms: `fn name(transform = path)` and `fn name(unsafe trusted_transform = path)`
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn $name:ident(transform = $transform:path) ];
+    ) => {
+        impl $slice_custom {
+            #[doc = concat!(
+                "Transforms the inner slice by `", stringify!($transform),
+                "`, and validates the result."
+            )]
+            $vis fn $name(&self) -> $core::result::Result<$custom, $error> {
+                let f: fn(&$slice_inner) -> $inner = $transform;
+                let inner = f(<$slice_spec as $crate::SliceSpec>::as_inner(self));
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the `validate()` call above.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn $name:ident(unsafe trusted_transform = $transform:path) ];
+    ) => {
+        impl $slice_custom {
+            #[doc = concat!(
+                "Transforms the inner slice by `", stringify!($transform),
+                "`, which preserves the validity."
+            )]
+            #[inline]
+            $vis fn $name(&self) -> $custom {
+                let f: fn(&$slice_inner) -> $inner = $transform;
+                let inner = f(<$slice_spec as $crate::SliceSpec>::as_inner(self));
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + The user declared by `unsafe trusted_transform` that the transform
+                    //       preserves the validity.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                }
+            }
+        }
+    };
+
+    // from_proof
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn from_proof ];
+    ) => {
+        impl $custom {
+            /// Creates the owned value from the proof of the validity of the slice.
+            #[inline]
+            $vis fn from_proof(proof: $crate::Validated<'_, $slice_spec>) -> Self
+            where
+                for<'a> $inner: $core::convert::From<&'a $slice_inner>,
+            {
+                proof.into_owned::<$spec>()
+            }
+        }
+    };
+
+    // try_from_cow, into_inner_cow
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn try_from_cow ];
+    ) => {
+        impl $custom {
+            /// Validates the `Cow` of the inner slice, and keeps it borrowed or owned.
+            $vis fn try_from_cow<'a>(
+                s: $alloc::borrow::Cow<'a, $slice_inner>,
+            ) -> $core::result::Result<$alloc::borrow::Cow<'a, $slice_custom>, $error>
+            where
+                $slice_inner: $alloc::borrow::ToOwned<Owned = $inner>,
+                $slice_custom: $alloc::borrow::ToOwned<Owned = $custom>,
+            {
+                match s {
+                    $alloc::borrow::Cow::Borrowed(s) => {
+                        if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(s) {
+                            return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(
+                                e,
+                                $alloc::borrow::ToOwned::to_owned(s),
+                            ));
+                        }
+                        Ok($alloc::borrow::Cow::Borrowed(unsafe {
+                            // This is safe only when all of the conditions below are met:
+                            //
+                            // * `$slice_spec::validate(s)` returns `Ok(())`.
+                            //     + This is ensured by the leading `validate()` call.
+                            // * Safety condition for `<$slice_spec as $crate::SliceSpec>` is satisfied.
+                            $crate::__from_inner_unchecked!(slice; $slice_spec, s)
+                        }))
+                    }
+                    $alloc::borrow::Cow::Owned(inner) => {
+                        if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                            <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                        ) {
+                            return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
+                        }
+                        Ok($alloc::borrow::Cow::Owned(unsafe {
+                            // This is safe only when all of the conditions below are met:
+                            //
+                            // * `$spec::validate(s)` returns `Ok(())`.
+                            //     + This is ensured by the leading `validate()` call.
+                            // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                            $crate::__from_inner_unchecked!(owned; $spec, inner)
+                        }))
+                    }
+                }
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn into_inner_cow ];
+    ) => {
+        impl $custom {
+            /// Converts the `Cow` of the custom slice into the `Cow` of the inner slice.
+            #[inline]
+            $vis fn into_inner_cow<'a>(
+                s: $alloc::borrow::Cow<'a, $slice_custom>,
+            ) -> $alloc::borrow::Cow<'a, $slice_inner>
+            where
+                $slice_inner: $alloc::borrow::ToOwned<Owned = $inner>,
+                $slice_custom: $alloc::borrow::ToOwned<Owned = $custom>,
+            {
+                match s {
+                    $alloc::borrow::Cow::Borrowed(s) => {
+                        $alloc::borrow::Cow::Borrowed(<$slice_spec as $crate::SliceSpec>::as_inner(s))
+                    }
+                    $alloc::borrow::Cow::Owned(s) => {
+                        $alloc::borrow::Cow::Owned(<$spec as $crate::OwnedSliceSpec>::into_inner(s))
+                    }
+                }
+            }
+        }
+    };
+
+    // try_from_vec, into_inner_vec
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn try_from_vec ];
+    ) => {
+        impl $custom {
+            /// Validates all the elements, and converts the vector into the vector of the custom
+            /// type.
+            ///
+            /// The inner values are moved without reallocation.
+            /// If an invalid element is found, the error has its index and the input vector.
+            $vis fn try_from_vec(
+                v: $alloc::vec::Vec<$inner>,
+            ) -> $core::result::Result<
+                $alloc::vec::Vec<Self>,
+                $crate::BulkValidationError<$slice_error, $inner>,
+            > {
+                let invalid = v.iter().enumerate().find_map(|(index, inner)| {
+                    <$slice_spec as $crate::SliceSpec>::validate(
+                        <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(inner),
+                    )
+                    .err()
+                    .map(|e| (index, e))
+                });
+                if let Some((index, e)) = invalid {
+                    return Err($crate::BulkValidationError::new(index, e, v));
+                }
+                Ok(v.into_iter()
+                    .map(|inner| unsafe {
+                        // This is safe only when all of the conditions below are met:
+                        //
+                        // * `$slice_spec::validate(s)` returns `Ok(())`.
+                        //     + This is ensured by the leading `validate()` calls for all the
+                        //       elements.
+                        // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                        $crate::__from_inner_unchecked!(owned; $spec, inner)
+                    })
+                    .collect())
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn into_inner_vec ];
+    ) => {
+        impl $custom {
+            /// Converts the vector of the custom type into the vector of the inner type.
+            ///
+            /// The inner values are moved without reallocation.
+            #[inline]
+            $vis fn into_inner_vec(v: $alloc::vec::Vec<Self>) -> $alloc::vec::Vec<$inner> {
+                v.into_iter()
+                    .map(<$spec as $crate::OwnedSliceSpec>::into_inner)
+                    .collect()
+            }
+        }
+    };
+
+    // try_arc_from_owned, try_rc_from_owned
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn try_arc_from_owned ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl [smartptr]; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $vis fn try_arc_from_owned -> $alloc::sync::Arc ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn try_rc_from_owned ];
+    ) => {
+        $crate::impl_methods_for_owned_slice! {
+            @impl [smartptr]; ({$core, $alloc}, $spec, $custom, $inner, $error,
+                $slice_spec, $slice_custom, $slice_inner, $slice_error);
+            rest=[ $vis fn try_rc_from_owned -> $alloc::rc::Rc ];
+        }
+    };
+    (
+        @impl [smartptr]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn $method:ident -> $($smartptr:ident)::* ];
+    ) => {
+        impl $slice_custom {
+            /// Validates the owned inner value, and converts it into a reference-counted custom
+            /// slice.
+            ///
+            /// The intermediate owned custom value is not created.
+            $vis fn $method(
+                inner: $inner,
+            ) -> $core::result::Result<$($smartptr)::* <Self>, $error>
+            where
+                $($smartptr)::* <$slice_inner>: $core::convert::From<$inner>,
+                $error: $core::convert::From<$slice_error>,
+            {
+                // Validate the converted slice, since the conversion is not trusted to keep the
+                // content.
+                let buf = $($smartptr)::* ::<$slice_inner>::from(inner);
+                <$slice_spec as $crate::SliceSpec>::validate(&*buf)?;
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$slice_spec::validate(s)` returns `Ok(())` for the inner slice `s`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    // * Safety condition for `<$slice_spec as $crate::SliceSpec>` is satisfied.
+                    //     + This ensures that the memory layout of `into_raw(buf)` is also valid
+                    //       as `$($smartptr)::* <$slice_custom>`.
+                    $($smartptr)::* ::<Self>::from_raw(
+                        $($smartptr)::* ::<$slice_inner>::into_raw(buf) as *const Self
+                    )
+                })
+            }
+        }
+    };
+
+    // into_raw
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn into_raw ];
+    ) => {
+        impl $custom {
+            /// Consumes the value and transfers the ownership of the C string to the caller.
+            #[inline]
+            $vis fn into_raw(self) -> *mut $core::ffi::c_char
+            where
+                $inner: $core::convert::Into<$alloc::ffi::CString>,
+            {
+                let inner = <$spec as $crate::OwnedSliceSpec>::into_inner(self);
+                $alloc::ffi::CString::into_raw(inner.into())
+            }
+        }
+    };
+
+    // from_raw
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis fn from_raw ];
+    ) => {
+        impl $custom {
+            /// Retakes the ownership of the C string, and validates it.
+            ///
+            /// # Safety
+            ///
+            /// Safety condition is same as `CString::from_raw`.
+            $vis unsafe fn from_raw(
+                ptr: *mut $core::ffi::c_char,
+            ) -> $core::result::Result<Self, $error>
+            where
+                $inner: $core::convert::From<$alloc::ffi::CString>,
+            {
+                let inner = <$inner>::from(unsafe { $alloc::ffi::CString::from_raw(ptr) });
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+
+    // serde helper module
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $vis:vis mod $name:ident(serde) ];
+    ) => {
+        $crate::__impl_serde! {
+            @module; ({$core, $alloc}, $spec, $custom, $inner, $error, $slice_spec, $slice_inner);
+            $vis mod $name
+        }
+    };
+
+    // Fallback.
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_custom:ty, $slice_inner:ty, $slice_error:ty);
+        rest=[ $($rest:tt)* ];
+    ) => {
+        compile_error!(concat!("Unsupported method: ", stringify!($($rest)*)));
+    };
+}
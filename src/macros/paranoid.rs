@@ -0,0 +1,73 @@
+//! Macros for `paranoid` feature.
+
+/// Converts the inner value into the custom type without validation.
+///
+/// With `paranoid` feature, the inner value is validated, and the conversion panics on failure.
+///
+/// This is used internally by the macros, and should be used in `unsafe` context.
+#[cfg(feature = "paranoid")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __from_inner_unchecked {
+    (slice; $spec:ty, $inner:expr) => {{
+        let inner = $inner;
+        if <$spec as $crate::SliceSpec>::validate(inner).is_err() {
+            $crate::__from_inner_unchecked!(@panic; $spec)
+        }
+        <$spec as $crate::SliceSpec>::from_inner_unchecked(inner)
+    }};
+    (slice_mut; $spec:ty, $inner:expr) => {{
+        let inner = $inner;
+        if <$spec as $crate::SliceSpec>::validate(&*inner).is_err() {
+            $crate::__from_inner_unchecked!(@panic; $spec)
+        }
+        <$spec as $crate::SliceSpec>::from_inner_unchecked_mut(inner)
+    }};
+    (owned; $spec:ty, $inner:expr) => {{
+        let inner = $inner;
+        if <<$spec as $crate::OwnedSliceSpec>::SliceSpec as $crate::SliceSpec>::validate(
+            <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner),
+        )
+        .is_err()
+        {
+            $crate::__from_inner_unchecked!(@panic; $spec)
+        }
+        <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked(inner)
+    }};
+    (value; $spec:ty, $inner:expr) => {{
+        let inner = $inner;
+        if <$spec as $crate::ValueSpec>::validate(&inner).is_err() {
+            $crate::__from_inner_unchecked!(@panic; $spec)
+        }
+        <$spec as $crate::ValueSpec>::from_inner_unchecked(inner)
+    }};
+    (@panic; $spec:ty) => {
+        panic!(concat!(
+            "`validated-slice` paranoid check: invalid value is passed to ",
+            "`from_inner_unchecked` of `",
+            stringify!($spec),
+            "`"
+        ))
+    };
+}
+
+/// Converts the inner value into the custom type without validation.
+///
+/// This is used internally by the macros, and should be used in `unsafe` context.
+#[cfg(not(feature = "paranoid"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __from_inner_unchecked {
+    (slice; $spec:ty, $inner:expr) => {
+        <$spec as $crate::SliceSpec>::from_inner_unchecked($inner)
+    };
+    (slice_mut; $spec:ty, $inner:expr) => {
+        <$spec as $crate::SliceSpec>::from_inner_unchecked_mut($inner)
+    };
+    (owned; $spec:ty, $inner:expr) => {
+        <$spec as $crate::OwnedSliceSpec>::from_inner_unchecked($inner)
+    };
+    (value; $spec:ty, $inner:expr) => {
+        <$spec as $crate::ValueSpec>::from_inner_unchecked($inner)
+    };
+}
@@ -0,0 +1,59 @@
+//! Macros for `rand` crate integration.
+
+/// Implements `rand` traits for the given custom owned slice type.
+///
+/// This is used internally by `impl_std_traits_for_owned_slice!` macro.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_rand {
+    (
+        @Distribution; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty,
+            $slice_spec:ty);
+    ) => {
+        impl $crate::__private::rand::distr::Distribution<$custom>
+            for $crate::__private::rand::distr::StandardUniform
+        where
+            $spec: $crate::GenerateSpec,
+        {
+            fn sample<R>(&self, rng: &mut R) -> $custom
+            where
+                R: $crate::__private::rand::Rng + ?Sized,
+            {
+                let inner: $inner = <$spec as $crate::GenerateSpec>::generate(rng);
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner),
+                ) {
+                    // The validation error is not required to implement `Debug`.
+                    let _ = e;
+                    panic!(concat!(
+                        "`GenerateSpec::generate()` for `",
+                        stringify!($spec),
+                        "` returned an invalid value"
+                    ));
+                }
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                }
+            }
+        }
+    };
+    (@$target:ident; $($rest:tt)*) => {
+        compile_error!(concat!("Unsupported target: rand::", stringify!($target)));
+    };
+}
+
+/// Emits a compile error, because `rand` feature is disabled.
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_rand {
+    ($($rest:tt)*) => {
+        compile_error!("`rand::*` targets require `rand` feature of `validated-slice` crate");
+    };
+}
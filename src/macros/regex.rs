@@ -0,0 +1,112 @@
+//! Macros for `regex` crate integration.
+
+/// Defines a spec and a custom slice type for strings matching the regex.
+///
+/// This requires `regex` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "regex")]
+/// # mod ident {
+/// validated_slice::regex_spec!(
+///     pub IdentSpec,
+///     /// Identifier.
+///     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///     pub IdentStr,
+///     r"^[A-Za-z_][A-Za-z0-9_]*$"
+/// );
+///
+/// validated_slice::impl_std_traits_for_slice! {
+///     Spec {
+///         spec: IdentSpec,
+///         custom: IdentStr,
+///         inner: str,
+///         error: validated_slice::RegexMismatchError,
+///     };
+///     { AsRef<str> };
+///     { TryFrom<&{Inner}> for &{Custom} };
+/// }
+/// # }
+/// # #[cfg(feature = "regex")]
+/// # {
+/// # use ident::IdentStr;
+/// use std::convert::TryFrom;
+///
+/// assert!(<&IdentStr>::try_from("foo_bar").is_ok());
+/// assert!(<&IdentStr>::try_from("0foo").is_err());
+/// # }
+/// ```
+///
+/// # Usage
+///
+/// The macro takes the spec type name, the custom slice type name, and the pattern.
+/// Visibility and attributes (including doc comments) can be specified for each type.
+///
+/// The macro generates the items below:
+///
+/// * The spec type, which implements `SliceSpec`.
+///     + The error type is [`RegexMismatchError`].
+/// * The custom slice type, which is a `#[repr(transparent)]` wrapper of `str`.
+///     + No traits are implemented by default. Use `#[derive(..)]` and
+///       [`impl_std_traits_for_slice!`] to implement traits.
+///
+/// The pattern should match the whole string, even if it is not anchored by `^` and `$`.
+/// The regex is compiled at the first validation, and the validation panics if the pattern is
+/// invalid.
+///
+/// [`RegexMismatchError`]: struct.RegexMismatchError.html
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+#[cfg(feature = "regex")]
+#[macro_export]
+macro_rules! regex_spec {
+    (
+        $(#[$spec_meta:meta])*
+        $spec_vis:vis $spec:ident,
+        $(#[$custom_meta:meta])*
+        $custom_vis:vis $custom:ident,
+        $pattern:expr $(,)?
+    ) => {
+        $(#[$spec_meta])*
+        $spec_vis enum $spec {}
+
+        impl $crate::SliceSpec for $spec {
+            type Custom = $custom;
+            type Inner = str;
+            type Error = $crate::RegexMismatchError;
+
+            fn validate(s: &Self::Inner) -> ::std::result::Result<(), Self::Error> {
+                static VALIDATOR: ::std::sync::OnceLock<$crate::__private::RegexValidator> =
+                    ::std::sync::OnceLock::new();
+                VALIDATOR
+                    .get_or_init(|| $crate::__private::RegexValidator::new($pattern))
+                    .validate(s)
+            }
+
+            $crate::impl_slice_spec_methods! {
+                field=0;
+                methods=[
+                    as_inner,
+                    as_inner_mut,
+                    from_inner_unchecked,
+                    from_inner_unchecked_mut,
+                ];
+            }
+        }
+
+        $(#[$custom_meta])*
+        // `#[repr(transparent)]` is required for the generated codes to be sound.
+        #[repr(transparent)]
+        $custom_vis struct $custom(str);
+    };
+}
+
+/// Emits a compile error, because `regex` feature is disabled.
+#[cfg(not(feature = "regex"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! regex_spec {
+    ($($rest:tt)*) => {
+        compile_error!("`regex_spec!` requires `regex` feature of `validated-slice` crate");
+    };
+}
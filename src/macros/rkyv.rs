@@ -0,0 +1,122 @@
+//! Macros for `rkyv` crate integration.
+
+/// Implements `rkyv` traits for the given custom owned slice type.
+///
+/// This is used internally by `impl_std_traits_for_owned_slice!` macro.
+///
+/// The archived form of the custom owned slice is the archived form of `Box<{SliceInner}>`.
+#[cfg(feature = "rkyv")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_rkyv {
+    (
+        @Archive; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $slice_spec:ty,
+            $slice_inner:ty);
+    ) => {
+        impl $crate::__private::rkyv::Archive for $custom
+        where
+            $slice_inner: $crate::__private::rkyv::ArchiveUnsized,
+        {
+            type Archived = $crate::__private::rkyv::boxed::ArchivedBox<
+                <$slice_inner as $crate::__private::rkyv::ArchiveUnsized>::Archived,
+            >;
+            type Resolver = $crate::__private::rkyv::boxed::BoxResolver;
+
+            #[inline]
+            fn resolve(
+                &self,
+                resolver: Self::Resolver,
+                out: $crate::__private::rkyv::Place<Self::Archived>,
+            ) {
+                let slice_inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                $crate::__private::rkyv::boxed::ArchivedBox::resolve_from_ref(
+                    slice_inner,
+                    resolver,
+                    out,
+                );
+            }
+        }
+    };
+    (
+        @Serialize; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $slice_spec:ty,
+            $slice_inner:ty);
+    ) => {
+        impl<S> $crate::__private::rkyv::Serialize<S> for $custom
+        where
+            S: $crate::__private::rkyv::rancor::Fallible + ?Sized,
+            $slice_inner: $crate::__private::rkyv::SerializeUnsized<S>,
+        {
+            #[inline]
+            fn serialize(
+                &self,
+                serializer: &mut S,
+            ) -> $core::result::Result<
+                Self::Resolver,
+                <S as $crate::__private::rkyv::rancor::Fallible>::Error,
+            > {
+                let slice_inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                $crate::__private::rkyv::boxed::ArchivedBox::serialize_from_ref(
+                    slice_inner,
+                    serializer,
+                )
+            }
+        }
+    };
+    (
+        @Deserialize; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $slice_spec:ty,
+            $slice_inner:ty);
+    ) => {
+        impl<D> $crate::__private::rkyv::Deserialize<$custom, D>
+            for $crate::__private::rkyv::boxed::ArchivedBox<
+                <$slice_inner as $crate::__private::rkyv::ArchiveUnsized>::Archived,
+            >
+        where
+            D: $crate::__private::rkyv::rancor::Fallible + ?Sized,
+            <D as $crate::__private::rkyv::rancor::Fallible>::Error:
+                $crate::__private::rkyv::rancor::Source,
+            $slice_inner: $crate::__private::rkyv::ArchiveUnsized
+                + $crate::__private::rkyv::traits::LayoutRaw,
+            <$slice_inner as $crate::__private::rkyv::ArchiveUnsized>::Archived:
+                $crate::__private::rkyv::DeserializeUnsized<$slice_inner, D>,
+            $inner: $core::convert::From<$alloc::boxed::Box<$slice_inner>>,
+        {
+            fn deserialize(
+                &self,
+                deserializer: &mut D,
+            ) -> $core::result::Result<$custom, <D as $crate::__private::rkyv::rancor::Fallible>::Error> {
+                let boxed = <Self as $crate::__private::rkyv::Deserialize<
+                    $alloc::boxed::Box<$slice_inner>,
+                    D,
+                >>::deserialize(self, deserializer)?;
+                let inner = <$inner>::from(boxed);
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    let e = <$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner);
+                    return Err(<<D as $crate::__private::rkyv::rancor::Fallible>::Error as $crate::__private::rkyv::rancor::Source>::new(e));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+    (@$target:ident; $($rest:tt)*) => {
+        compile_error!(concat!("Unsupported target: rkyv::", stringify!($target)));
+    };
+}
+
+/// Emits a compile error, because `rkyv` feature is disabled.
+#[cfg(not(feature = "rkyv"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_rkyv {
+    ($($rest:tt)*) => {
+        compile_error!("`rkyv::*` targets require `rkyv` feature of `validated-slice` crate");
+    };
+}
@@ -0,0 +1,57 @@
+//! Macros for `schemars` crate integration.
+
+/// Implements `schemars::JsonSchema` for the given custom type.
+///
+/// This is used internally by `impl_std_traits_for{,_owned}_slice!` macros.
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_json_schema {
+    (
+        ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $custom:ty, $base:ty);
+        spec=[$($schema_spec:ty)?]
+    ) => {
+        impl<$($lt $(: $bound)?),*> $crate::__private::schemars::JsonSchema for $custom
+        where
+            $base: $crate::__private::schemars::JsonSchema,
+            $($schema_spec: $crate::JsonSchemaSpec,)?
+        {
+            fn schema_name() -> $alloc::borrow::Cow<'static, str> {
+                $alloc::borrow::Cow::Borrowed(stringify!($custom))
+            }
+
+            fn schema_id() -> $alloc::borrow::Cow<'static, str> {
+                $alloc::borrow::Cow::Borrowed(concat!(module_path!(), "::", stringify!($custom)))
+            }
+
+            fn json_schema(
+                generator: &mut $crate::__private::schemars::SchemaGenerator,
+            ) -> $crate::__private::schemars::Schema {
+                #[allow(unused_mut)]
+                let mut schema =
+                    <$base as $crate::__private::schemars::JsonSchema>::json_schema(generator);
+                $(
+                    if let Some(format) = <$schema_spec as $crate::JsonSchemaSpec>::FORMAT {
+                        schema.insert("format".into(), format.into());
+                    }
+                    if let Some(pattern) = <$schema_spec as $crate::JsonSchemaSpec>::PATTERN {
+                        schema.insert("pattern".into(), pattern.into());
+                    }
+                )?
+                schema
+            }
+        }
+    };
+}
+
+/// Emits a compile error, because `schemars` feature is disabled.
+#[cfg(not(feature = "schemars"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_json_schema {
+    ($($rest:tt)*) => {
+        compile_error!(
+            "`JsonSchema` target requires `schemars` feature of `validated-slice` crate"
+        );
+    };
+}
@@ -0,0 +1,74 @@
+//! Macros for `serde` crate integration.
+
+/// Defines a module to (de)serialize the owned custom slice as the inner value.
+///
+/// This is used internally by `impl_methods_for_owned_slice!` macro.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_serde {
+    (
+        @module; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_inner:ty);
+        $vis:vis mod $name:ident
+    ) => {
+        #[doc = concat!(
+            "Serializes and deserializes `", stringify!($custom), "` as the inner value.\n\n",
+            "Use this module by `#[serde(with = \"", stringify!($name), "\")]` field attribute."
+        )]
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// Serializes the value as the inner slice.
+            pub fn serialize<S>(
+                value: &$custom,
+                serializer: S,
+            ) -> $core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+                $slice_inner: $crate::__private::serde::Serialize,
+            {
+                $crate::__private::serde::Serialize::serialize(
+                    <$spec as $crate::OwnedSliceSpec>::as_slice_inner(value),
+                    serializer,
+                )
+            }
+
+            /// Deserializes the inner value, and validates it.
+            pub fn deserialize<'de, D>(deserializer: D) -> $core::result::Result<$custom, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+                $inner: $crate::__private::serde::Deserialize<'de>,
+                $error: $core::fmt::Display,
+            {
+                let inner =
+                    <$inner as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner)
+                ) {
+                    let e = <$spec as $crate::OwnedSliceSpec>::convert_validation_error(e, inner);
+                    return Err(<D::Error as $crate::__private::serde::de::Error>::custom(e));
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+}
+
+/// Emits a compile error, because `serde` feature is disabled.
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_serde {
+    ($($rest:tt)*) => {
+        compile_error!("`serde` helper modules require `serde` feature of `validated-slice` crate");
+    };
+}
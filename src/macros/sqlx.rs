@@ -0,0 +1,112 @@
+//! Macros for `sqlx` crate integration.
+
+/// Implements `sqlx` traits for the given custom owned slice type.
+///
+/// This is used internally by `impl_std_traits_for_owned_slice!` macro.
+#[cfg(feature = "sqlx")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_sqlx {
+    (
+        @Type; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_inner:ty);
+    ) => {
+        impl<DB> $crate::__private::sqlx::Type<DB> for $custom
+        where
+            DB: $crate::__private::sqlx::Database,
+            $inner: $crate::__private::sqlx::Type<DB>,
+        {
+            #[inline]
+            fn type_info() -> <DB as $crate::__private::sqlx::Database>::TypeInfo {
+                <$inner as $crate::__private::sqlx::Type<DB>>::type_info()
+            }
+
+            #[inline]
+            fn compatible(ty: &<DB as $crate::__private::sqlx::Database>::TypeInfo) -> bool {
+                <$inner as $crate::__private::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+    };
+    (
+        @Encode; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_inner:ty);
+    ) => {
+        impl<'q, DB> $crate::__private::sqlx::Encode<'q, DB> for $custom
+        where
+            DB: $crate::__private::sqlx::Database,
+            $inner: $crate::__private::sqlx::Encode<'q, DB>,
+            for<'e> &'e $slice_inner: $crate::__private::sqlx::Encode<'q, DB>,
+        {
+            #[inline]
+            fn encode(
+                self,
+                buf: &mut <DB as $crate::__private::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> $core::result::Result<
+                $crate::__private::sqlx::encode::IsNull,
+                $crate::__private::sqlx::error::BoxDynError,
+            > {
+                let inner = <$spec as $crate::OwnedSliceSpec>::into_inner(self);
+                <$inner as $crate::__private::sqlx::Encode<'q, DB>>::encode(inner, buf)
+            }
+
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as $crate::__private::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> $core::result::Result<
+                $crate::__private::sqlx::encode::IsNull,
+                $crate::__private::sqlx::error::BoxDynError,
+            > {
+                let inner = <$spec as $crate::OwnedSliceSpec>::as_slice_inner(self);
+                <&$slice_inner as $crate::__private::sqlx::Encode<'q, DB>>::encode_by_ref(
+                    &inner, buf,
+                )
+            }
+        }
+    };
+    (
+        @Decode; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty,
+            $slice_spec:ty, $slice_inner:ty);
+    ) => {
+        impl<'r, DB> $crate::__private::sqlx::Decode<'r, DB> for $custom
+        where
+            DB: $crate::__private::sqlx::Database,
+            $inner: $crate::__private::sqlx::Decode<'r, DB>,
+            $error: $core::convert::Into<$crate::__private::sqlx::error::BoxDynError>,
+        {
+            fn decode(
+                value: <DB as $crate::__private::sqlx::Database>::ValueRef<'r>,
+            ) -> $core::result::Result<Self, $crate::__private::sqlx::error::BoxDynError> {
+                let inner = <$inner as $crate::__private::sqlx::Decode<'r, DB>>::decode(value)?;
+                if let Err(e) = <$slice_spec as $crate::SliceSpec>::validate(
+                    <$spec as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner),
+                ) {
+                    return Err(<$spec as $crate::OwnedSliceSpec>::convert_validation_error(
+                        e, inner,
+                    )
+                    .into());
+                }
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()` call.
+                    // * Safety condition for `<$spec as $crate::OwnedSliceSpec>` is satisfied.
+                    $crate::__from_inner_unchecked!(owned; $spec, inner)
+                })
+            }
+        }
+    };
+    (@$target:ident; $($rest:tt)*) => {
+        compile_error!(concat!("Unsupported target: sqlx::", stringify!($target)));
+    };
+}
+
+/// Emits a compile error, because `sqlx` feature is disabled.
+#[cfg(not(feature = "sqlx"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_sqlx {
+    ($($rest:tt)*) => {
+        compile_error!("`sqlx::*` targets require `sqlx` feature of `validated-slice` crate");
+    };
+}
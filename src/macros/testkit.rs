@@ -0,0 +1,246 @@
+//! Macros to test specs.
+
+/// Generates unit tests for the spec with valid and invalid samples.
+///
+/// This generates `#[test]` functions below:
+///
+/// * `validate_samples`
+///     + Checks that `SliceSpec::validate` accepts all the valid samples and rejects all the
+///       invalid samples.
+/// * `try_from_samples`
+///     + Checks that `TryFrom<&{Inner}> for &{Custom}` accepts all the valid samples and rejects
+///       all the invalid samples.
+///     + Checks that the converted custom slice refers to the same inner slice.
+/// * `cmp_symmetry_samples`
+///     + Checks that `PartialEq` of the custom slice type is reflexive and symmetric for the
+///       valid samples.
+/// * `owned_roundtrip_samples` (only when `owned = OwnedSpec` is specified)
+///     + Checks that `TryFrom<{Inner}> for {Custom}` of the owned type accepts all the valid
+///       samples and rejects all the invalid samples.
+///     + Checks that the inner value is kept unchanged by the conversion from and into the owned
+///       custom type.
+///
+/// Since the names of the generated functions are fixed, invoke this macro in the dedicated
+/// module for each spec.
+///
+/// # Examples
+///
+/// ```
+/// # #[repr(transparent)]
+/// # #[derive(Debug, PartialEq, Eq)]
+/// # pub struct AsciiStr(str);
+/// #
+/// # pub enum AsciiStrSpec {}
+/// #
+/// # impl validated_slice::SliceSpec for AsciiStrSpec {
+/// #     type Custom = AsciiStr;
+/// #     type Inner = str;
+/// #     type Error = ();
+/// #
+/// #     fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+/// #         if s.is_ascii() { Ok(()) } else { Err(()) }
+/// #     }
+/// #
+/// #     validated_slice::impl_slice_spec_methods! {
+/// #         field=0;
+/// #         methods=[
+/// #             as_inner,
+/// #             as_inner_mut,
+/// #             from_inner_unchecked,
+/// #             from_inner_unchecked_mut,
+/// #         ];
+/// #     }
+/// # }
+/// #
+/// # validated_slice::impl_std_traits_for_slice! {
+/// #     Spec {
+/// #         spec: AsciiStrSpec,
+/// #         custom: AsciiStr,
+/// #         inner: str,
+/// #         error: (),
+/// #     };
+/// #     { TryFrom<&{Inner}> for &{Custom} };
+/// # }
+/// #[cfg(test)]
+/// mod ascii_str_spec {
+///     use super::*;
+///
+///     validated_slice::spec_tests!(AsciiStrSpec, valid = ["", "a", "b"], invalid = ["ä"]);
+/// }
+/// ```
+///
+/// # Usage
+///
+/// ```text
+/// validated_slice::spec_tests!(
+///     SliceSpecType,
+///     valid = [valid_sample, ...],
+///     invalid = [invalid_sample, ...],
+///     // Optional.
+///     owned = OwnedSliceSpecType,
+/// );
+/// ```
+///
+/// Samples are expressions of type `&{Inner}` (coercible ones such as `b"foo"` for `&[u8]` are
+/// also accepted).
+///
+/// The generated tests require the types below:
+///
+/// * `{Inner}: Debug`.
+/// * `{Custom}: PartialEq`.
+/// * `TryFrom<&{Inner}> for &{Custom}`.
+/// * When `owned = OwnedSpec` is specified:
+///     + `{SliceInner}` of the owned spec should be same as `{Inner}` of the spec.
+///     + `{Inner}: From<&{SliceInner}>` for the owned spec, and `{SliceInner}: PartialEq`.
+///     + `TryFrom<{Inner}> for {Custom}` for the owned spec.
+#[macro_export]
+macro_rules! spec_tests {
+    (
+        $spec:ty,
+        valid = [$($valid:expr),* $(,)?],
+        invalid = [$($invalid:expr),* $(,)?]
+        $(,)?
+    ) => {
+        $crate::spec_tests! {
+            @borrowed; $spec; valid=[$($valid),*]; invalid=[$($invalid),*];
+        }
+    };
+    (
+        $spec:ty,
+        valid = [$($valid:expr),* $(,)?],
+        invalid = [$($invalid:expr),* $(,)?],
+        owned = $owned:ty
+        $(,)?
+    ) => {
+        $crate::spec_tests! {
+            @borrowed; $spec; valid=[$($valid),*]; invalid=[$($invalid),*];
+        }
+        $crate::spec_tests! {
+            @owned; $spec, $owned; valid=[$($valid),*]; invalid=[$($invalid),*];
+        }
+    };
+
+    (
+        @borrowed; $spec:ty; valid=[$($valid:expr),*]; invalid=[$($invalid:expr),*];
+    ) => {
+        #[test]
+        fn validate_samples() {
+            $({
+                let s: &<$spec as $crate::SliceSpec>::Inner = $valid;
+                assert!(
+                    <$spec as $crate::SliceSpec>::validate(s).is_ok(),
+                    "should be valid: {:?}",
+                    s
+                );
+            })*
+            $({
+                let s: &<$spec as $crate::SliceSpec>::Inner = $invalid;
+                assert!(
+                    <$spec as $crate::SliceSpec>::validate(s).is_err(),
+                    "should be invalid: {:?}",
+                    s
+                );
+            })*
+        }
+
+        #[test]
+        fn try_from_samples() {
+            $({
+                let s: &<$spec as $crate::SliceSpec>::Inner = $valid;
+                let custom = <&<$spec as $crate::SliceSpec>::Custom as ::core::convert::TryFrom<
+                    &<$spec as $crate::SliceSpec>::Inner,
+                >>::try_from(s)
+                .ok()
+                .unwrap_or_else(|| panic!("should be valid: {:?}", s));
+                assert!(
+                    ::core::ptr::eq(<$spec as $crate::SliceSpec>::as_inner(custom), s),
+                    "should refer to the same slice: {:?}",
+                    s
+                );
+            })*
+            $({
+                let s: &<$spec as $crate::SliceSpec>::Inner = $invalid;
+                assert!(
+                    <&<$spec as $crate::SliceSpec>::Custom as ::core::convert::TryFrom<
+                        &<$spec as $crate::SliceSpec>::Inner,
+                    >>::try_from(s)
+                    .is_err(),
+                    "should be invalid: {:?}",
+                    s
+                );
+            })*
+        }
+
+        #[test]
+        fn cmp_symmetry_samples() {
+            let samples: &[(
+                &<$spec as $crate::SliceSpec>::Inner,
+                &<$spec as $crate::SliceSpec>::Custom,
+            )] = &[$({
+                let s: &<$spec as $crate::SliceSpec>::Inner = $valid;
+                let custom = <&<$spec as $crate::SliceSpec>::Custom as ::core::convert::TryFrom<
+                    &<$spec as $crate::SliceSpec>::Inner,
+                >>::try_from(s)
+                .ok()
+                .unwrap_or_else(|| panic!("should be valid: {:?}", s));
+                (s, custom)
+            }),*];
+            for &(lhs_inner, lhs) in samples {
+                assert!(lhs == lhs, "should be reflexive: {:?}", lhs_inner);
+                for &(rhs_inner, rhs) in samples {
+                    assert_eq!(
+                        lhs == rhs,
+                        rhs == lhs,
+                        "should be symmetric: {:?} and {:?}",
+                        lhs_inner,
+                        rhs_inner
+                    );
+                }
+            }
+        }
+
+    };
+    (
+        @owned; $spec:ty, $owned:ty; valid=[$($valid:expr),*]; invalid=[$($invalid:expr),*];
+    ) => {
+        #[test]
+        fn owned_roundtrip_samples() {
+            $({
+                let s: &<$spec as $crate::SliceSpec>::Inner = $valid;
+                let inner = <<$owned as $crate::OwnedSliceSpec>::Inner as ::core::convert::From<
+                    &<$owned as $crate::OwnedSliceSpec>::SliceInner,
+                >>::from(s);
+                let owned = <<$owned as $crate::OwnedSliceSpec>::Custom as ::core::convert::TryFrom<
+                    <$owned as $crate::OwnedSliceSpec>::Inner,
+                >>::try_from(inner)
+                .ok()
+                .unwrap_or_else(|| panic!("should be valid: {:?}", s));
+                assert!(
+                    <$owned as $crate::OwnedSliceSpec>::as_slice_inner(&owned) == s,
+                    "should keep the content: {:?}",
+                    s
+                );
+                let inner = <$owned as $crate::OwnedSliceSpec>::into_inner(owned);
+                assert!(
+                    <$owned as $crate::OwnedSliceSpec>::inner_as_slice_inner(&inner) == s,
+                    "should keep the content: {:?}",
+                    s
+                );
+            })*
+            $({
+                let s: &<$spec as $crate::SliceSpec>::Inner = $invalid;
+                let inner = <<$owned as $crate::OwnedSliceSpec>::Inner as ::core::convert::From<
+                    &<$owned as $crate::OwnedSliceSpec>::SliceInner,
+                >>::from(s);
+                assert!(
+                    <<$owned as $crate::OwnedSliceSpec>::Custom as ::core::convert::TryFrom<
+                        <$owned as $crate::OwnedSliceSpec>::Inner,
+                    >>::try_from(inner)
+                    .is_err(),
+                    "should be invalid: {:?}",
+                    s
+                );
+            })*
+        }
+    };
+}
@@ -0,0 +1,42 @@
+//! Macros for `ufmt` crate integration.
+
+/// Implements `ufmt::uDisplay` or `ufmt::uDebug` for the given custom type.
+///
+/// This is used internally by `impl_std_traits_for{,_owned}_slice!` macros.
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_ufmt {
+    (
+        ({$core:ident, $alloc:ident $(, $lt:tt $(: $bound:path)?)*}, $custom:ty, $inner:ty);
+        $trait:ident;
+        |$this:ident| $as_inner:expr
+    ) => {
+        impl<$($lt $(: $bound)?),*> $crate::__private::ufmt::$trait for $custom
+        where
+            $inner: $crate::__private::ufmt::$trait,
+        {
+            #[inline]
+            fn fmt<W>(
+                &self,
+                f: &mut $crate::__private::ufmt::Formatter<'_, W>,
+            ) -> $core::result::Result<(), W::Error>
+            where
+                W: $crate::__private::ufmt::uWrite + ?Sized,
+            {
+                let $this = self;
+                <$inner as $crate::__private::ufmt::$trait>::fmt($as_inner, f)
+            }
+        }
+    };
+}
+
+/// Emits a compile error, because `ufmt` feature is disabled.
+#[cfg(not(feature = "ufmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_ufmt {
+    ($($rest:tt)*) => {
+        compile_error!("`ufmt` targets require `ufmt` feature of `validated-slice` crate");
+    };
+}
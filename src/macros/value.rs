@@ -0,0 +1,889 @@
+//! Macros for custom sized value types.
+
+/// Implements std traits for the given custom value type.
+///
+/// To implement `PartialEq` and `PartialOrd`, use [`impl_cmp_for_value!`] macro.
+///
+/// # Usage
+///
+/// ## Examples
+///
+/// ```
+/// /// Non-zero TCP/UDP port number.
+/// #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// pub struct Port(u16);
+///
+/// /// Spec for `Port` type.
+/// enum PortSpec {}
+///
+/// impl validated_slice::ValueSpec for PortSpec {
+///     // Custom value type.
+///     type Custom = Port;
+///     // Backend type of `Port`.
+///     type Inner = u16;
+///     // Validation error type.
+///     type Error = PortError;
+///
+///     /* ... and methods. */
+/// #     fn validate(v: &Self::Inner) -> Result<(), Self::Error> {
+/// #         if *v == 0 {
+/// #             return Err(PortError::Zero);
+/// #         }
+/// #         Ok(())
+/// #     }
+/// #     fn as_inner(v: &Self::Custom) -> &Self::Inner {
+/// #         &v.0
+/// #     }
+/// #     unsafe fn from_inner_unchecked(v: Self::Inner) -> Self::Custom {
+/// #         Port(v)
+/// #     }
+/// #     fn into_inner(v: Self::Custom) -> Self::Inner {
+/// #         v.0
+/// #     }
+/// }
+/// # #[derive(Debug)]
+/// # pub enum PortError {
+/// #     Zero,
+/// #     Parse(std::num::ParseIntError),
+/// # }
+/// # impl From<std::num::ParseIntError> for PortError {
+/// #     fn from(e: std::num::ParseIntError) -> Self {
+/// #         PortError::Parse(e)
+/// #     }
+/// # }
+///
+/// validated_slice::impl_std_traits_for_value! {
+///     // `Std` is omissible.
+///     Std {
+///         // Module identifier of `core` crate.
+///         // Default is `std`.
+///         core: core,
+///         // Module identifier of `alloc` crate.
+///         // Default is `std`.
+///         alloc: alloc,
+///     };
+///     Spec {
+///         spec: PortSpec,
+///         custom: Port,
+///         inner: u16,
+///         error: PortError,
+///     };
+///     { AsRef<{Inner}> };
+///     { From<{Custom}> for {Inner} };
+///     { TryFrom<{Inner}> };
+///     { Debug };
+///     { Display };
+///     { FromStr };
+///     /* ... and more traits you want! */
+/// }
+/// ```
+///
+/// The syntax of `Std` part is same as [`impl_std_traits_for_slice!`] macro.
+///
+/// `custom`, `inner`, and `error` fields can be omitted as `Spec { spec: PortSpec };`, and then
+/// they are resolved through the associated types of [`ValueSpec`] trait.
+///
+/// ## Type names
+///
+/// As type name, you can use `{Custom}` and `{Inner}` instead of a real type name.
+/// They are replaced to the specified custom and inner types.
+///
+/// ## Supported trait impls
+///
+/// **NOTE**: To implemente `PartialEq` and `PartialOrd`, use `impl_cmp_for_value!` macro.
+///
+/// Each trait impl is specified by `{ TraitName<TyParams> for TyImplTarget };` format.
+/// `<TyParams>` part and `for TyImplTarget` part is optional.
+///
+/// Default impl target is `{Custom}`.
+/// Explicit `for {Custom}` is also accepted, and it is same as omitting `for TyImplTarget` part.
+///
+/// Supported trait impls are:
+///
+/// * `std::convert`
+///     + `{ AsRef<{Inner}> };`
+///     + `{ AsRef<any_ty> };`
+///     + `{ From<{Custom}> for {Inner} };`
+///     + `{ TryFrom<{Inner}> };`
+///     + `{ TryFrom<&{Inner}> };`
+///         - This requires `{Inner}: Clone`.
+/// * `std::default`
+///     + `{ Default };`
+///         - The default value of `{Inner}` is validated, and `default()` panics if it is invalid.
+/// * `std::fmt`
+///     + `{ Debug };`
+///     + `{ Debug(name = "Name") };`
+///         - Formats as a tuple struct `Name(..)` with the `{Inner}` as its field.
+///     + `{ Debug(with = path::to::fn) };`
+///         - The function should have the type `fn(&{Inner}, &mut Formatter<'_>) -> fmt::Result`.
+///     + `{ Display };`
+///     + `{ Display(with = path::to::fn) };`
+///         - The function should have the type `fn(&{Inner}, &mut Formatter<'_>) -> fmt::Result`.
+///     + `{ LowerHex };`, `{ UpperHex };`, `{ Binary };`, `{ Octal };`
+///         - These are delegated to `{Inner}`.
+/// * `std::hash`
+///     + `{ Hash };`
+///     + `{ Hash(with = path::to::fn) };`
+///         - The function should have the type `fn<H: Hasher>(&{Inner}, &mut H)`.
+///         - Use this together with custom comparison (`base: Custom`), so that `Hash` stays
+///           consistent with `PartialEq`.
+/// * `std::ops`
+///     + `{ Deref<Target = {Inner}> };`
+/// * `std::str`
+///     + `{ FromStr };`
+///         - This requires `{Inner}: FromStr` and `{Error}: From<<{Inner} as FromStr>::Err>`.
+///
+/// ## Checks
+///
+/// Additional checks can be enabled by `Check { .. };` right after `Spec { .. };`.
+///
+/// * `Trusted`: Requires the spec to implement [`TrustedSpec`].
+///
+/// ```ignore
+/// validated_slice::impl_std_traits_for_value! {
+///     Spec { /* ... */ };
+///     Check { Trusted };
+///     /* ... */
+/// }
+/// ```
+///
+/// [`impl_cmp_for_value!`]: macro.impl_cmp_for_value.html
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+/// [`TrustedSpec`]: trait.TrustedSpec.html
+/// [`ValueSpec`]: trait.ValueSpec.html
+#[macro_export]
+macro_rules! impl_std_traits_for_value {
+    (
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty,
+        };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $(
+            $crate::__with_default_std! {
+                $crate::impl_std_traits_for_value;
+                head=[@impl;];
+                lifetimes=[];
+                args=[$spec, $custom, $inner, $error];
+                rest=[$($rest)*];
+            }
+        )*
+    };
+
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            error: $error:ty,
+        };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $(
+            $crate::impl_std_traits_for_value! {
+                @impl; ({$core, $alloc}, $spec, $custom, $inner, $error);
+                rest=[$($rest)*];
+            }
+        )*
+    };
+
+    // Spec fields inferred from the spec type.
+    (
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::ValueSpec>::Custom,
+                inner: <$spec as $crate::ValueSpec>::Inner,
+                error: <$spec as $crate::ValueSpec>::Error,
+            };
+            $($rest)*
+        }
+    };
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            Std {
+                core: $core,
+                alloc: $alloc,
+            };
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::ValueSpec>::Custom,
+                inner: <$spec as $crate::ValueSpec>::Inner,
+                error: <$spec as $crate::ValueSpec>::Error,
+            };
+            $($rest)*
+        }
+    };
+
+    // Checks.
+    (
+        $(
+            Std {
+                core: $core:ident,
+                alloc: $alloc:ident,
+            };
+        )?
+        Spec $spec_fields:tt;
+        Check { $($check:ident),+ $(,)? };
+        $({$($rest:tt)*});* $(;)?
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            @check; [$($check),+]; $spec_fields; [$({$($rest)*})*];
+        }
+        $crate::impl_std_traits_for_value! {
+            $(
+                Std {
+                    core: $core,
+                    alloc: $alloc,
+                };
+            )?
+            Spec $spec_fields;
+            $({$($rest)*});*
+        }
+    };
+    (@check; [$check:ident $(, $checks:ident)*]; $spec_fields:tt; $entries:tt;) => {
+        $crate::impl_std_traits_for_value! { @check[$check]; $spec_fields; $entries; }
+        $crate::impl_std_traits_for_value! { @check; [$($checks),*]; $spec_fields; $entries; }
+    };
+    (@check; []; $spec_fields:tt; $entries:tt;) => {};
+    (@check[Trusted]; { spec: $spec:ty $(, $($_fields:tt)*)? }; $entries:tt;) => {
+        const _: fn() = $crate::__private::assert_trusted_value_spec::<$spec>;
+    };
+    (@check[$check:ident]; $spec_fields:tt; $entries:tt;) => {
+        compile_error!(concat!("Unsupported check: ", stringify!($check)));
+    };
+
+    // std::convert::AsRef
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ AsRef<{Inner}> ];
+    ) => {
+        impl $core::convert::AsRef<$inner> for $custom {
+            #[inline]
+            fn as_ref(&self) -> &$inner {
+                <$spec as $crate::ValueSpec>::as_inner(self)
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ AsRef<$param:ty> ];
+    ) => {
+        impl $core::convert::AsRef<$param> for $custom
+        where
+            $inner: $core::convert::AsRef<$param>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &$param {
+                <$spec as $crate::ValueSpec>::as_inner(self).as_ref()
+            }
+        }
+    };
+
+    // std::convert::From
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ From<{Custom}> for {Inner} ];
+    ) => {
+        impl $core::convert::From<$custom> for $inner {
+            #[inline]
+            fn from(custom: $custom) -> Self {
+                <$spec as $crate::ValueSpec>::into_inner(custom)
+            }
+        }
+    };
+
+    // std::convert::TryFrom
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ TryFrom<{Inner}> ];
+    ) => {
+        impl $core::convert::TryFrom<$inner> for $custom {
+            type Error = $error;
+
+            fn try_from(inner: $inner) -> $core::result::Result<Self, Self::Error> {
+                <$spec as $crate::ValueSpec>::validate(&inner)?;
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    $crate::__from_inner_unchecked!(value; $spec, inner)
+                })
+            }
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ TryFrom<&{Inner}> ];
+    ) => {
+        impl<'a> $core::convert::TryFrom<&'a $inner> for $custom
+        where
+            $inner: $core::clone::Clone,
+        {
+            type Error = $error;
+
+            fn try_from(inner: &'a $inner) -> $core::result::Result<Self, Self::Error> {
+                <$spec as $crate::ValueSpec>::validate(inner)?;
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    $crate::__from_inner_unchecked!(value; $spec, inner.clone())
+                })
+            }
+        }
+    };
+
+    // std::default::Default
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Default ];
+    ) => {
+        impl $core::default::Default for $custom
+        where
+            $inner: $core::default::Default,
+        {
+            fn default() -> Self {
+                let inner = <$inner as $core::default::Default>::default();
+                assert!(
+                    <$spec as $crate::ValueSpec>::validate(&inner).is_ok(),
+                    "Attempt to create invalid data: `Default for {}`",
+                    stringify!($custom)
+                );
+                unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading assert.
+                    $crate::__from_inner_unchecked!(value; $spec, inner)
+                }
+            }
+        }
+    };
+
+    // std::fmt::Debug
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Debug ];
+    ) => {
+        impl $core::fmt::Debug for $custom
+        where
+            $inner: $core::fmt::Debug,
+        {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner = <$spec as $crate::ValueSpec>::as_inner(self);
+                <$inner as $core::fmt::Debug>::fmt(inner, f)
+            }
+        }
+    };
+
+    // std::fmt::Debug with a wrapper name
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Debug(name = $name:expr) ];
+    ) => {
+        impl $core::fmt::Debug for $custom
+        where
+            $inner: $core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner = <$spec as $crate::ValueSpec>::as_inner(self);
+                f.debug_tuple($name).field(&inner).finish()
+            }
+        }
+    };
+
+    // std::fmt::Debug with a custom formatting function
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Debug(with = $fmt:path) ];
+    ) => {
+        impl $core::fmt::Debug for $custom {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner: &$inner = <$spec as $crate::ValueSpec>::as_inner(self);
+                $fmt(inner, f)
+            }
+        }
+    };
+
+    // std::fmt::{LowerHex, UpperHex, Binary, Octal}
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ LowerHex ];
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            @impl [fmt]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ LowerHex ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ UpperHex ];
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            @impl [fmt]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ UpperHex ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Binary ];
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            @impl [fmt]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ Binary ];
+        }
+    };
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Octal ];
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            @impl [fmt]; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            rest=[ Octal ];
+        }
+    };
+    (
+        @impl [fmt]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $trait:ident ];
+    ) => {
+        impl $core::fmt::$trait for $custom
+        where
+            $inner: $core::fmt::$trait,
+        {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner = <$spec as $crate::ValueSpec>::as_inner(self);
+                <$inner as $core::fmt::$trait>::fmt(inner, f)
+            }
+        }
+    };
+
+    // std::fmt::Display
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Display ];
+    ) => {
+        impl $core::fmt::Display for $custom
+        where
+            $inner: $core::fmt::Display,
+        {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner = <$spec as $crate::ValueSpec>::as_inner(self);
+                <$inner as $core::fmt::Display>::fmt(inner, f)
+            }
+        }
+    };
+
+    // std::fmt::Display with a custom formatting function
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Display(with = $fmt:path) ];
+    ) => {
+        impl $core::fmt::Display for $custom {
+            #[inline]
+            fn fmt(&self, f: &mut $core::fmt::Formatter<'_>) -> $core::fmt::Result {
+                let inner: &$inner = <$spec as $crate::ValueSpec>::as_inner(self);
+                $fmt(inner, f)
+            }
+        }
+    };
+
+    // std::hash::Hash
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Hash ];
+    ) => {
+        impl $core::hash::Hash for $custom
+        where
+            $inner: $core::hash::Hash,
+        {
+            #[inline]
+            fn hash<H: $core::hash::Hasher>(&self, state: &mut H) {
+                let inner = <$spec as $crate::ValueSpec>::as_inner(self);
+                <$inner as $core::hash::Hash>::hash(inner, state)
+            }
+        }
+    };
+
+    // std::hash::Hash with a custom hash function
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Hash(with = $hash:path) ];
+    ) => {
+        impl $core::hash::Hash for $custom {
+            #[inline]
+            fn hash<H: $core::hash::Hasher>(&self, state: &mut H) {
+                let inner: &$inner = <$spec as $crate::ValueSpec>::as_inner(self);
+                $hash(inner, state)
+            }
+        }
+    };
+
+    // std::ops::Deref
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ Deref<Target = {Inner}> ];
+    ) => {
+        impl $core::ops::Deref for $custom {
+            type Target = $inner;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                <$spec as $crate::ValueSpec>::as_inner(self)
+            }
+        }
+    };
+
+    // std::str::FromStr
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ FromStr ];
+    ) => {
+        impl $core::str::FromStr for $custom
+        where
+            $inner: $core::str::FromStr,
+            $error: $core::convert::From<<$inner as $core::str::FromStr>::Err>,
+        {
+            type Err = $error;
+
+            fn from_str(s: &str) -> $core::result::Result<Self, Self::Err> {
+                let inner = <$inner as $core::str::FromStr>::from_str(s)?;
+                <$spec as $crate::ValueSpec>::validate(&inner)?;
+                Ok(unsafe {
+                    // This is safe only when all of the conditions below are met:
+                    //
+                    // * `$spec::validate(s)` returns `Ok(())`.
+                    //     + This is ensured by the leading `validate()?` call.
+                    $crate::__from_inner_unchecked!(value; $spec, inner)
+                })
+            }
+        }
+    };
+
+    // Explicit `for {Custom}`, which is same as the default impl target.
+    (
+        @impl; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $error:ty);
+        rest=[ $($rest:tt)* ];
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            @strip_for_custom; ({$core, $alloc}, $spec, $custom, $inner, $error);
+            target=[]; rest=[$($rest)*];
+        }
+    };
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[for {Custom}];
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            @impl; $params;
+            rest=[$($target)*];
+        }
+    };
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[$first:tt $($rest:tt)*];
+    ) => {
+        $crate::impl_std_traits_for_value! {
+            @strip_for_custom; $params;
+            target=[$($target)* $first]; rest=[$($rest)*];
+        }
+    };
+
+    // Fallback.
+    (
+        @strip_for_custom; $params:tt;
+        target=[$($target:tt)*]; rest=[];
+    ) => {
+        compile_error!(concat!("Unsupported target: ", stringify!($($target)*)));
+    };
+}
+
+/// Implements `PartialEq` and `PartialOrd` for the given custom value type.
+///
+/// # Usage
+///
+/// ## Examples
+///
+/// ```ignore
+/// validated_slice::impl_cmp_for_value! {
+///     // `Std` is omissible.
+///     Std {
+///         // Module identifier of `core` crate.
+///         // Default is `std`.
+///         core: core,
+///         // Module identifier of `alloc` crate.
+///         // Default is `std`.
+///         alloc: alloc,
+///     };
+///     Spec {
+///         spec: PortSpec,
+///         custom: Port,
+///         inner: u16,
+///         base: Inner,
+///     };
+///     Cmp { PartialEq, PartialOrd };
+///     { ({Custom}), ({Inner}), rev };
+///     { ({Custom}), (&{Inner}), rev };
+/// }
+/// ```
+///
+/// The syntax is same as [`impl_cmp_for_slice!`] macro, except that `Cow<ty>` is not supported.
+/// `custom` and `inner` fields can be omitted in the same way.
+///
+/// ## Supported types
+///
+/// * `{Custom}`
+/// * `&{Custom}`
+/// * `{Inner}`
+/// * `&{Inner}`
+/// * ... and arbitrary types
+///
+/// [`impl_cmp_for_slice!`]: macro.impl_cmp_for_slice.html
+#[macro_export]
+macro_rules! impl_cmp_for_value {
+    (
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            base: $base:ident,
+        };
+        Cmp { $($cmp_targets:ident),* };
+        $($rest:tt)*
+    ) => {
+        $crate::__with_default_std! {
+            $crate::impl_cmp_for_value;
+            head=[@full;];
+            std_block;
+            Spec {
+                spec: $spec,
+                custom: $custom,
+                inner: $inner,
+                base: $base,
+            };
+            Cmp { $($cmp_targets),* };
+            $($rest)*
+        }
+    };
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            base: $base:ident,
+        };
+        Cmp { $($cmp_targets:ident),* };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_cmp_for_value! {
+            @full;
+            Std {
+                core: $core,
+                alloc: $alloc,
+            };
+            Spec {
+                spec: $spec,
+                custom: $custom,
+                inner: $inner,
+                base: $base,
+            };
+            Cmp { $($cmp_targets),* };
+            $($rest)*
+        }
+    };
+
+    // Spec fields inferred from the spec type.
+    (
+        Spec {
+            spec: $spec:ty,
+            base: $base:ident $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_cmp_for_value! {
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::ValueSpec>::Custom,
+                inner: <$spec as $crate::ValueSpec>::Inner,
+                base: $base,
+            };
+            $($rest)*
+        }
+    };
+    (
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            base: $base:ident $(,)?
+        };
+        $($rest:tt)*
+    ) => {
+        $crate::impl_cmp_for_value! {
+            Std {
+                core: $core,
+                alloc: $alloc,
+            };
+            Spec {
+                spec: $spec,
+                custom: <$spec as $crate::ValueSpec>::Custom,
+                inner: <$spec as $crate::ValueSpec>::Inner,
+                base: $base,
+            };
+            $($rest)*
+        }
+    };
+
+    (
+        @full;
+        Std {
+            core: $core:ident,
+            alloc: $alloc:ident,
+        };
+        Spec {
+            spec: $spec:ty,
+            custom: $custom:ty,
+            inner: $inner:ty,
+            base: $base:ident,
+        };
+        Cmp $cmps:tt;
+        $({ ($($lhs:tt)*), ($($rhs:tt)*) $(, $($opt:ident),*)? });* $(;)?
+    ) => {
+        $(
+            $crate::impl_cmp_for_value! {
+                @pair$cmps; ({$core, $alloc}, $spec, $custom, $inner, $base);
+                { ($($lhs)*), ($($rhs)*) $(, $($opt),*)? };
+            }
+        )*
+    };
+
+    (
+        @pair{ $($cmp:ident),* }; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $base:ident);
+        $pair:tt;
+    ) => {
+        $(
+            $crate::impl_cmp_for_value! {
+                @impl[$cmp]; ({$core, $alloc}, $spec, $custom, $inner, $base);
+                $pair;
+            }
+        )*
+    };
+
+    (
+        @impl[$cmp:ident]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $base:ident);
+        { ($($lhs:tt)*), ($($rhs:tt)*), rev };
+    ) => {
+        $crate::impl_cmp_for_value! {
+            @impl[$cmp]; ({$core, $alloc}, $spec, $custom, $inner, $base);
+            { ($($lhs)*), ($($rhs)*) };
+        }
+        $crate::impl_cmp_for_value! {
+            @impl[$cmp]; ({$core, $alloc}, $spec, $custom, $inner, $base);
+            { ($($rhs)*), ($($lhs)*) };
+        }
+    };
+    (
+        @impl[PartialEq]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $base:ident);
+        { ($($lhs:tt)*), ($($rhs:tt)*) };
+    ) => {
+        impl $core::cmp::PartialEq<
+            $crate::impl_cmp_for_value!(@type; ($custom, $inner); { $($rhs)* })
+        > for $crate::impl_cmp_for_value!(@type; ($custom, $inner); { $($lhs)* })
+        {
+            #[inline]
+            fn eq(&self, other: &$crate::impl_cmp_for_value!(@type; ($custom, $inner); { $($rhs)* })) -> bool {
+                $crate::impl_cmp_for_slice!(@cmp_fn[PartialEq]; ($spec, $custom, $inner, $base))(
+                    $crate::impl_cmp_for_value!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($lhs)* }; self),
+                    $crate::impl_cmp_for_value!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($rhs)* }; other),
+                )
+            }
+        }
+    };
+    (
+        @impl[PartialOrd]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty, $base:ident);
+        { ($($lhs:tt)*), ($($rhs:tt)*) };
+    ) => {
+        impl $core::cmp::PartialOrd<
+            $crate::impl_cmp_for_value!(@type; ($custom, $inner); { $($rhs)* })
+        > for $crate::impl_cmp_for_value!(@type; ($custom, $inner); { $($lhs)* })
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &$crate::impl_cmp_for_value!(@type; ($custom, $inner); { $($rhs)* }))
+                -> $core::option::Option<$core::cmp::Ordering>
+            {
+                $crate::impl_cmp_for_slice!(@cmp_fn[PartialOrd]; ($spec, $custom, $inner, $base))(
+                    $crate::impl_cmp_for_value!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($lhs)* }; self),
+                    $crate::impl_cmp_for_value!(@expr[$base]; ({$core, $alloc}, $spec, $custom, $inner); { $($rhs)* }; other),
+                )
+            }
+        }
+    };
+
+    (@type; ($custom:ty, $inner:ty); { {Custom} }) => { $custom };
+    (@type; ($custom:ty, $inner:ty); { &{Custom} }) => { &$custom };
+    (@type; ($custom:ty, $inner:ty); { {Inner} }) => { $inner };
+    (@type; ($custom:ty, $inner:ty); { &{Inner} }) => { &$inner };
+    (@type; ($custom:ty, $inner:ty); { $ty:ty }) => { $ty };
+
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { {Custom} }; $expr:expr) => {
+        <$spec as $crate::ValueSpec>::as_inner($expr)
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { &{Custom} }; $expr:expr) => {
+        <$spec as $crate::ValueSpec>::as_inner(*$expr)
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { {Inner} }; $expr:expr) => {
+        $expr
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { &{Inner} }; $expr:expr) => {
+        *$expr
+    };
+    (@expr[Inner]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { $ty:ty }; $expr:expr) => {
+        $core::convert::AsRef::<$inner>::as_ref($expr)
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { {Custom} }; $expr:expr) => {
+        $expr
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { &{Custom} }; $expr:expr) => {
+        *$expr
+    };
+    (@expr[Custom]; ({$core:ident, $alloc:ident}, $spec:ty, $custom:ty, $inner:ty); { $ty:ty }; $expr:expr) => {
+        $core::convert::AsRef::<$custom>::as_ref($expr)
+    };
+
+    ($($rest:tt)*) => {
+        compile_error!(stringify!($($rest)*));
+    };
+}
@@ -0,0 +1,227 @@
+//! Parser-backed validation.
+
+use core::error;
+use core::fmt;
+use core::ops::Range;
+
+use crate::{SliceLen, SpannedError};
+
+/// A trait for parser errors which know the position of the failure.
+///
+/// This is used by [`validate_by_parser`] and [`validate_by_complete_parser`] to map the parser
+/// errors into [`ParseError`].
+///
+/// With `nom` feature, this is implemented for `nom::error::Error<&I>` and `nom::Err<E>`.
+///
+/// [`ParseError`]: struct.ParseError.html
+/// [`validate_by_parser`]: fn.validate_by_parser.html
+/// [`validate_by_complete_parser`]: fn.validate_by_complete_parser.html
+pub trait ParseErrorPosition<I: ?Sized> {
+    /// Returns the position of the failure in the whole input.
+    fn position(&self, input: &I) -> usize;
+}
+
+/// The kind of [`ParseError`].
+///
+/// [`ParseError`]: struct.ParseError.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind<E> {
+    /// The parser failed.
+    Parser(E),
+    /// The parser succeeded, but did not consume the whole input.
+    TrailingInput,
+}
+
+/// Validation error of parser-backed specs.
+///
+/// This has the position of the failure as `valid_up_to`, as the errors defined with
+/// [`impl_validation_error!`].
+///
+/// [`impl_validation_error!`]: macro.impl_validation_error.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseError<E> {
+    /// Position of the failure.
+    valid_up_to: usize,
+    /// Error kind.
+    kind: ParseErrorKind<E>,
+}
+
+impl<E> ParseError<E> {
+    /// Creates a new error.
+    #[inline]
+    pub fn new(valid_up_to: usize, kind: ParseErrorKind<E>) -> Self {
+        Self { valid_up_to, kind }
+    }
+
+    /// Returns the length of the valid prefix of the input.
+    #[inline]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the error kind.
+    #[inline]
+    pub fn kind(&self) -> &ParseErrorKind<E> {
+        &self.kind
+    }
+
+    /// Returns the error kind.
+    #[inline]
+    pub fn into_kind(self) -> ParseErrorKind<E> {
+        self.kind
+    }
+
+    /// Maps the parser error by the given function.
+    ///
+    /// This is useful to convert the parser errors borrowing the input into owned ones, since
+    /// validation errors of specs cannot borrow the input.
+    #[inline]
+    pub fn map<F, U>(self, f: F) -> ParseError<U>
+    where
+        F: FnOnce(E) -> U,
+    {
+        let kind = match self.kind {
+            ParseErrorKind::Parser(e) => ParseErrorKind::Parser(f(e)),
+            ParseErrorKind::TrailingInput => ParseErrorKind::TrailingInput,
+        };
+        ParseError::new(self.valid_up_to, kind)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Parser(e) => write!(f, "{} at position {}", e, self.valid_up_to),
+            ParseErrorKind::TrailingInput => {
+                write!(
+                    f,
+                    "unexpected trailing input at position {}",
+                    self.valid_up_to
+                )
+            }
+        }
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for ParseError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::Parser(e) => Some(e),
+            ParseErrorKind::TrailingInput => None,
+        }
+    }
+}
+
+impl<E> SpannedError for ParseError<E> {
+    #[inline]
+    fn span(&self) -> Range<usize> {
+        self.valid_up_to..self.valid_up_to
+    }
+
+    #[inline]
+    fn found(&self) -> Option<&str> {
+        match self.kind {
+            ParseErrorKind::Parser(_) => None,
+            ParseErrorKind::TrailingInput => Some("trailing input"),
+        }
+    }
+}
+
+/// Validates the input by a parser which returns the remaining input (such as `nom` parsers).
+///
+/// The parser should consume the whole input.
+/// If the parser returns the non-empty remaining input, [`ParseErrorKind::TrailingInput`] error
+/// is returned.
+///
+/// # Examples
+///
+/// ```
+/// use validated_slice::{validate_by_parser, ParseErrorKind, ParseErrorPosition};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct DigitExpected<'a>(&'a str);
+///
+/// impl ParseErrorPosition<str> for DigitExpected<'_> {
+///     fn position(&self, input: &str) -> usize {
+///         input.len() - self.0.len()
+///     }
+/// }
+///
+/// fn digits(s: &str) -> Result<(&str, &str), DigitExpected<'_>> {
+///     match s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()) {
+///         0 => Err(DigitExpected(s)),
+///         len => Ok((&s[len..], &s[..len])),
+///     }
+/// }
+///
+/// assert!(validate_by_parser("42", digits).is_ok());
+///
+/// let e = validate_by_parser("42a", digits).unwrap_err();
+/// assert_eq!(e.valid_up_to(), 2);
+/// assert_eq!(e.kind(), &ParseErrorKind::TrailingInput);
+///
+/// let e = validate_by_parser("a", digits).unwrap_err();
+/// assert_eq!(e.valid_up_to(), 0);
+/// assert_eq!(e.kind(), &ParseErrorKind::Parser(DigitExpected("a")));
+/// ```
+///
+/// [`ParseErrorKind::TrailingInput`]: enum.ParseErrorKind.html#variant.TrailingInput
+pub fn validate_by_parser<'a, I, O, E, P>(input: &'a I, parser: P) -> Result<(), ParseError<E>>
+where
+    I: ?Sized + SliceLen,
+    E: ParseErrorPosition<I>,
+    P: FnOnce(&'a I) -> Result<(&'a I, O), E>,
+{
+    match parser(input) {
+        Ok((rest, _)) if rest.slice_len() == 0 => Ok(()),
+        Ok((rest, _)) => Err(ParseError::new(
+            input.slice_len() - rest.slice_len(),
+            ParseErrorKind::TrailingInput,
+        )),
+        Err(e) => Err(ParseError::new(
+            e.position(input),
+            ParseErrorKind::Parser(e),
+        )),
+    }
+}
+
+/// Validates the input by a parser which consumes the whole input (such as `chumsky` parsers).
+///
+/// The parser should fail if the whole input is not consumed.
+pub fn validate_by_complete_parser<'a, I, O, E, P>(
+    input: &'a I,
+    parser: P,
+) -> Result<(), ParseError<E>>
+where
+    I: ?Sized,
+    E: ParseErrorPosition<I>,
+    P: FnOnce(&'a I) -> Result<O, E>,
+{
+    match parser(input) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ParseError::new(
+            e.position(input),
+            ParseErrorKind::Parser(e),
+        )),
+    }
+}
+
+#[cfg(feature = "nom")]
+impl<I: ?Sized + SliceLen> ParseErrorPosition<I> for nom::error::Error<&I> {
+    #[inline]
+    fn position(&self, input: &I) -> usize {
+        // `self.input` is the remaining input at the failure.
+        input.slice_len().saturating_sub(self.input.slice_len())
+    }
+}
+
+#[cfg(feature = "nom")]
+impl<I: ?Sized + SliceLen, E: ParseErrorPosition<I>> ParseErrorPosition<I> for nom::Err<E> {
+    #[inline]
+    fn position(&self, input: &I) -> usize {
+        match self {
+            nom::Err::Incomplete(_) => input.slice_len(),
+            nom::Err::Error(e) | nom::Err::Failure(e) => e.position(input),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Regex-defined specs.
+
+use core::error;
+use core::fmt;
+
+/// Validation error of the specs defined by [`regex_spec!`].
+///
+/// [`regex_spec!`]: macro.regex_spec.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegexMismatchError {
+    /// Byte position up to which the input is matched.
+    valid_up_to: usize,
+}
+
+impl RegexMismatchError {
+    /// Returns the byte position up to which the input is matched.
+    ///
+    /// This is the end of the match starting at the beginning of the input, or 0 if there is no
+    /// such match.
+    /// Note that this is always 0 for the patterns with the end anchor (such as `^foo$`), since
+    /// such patterns never match partially.
+    #[inline]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl fmt::Display for RegexMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the string does not match the pattern (matched up to {})",
+            self.valid_up_to
+        )
+    }
+}
+
+impl error::Error for RegexMismatchError {}
+
+/// Validator for the specs defined by [`regex_spec!`].
+///
+/// This is used internally by the generated codes.
+///
+/// [`regex_spec!`]: macro.regex_spec.html
+#[doc(hidden)]
+#[derive(Debug, Clone)]
+pub struct RegexValidator {
+    /// Regex anchored at both ends.
+    whole: regex::Regex,
+    /// Regex anchored at the beginning.
+    prefix: regex::Regex,
+}
+
+impl RegexValidator {
+    /// Compiles the pattern.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is invalid.
+    pub fn new(pattern: &str) -> Self {
+        let compile = |pattern: &str| {
+            regex::Regex::new(pattern).unwrap_or_else(|e| panic!("invalid regex pattern: {}", e))
+        };
+        Self {
+            whole: compile(&format!("^(?:{})$", pattern)),
+            prefix: compile(&format!("^(?:{})", pattern)),
+        }
+    }
+
+    /// Validates the string.
+    ///
+    /// The pattern should match the whole string.
+    pub fn validate(&self, s: &str) -> Result<(), RegexMismatchError> {
+        if self.whole.is_match(s) {
+            return Ok(());
+        }
+        let valid_up_to = self.prefix.find(s).map_or(0, |m| m.end());
+        Err(RegexMismatchError { valid_up_to })
+    }
+}
@@ -0,0 +1,393 @@
+//! Search on inner slices.
+
+use core::ops::{Index, Range, RangeFrom, RangeTo};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::SliceLen;
+
+/// A trait for inner slice types which support substring search.
+///
+/// This is used by the [`Needle`] impls for the references to the slices, so that the slices can
+/// be used as the needles of the search methods generated by [`impl_methods_for_slice!`].
+///
+/// This is implemented for `str` and `[T]` where `T: PartialEq`.
+///
+/// # Safety
+///
+/// The slices returned by [`strip_prefix_slice`][`SearchSlice::strip_prefix_slice`] and
+/// [`strip_suffix_slice`][`SearchSlice::strip_suffix_slice`] should be sub-slices of `self`.
+/// The generated methods rely on this, and create the custom slices from the results without
+/// validation when the spec is closed under sub-slicing.
+///
+/// [`Needle`]: trait.Needle.html
+/// [`SearchSlice::strip_prefix_slice`]: trait.SearchSlice.html#tymethod.strip_prefix_slice
+/// [`SearchSlice::strip_suffix_slice`]: trait.SearchSlice.html#tymethod.strip_suffix_slice
+///
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+pub unsafe trait SearchSlice {
+    /// Returns `true` if the slice contains the given sub-slice.
+    #[inline]
+    fn contains_slice(&self, needle: &Self) -> bool {
+        self.find_slice(needle).is_some()
+    }
+
+    /// Returns `true` if the slice starts with the given sub-slice.
+    fn starts_with_slice(&self, needle: &Self) -> bool;
+
+    /// Returns `true` if the slice ends with the given sub-slice.
+    fn ends_with_slice(&self, needle: &Self) -> bool;
+
+    /// Returns the index of the first occurrence of the given sub-slice.
+    ///
+    /// For `str`, the index is a byte position.
+    fn find_slice(&self, needle: &Self) -> Option<usize>;
+
+    /// Returns the sub-slice with the given prefix removed.
+    ///
+    /// Returns `None` if the slice does not start with the prefix.
+    /// The returned slice should be a sub-slice of `self`.
+    fn strip_prefix_slice<'a>(&'a self, prefix: &Self) -> Option<&'a Self>;
+
+    /// Returns the sub-slice with the given suffix removed.
+    ///
+    /// Returns `None` if the slice does not end with the suffix.
+    /// The returned slice should be a sub-slice of `self`.
+    fn strip_suffix_slice<'a>(&'a self, suffix: &Self) -> Option<&'a Self>;
+}
+
+// `str::strip_prefix` and `str::strip_suffix` return sub-slices of `self`.
+unsafe impl SearchSlice for str {
+    #[inline]
+    fn contains_slice(&self, needle: &Self) -> bool {
+        self.contains(needle)
+    }
+
+    #[inline]
+    fn starts_with_slice(&self, needle: &Self) -> bool {
+        self.starts_with(needle)
+    }
+
+    #[inline]
+    fn ends_with_slice(&self, needle: &Self) -> bool {
+        self.ends_with(needle)
+    }
+
+    #[inline]
+    fn find_slice(&self, needle: &Self) -> Option<usize> {
+        self.find(needle)
+    }
+
+    #[inline]
+    fn strip_prefix_slice<'a>(&'a self, prefix: &Self) -> Option<&'a Self> {
+        self.strip_prefix(prefix)
+    }
+
+    #[inline]
+    fn strip_suffix_slice<'a>(&'a self, suffix: &Self) -> Option<&'a Self> {
+        self.strip_suffix(suffix)
+    }
+}
+
+// `<[T]>::strip_prefix` and `<[T]>::strip_suffix` return sub-slices of `self`.
+unsafe impl<T: PartialEq> SearchSlice for [T] {
+    #[inline]
+    fn starts_with_slice(&self, needle: &Self) -> bool {
+        self.starts_with(needle)
+    }
+
+    #[inline]
+    fn ends_with_slice(&self, needle: &Self) -> bool {
+        self.ends_with(needle)
+    }
+
+    fn find_slice(&self, needle: &Self) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        self.windows(needle.len())
+            .position(|window| window == needle)
+    }
+
+    #[inline]
+    fn strip_prefix_slice<'a>(&'a self, prefix: &Self) -> Option<&'a Self> {
+        self.strip_prefix(prefix)
+    }
+
+    #[inline]
+    fn strip_suffix_slice<'a>(&'a self, suffix: &Self) -> Option<&'a Self> {
+        self.strip_suffix(suffix)
+    }
+}
+
+/// A trait for needles of the search on the haystack `H`.
+///
+/// This is a small alternative to the unstable `core::str::pattern::Pattern`, and is accepted by
+/// the search methods generated by [`impl_methods_for_slice!`], such as `{ fn contains }`,
+/// `{ fn find }`, and `{ fn strip_prefix }`.
+///
+/// The matches are reported as ranges and lengths, and the generated methods slice the haystack
+/// by themselves. So a wrong implementation can make the generated methods return wrong results
+/// or panic, but cannot make them create invalid custom slices.
+///
+/// This is implemented for:
+///
+/// * `&H` where `H: SearchSlice + SliceLen`, such as `&str` and `&[T]`,
+/// * `&String`, `&Vec<T>`, and `&[T; N]`,
+/// * `char` for `str` haystacks,
+/// * `F: FnMut(char) -> bool` for `str` haystacks, which matches a character, and
+/// * `F: FnMut(&T) -> bool` for `[T]` haystacks, which matches an element.
+///
+/// `&{Custom}` can be a needle by `{ Needle<{Inner}> for &{Custom} };` of
+/// [`impl_std_traits_for_slice!`].
+///
+/// # Examples
+///
+/// ```
+/// use validated_slice::Needle;
+///
+/// assert_eq!(Needle::find_in(&mut '.', "foo.bar"), Some(3..4));
+/// assert_eq!(Needle::find_in(&mut "bar", "foo.bar"), Some(4..7));
+/// assert_eq!(
+///     Needle::find_in(&mut |c: char| c.is_ascii_punctuation(), "foo.bar"),
+///     Some(3..4)
+/// );
+/// assert_eq!(Needle::match_prefix_len(&mut &[1, 2], &[1, 2, 3][..]), Some(2));
+/// assert_eq!(Needle::match_suffix_len(&mut 'é', "café"), Some(2));
+/// ```
+///
+/// [`impl_methods_for_slice!`]: macro.impl_methods_for_slice.html
+/// [`impl_std_traits_for_slice!`]: macro.impl_std_traits_for_slice.html
+pub trait Needle<H: ?Sized> {
+    /// Returns the range of the first match in the haystack.
+    ///
+    /// For `str`, the range is in bytes.
+    fn find_in(&mut self, haystack: &H) -> Option<Range<usize>>;
+
+    /// Returns the length of the match at the start of the haystack.
+    ///
+    /// Returns `None` if the haystack does not start with a match.
+    /// For `str`, the length is in bytes.
+    fn match_prefix_len(&mut self, haystack: &H) -> Option<usize>;
+
+    /// Returns the length of the match at the end of the haystack.
+    ///
+    /// Returns `None` if the haystack does not end with a match.
+    /// For `str`, the length is in bytes.
+    fn match_suffix_len(&mut self, haystack: &H) -> Option<usize>;
+
+    /// Returns `true` if the haystack contains a match.
+    #[inline]
+    fn is_contained_in(&mut self, haystack: &H) -> bool {
+        self.find_in(haystack).is_some()
+    }
+
+    /// Returns `true` if the haystack starts with a match.
+    #[inline]
+    fn is_prefix_of(&mut self, haystack: &H) -> bool {
+        self.match_prefix_len(haystack).is_some()
+    }
+
+    /// Returns `true` if the haystack ends with a match.
+    #[inline]
+    fn is_suffix_of(&mut self, haystack: &H) -> bool {
+        self.match_suffix_len(haystack).is_some()
+    }
+}
+
+impl<H: ?Sized + SearchSlice + SliceLen> Needle<H> for &H {
+    #[inline]
+    fn find_in(&mut self, haystack: &H) -> Option<Range<usize>> {
+        let start = haystack.find_slice(self)?;
+        Some(start..(start + self.slice_len()))
+    }
+
+    #[inline]
+    fn match_prefix_len(&mut self, haystack: &H) -> Option<usize> {
+        if haystack.starts_with_slice(self) {
+            Some(self.slice_len())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn match_suffix_len(&mut self, haystack: &H) -> Option<usize> {
+        if haystack.ends_with_slice(self) {
+            Some(self.slice_len())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn is_contained_in(&mut self, haystack: &H) -> bool {
+        haystack.contains_slice(self)
+    }
+
+    #[inline]
+    fn is_prefix_of(&mut self, haystack: &H) -> bool {
+        haystack.starts_with_slice(self)
+    }
+
+    #[inline]
+    fn is_suffix_of(&mut self, haystack: &H) -> bool {
+        haystack.ends_with_slice(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Needle<str> for &String {
+    #[inline]
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        self.as_str().find_in(haystack)
+    }
+
+    #[inline]
+    fn match_prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().match_prefix_len(haystack)
+    }
+
+    #[inline]
+    fn match_suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_str().match_suffix_len(haystack)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: PartialEq> Needle<[T]> for &Vec<T> {
+    #[inline]
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        self.as_slice().find_in(haystack)
+    }
+
+    #[inline]
+    fn match_prefix_len(&mut self, haystack: &[T]) -> Option<usize> {
+        self.as_slice().match_prefix_len(haystack)
+    }
+
+    #[inline]
+    fn match_suffix_len(&mut self, haystack: &[T]) -> Option<usize> {
+        self.as_slice().match_suffix_len(haystack)
+    }
+}
+
+impl<T: PartialEq, const N: usize> Needle<[T]> for &[T; N] {
+    #[inline]
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        (&self[..]).find_in(haystack)
+    }
+
+    #[inline]
+    fn match_prefix_len(&mut self, haystack: &[T]) -> Option<usize> {
+        (&self[..]).match_prefix_len(haystack)
+    }
+
+    #[inline]
+    fn match_suffix_len(&mut self, haystack: &[T]) -> Option<usize> {
+        (&self[..]).match_suffix_len(haystack)
+    }
+}
+
+impl Needle<str> for char {
+    #[inline]
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        let start = haystack.find(*self)?;
+        Some(start..(start + self.len_utf8()))
+    }
+
+    #[inline]
+    fn match_prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        if haystack.starts_with(*self) {
+            Some(self.len_utf8())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn match_suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        if haystack.ends_with(*self) {
+            Some(self.len_utf8())
+        } else {
+            None
+        }
+    }
+}
+
+impl<F: FnMut(char) -> bool> Needle<str> for F {
+    #[inline]
+    fn find_in(&mut self, haystack: &str) -> Option<Range<usize>> {
+        let (start, c) = haystack.char_indices().find(|&(_, c)| self(c))?;
+        Some(start..(start + c.len_utf8()))
+    }
+
+    #[inline]
+    fn match_prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack
+            .chars()
+            .next()
+            .filter(|&c| self(c))
+            .map(char::len_utf8)
+    }
+
+    #[inline]
+    fn match_suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack
+            .chars()
+            .next_back()
+            .filter(|&c| self(c))
+            .map(char::len_utf8)
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool> Needle<[T]> for F {
+    #[inline]
+    fn find_in(&mut self, haystack: &[T]) -> Option<Range<usize>> {
+        let start = haystack.iter().position(self)?;
+        Some(start..(start + 1))
+    }
+
+    #[inline]
+    fn match_prefix_len(&mut self, haystack: &[T]) -> Option<usize> {
+        haystack.first().filter(|&elem| self(elem)).map(|_| 1)
+    }
+
+    #[inline]
+    fn match_suffix_len(&mut self, haystack: &[T]) -> Option<usize> {
+        haystack.last().filter(|&elem| self(elem)).map(|_| 1)
+    }
+}
+
+/// Returns the haystack with the prefix of the given length removed.
+///
+/// # Panics
+///
+/// Panics if `len` is out of range, or is not on a character boundary of `str`.
+#[inline]
+pub fn strip_prefix_len<H>(haystack: &H, len: usize) -> &H
+where
+    H: ?Sized + Index<RangeFrom<usize>, Output = H>,
+{
+    &haystack[len..]
+}
+
+/// Returns the haystack with the suffix of the given length removed.
+///
+/// # Panics
+///
+/// Panics if `len` is out of range, or is not on a character boundary of `str`.
+#[inline]
+pub fn strip_suffix_len<H>(haystack: &H, len: usize) -> &H
+where
+    H: ?Sized + SliceLen + Index<RangeTo<usize>, Output = H>,
+{
+    let end = haystack
+        .slice_len()
+        .checked_sub(len)
+        .expect("the suffix should not be longer than the haystack");
+    &haystack[..end]
+}
@@ -0,0 +1,434 @@
+//! Adapters for `#[serde(with = "..")]` attributes.
+//!
+//! These modules allow the custom types to be (de)serialized without implementing `Serialize` and
+//! `Deserialize` for the custom types themselves.
+//!
+//! # Examples
+//!
+//! ```
+//! # /// ASCII string.
+//! # #[derive(Debug, Clone, PartialEq, Eq)]
+//! # pub struct AsciiString(String);
+//! #
+//! # impl std::convert::TryFrom<String> for AsciiString {
+//! #     type Error = &'static str;
+//! #
+//! #     fn try_from(s: String) -> Result<Self, Self::Error> {
+//! #         if s.is_ascii() {
+//! #             Ok(Self(s))
+//! #         } else {
+//! #             Err("non-ASCII character found")
+//! #         }
+//! #     }
+//! # }
+//! #
+//! # impl AsRef<str> for AsciiString {
+//! #     fn as_ref(&self) -> &str {
+//! #         &self.0
+//! #     }
+//! # }
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct User {
+//!     #[serde(with = "validated_slice::serde_adapter::try_from_string")]
+//!     name: AsciiString,
+//! }
+//!
+//! let user: User = serde_json::from_str(r#"{"name":"alice"}"#)?;
+//! assert_eq!(user.name.as_ref(), "alice");
+//! assert_eq!(serde_json::to_string(&user)?, r#"{"name":"alice"}"#);
+//!
+//! assert!(serde_json::from_str::<User>(r#"{"name":"é"}"#).is_err());
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, DeserializeSeed, Deserializer};
+
+use crate::{OwnedSliceSpec, SliceSpec};
+
+/// Serializes the value by `Display`, and deserializes the value by `FromStr`.
+///
+/// This is useful for the custom types with `Display` and `FromStr` targets.
+pub mod display_from_str {
+    use core::fmt;
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::ser::Serializer;
+
+    /// Serializes the value by `Display`.
+    #[inline]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes the value by `FromStr`.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FromStrVisitor(PhantomData))
+    }
+
+    /// Visitor to deserialize the value by `FromStr`.
+    struct FromStrVisitor<T>(PhantomData<fn() -> T>);
+
+    impl<T> Visitor<'_> for FromStrVisitor<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(E::custom)
+        }
+    }
+}
+
+/// Serializes the value as a string by `AsRef<str>`, and deserializes the value by
+/// `TryFrom<String>`.
+///
+/// This is useful for the custom owned string types with `TryFrom<{Inner}>` target.
+pub mod try_from_string {
+    use alloc::string::String;
+    use core::convert::TryFrom;
+    use core::fmt;
+
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::Serializer;
+
+    /// Serializes the value as a string.
+    #[inline]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + AsRef<str>,
+        S: Serializer,
+    {
+        serializer.serialize_str(value.as_ref())
+    }
+
+    /// Deserializes a string and converts it by `TryFrom<String>`.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<String>,
+        T::Error: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        T::try_from(s).map_err(D::Error::custom)
+    }
+}
+
+/// A trait for types which sanitize the input before validation on lenient deserialization.
+///
+/// This is used by [`sanitized_string`] module.
+/// Implementations can normalize the input (such as trimming whitespaces or NFC normalization),
+/// or replace the invalid parts lossily, instead of letting the deserialization fail.
+///
+/// [`sanitized_string`]: sanitized_string/index.html
+pub trait Sanitize {
+    /// Sanitizes the string.
+    fn sanitize(s: String) -> String;
+}
+
+/// Serializes the value as a string by `AsRef<str>`, and deserializes the value by
+/// `TryFrom<String>` after sanitizing the string by [`Sanitize`].
+///
+/// This is useful to accept the slightly malformed input instead of rejecting it.
+/// The value is still validated after the sanitization, so the deserialization fails if the
+/// sanitized string is invalid.
+///
+/// # Examples
+///
+/// ```
+/// # /// ASCII string.
+/// # #[derive(Debug, Clone, PartialEq, Eq)]
+/// # pub struct AsciiString(String);
+/// #
+/// # impl std::convert::TryFrom<String> for AsciiString {
+/// #     type Error = &'static str;
+/// #
+/// #     fn try_from(s: String) -> Result<Self, Self::Error> {
+/// #         if s.is_ascii() {
+/// #             Ok(Self(s))
+/// #         } else {
+/// #             Err("non-ASCII character found")
+/// #         }
+/// #     }
+/// # }
+/// #
+/// # impl AsRef<str> for AsciiString {
+/// #     fn as_ref(&self) -> &str {
+/// #         &self.0
+/// #     }
+/// # }
+/// use serde::Deserialize;
+/// use validated_slice::serde_adapter::Sanitize;
+///
+/// impl Sanitize for AsciiString {
+///     fn sanitize(s: String) -> String {
+///         s.trim()
+///             .chars()
+///             .map(|c| if c.is_ascii() { c } else { '?' })
+///             .collect()
+///     }
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct User {
+///     #[serde(with = "validated_slice::serde_adapter::sanitized_string")]
+///     name: AsciiString,
+/// }
+///
+/// let user: User = serde_json::from_str(r#"{"name":" caf\u00e9 "}"#)?;
+/// assert_eq!(user.name.as_ref(), "caf?");
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+///
+/// [`Sanitize`]: ../trait.Sanitize.html
+pub mod sanitized_string {
+    use alloc::string::String;
+    use core::convert::TryFrom;
+    use core::fmt;
+
+    use serde::de::{Deserialize, Deserializer, Error};
+
+    use super::Sanitize;
+
+    pub use super::try_from_string::serialize;
+
+    /// Deserializes a string, sanitizes it, and converts it by `TryFrom<String>`.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Sanitize + TryFrom<String>,
+        T::Error: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        T::try_from(T::sanitize(s)).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes the value as bytes by `AsRef<[u8]>`, and deserializes the value by
+/// `TryFrom<Vec<u8>>`.
+///
+/// This is useful for the custom owned byte vector types with `TryFrom<{Inner}>` target.
+pub mod try_from_bytes {
+    use alloc::vec::Vec;
+    use core::convert::TryFrom;
+    use core::fmt;
+
+    use serde::de::{self, Deserializer, SeqAccess, Visitor};
+    use serde::ser::Serializer;
+
+    /// Serializes the value as bytes.
+    #[inline]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized + AsRef<[u8]>,
+        S: Serializer,
+    {
+        serializer.serialize_bytes(value.as_ref())
+    }
+
+    /// Deserializes bytes and converts them by `TryFrom<Vec<u8>>`.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<Vec<u8>>,
+        T::Error: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        let bytes = deserializer.deserialize_byte_buf(ByteBufVisitor)?;
+        T::try_from(bytes).map_err(de::Error::custom)
+    }
+
+    /// Visitor to deserialize a byte buffer.
+    struct ByteBufVisitor;
+
+    impl<'de> Visitor<'de> for ByteBufVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("bytes")
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(b) = seq.next_element()? {
+                bytes.push(b);
+            }
+            Ok(bytes)
+        }
+    }
+}
+
+/// Seed to deserialize the owned custom value from a trusted source without validation.
+///
+/// This deserializes `{Inner}` of the spec `S`, and converts it into `{Custom}` by
+/// `OwnedSliceSpec::from_inner_unchecked`.
+/// This is useful to skip the cost of revalidation when the data is known to be valid, for
+/// example when it is loaded from the cache written by the same program.
+///
+/// With `paranoid` feature, the value is validated anyway, and the deserialization panics if it
+/// is invalid.
+///
+/// # Examples
+///
+/// ```
+/// # use validated_slice::OwnedSliceSpec;
+/// # /// ASCII string.
+/// # #[derive(Debug)]
+/// # pub struct AsciiString(String);
+/// #
+/// # #[repr(transparent)]
+/// # pub struct AsciiStr(str);
+/// #
+/// # enum AsciiStrSpec {}
+/// #
+/// # impl validated_slice::SliceSpec for AsciiStrSpec {
+/// #     type Custom = AsciiStr;
+/// #     type Inner = str;
+/// #     type Error = ();
+/// #
+/// #     fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+/// #         if s.is_ascii() { Ok(()) } else { Err(()) }
+/// #     }
+/// #
+/// #     validated_slice::impl_slice_spec_methods! {
+/// #         field=0;
+/// #         methods=[
+/// #             as_inner,
+/// #             as_inner_mut,
+/// #             from_inner_unchecked,
+/// #             from_inner_unchecked_mut,
+/// #         ];
+/// #     }
+/// # }
+/// #
+/// # enum AsciiStringSpec {}
+/// #
+/// # impl OwnedSliceSpec for AsciiStringSpec {
+/// #     type Custom = AsciiString;
+/// #     type Inner = String;
+/// #     type Error = ();
+/// #     type SliceSpec = AsciiStrSpec;
+/// #     type SliceCustom = AsciiStr;
+/// #     type SliceInner = str;
+/// #     type SliceError = ();
+/// #
+/// #     fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+/// #         e
+/// #     }
+/// #
+/// #     fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+/// #         &s.0
+/// #     }
+/// #
+/// #     fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+/// #         s
+/// #     }
+/// #
+/// #     unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+/// #         AsciiString(s)
+/// #     }
+/// #
+/// #     fn into_inner(s: Self::Custom) -> Self::Inner {
+/// #         s.0
+/// #     }
+/// # }
+/// use serde::de::DeserializeSeed;
+/// use validated_slice::serde_adapter::TrustedSeed;
+///
+/// // The cache is written by this program from validated values.
+/// let cache = r#""alice""#;
+/// let seed = unsafe { TrustedSeed::<AsciiStringSpec>::new() };
+/// let name = seed.deserialize(&mut serde_json::Deserializer::from_str(cache))?;
+/// assert_eq!(name.0, "alice");
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+pub struct TrustedSeed<S: ?Sized>(PhantomData<fn() -> S>);
+
+impl<S: ?Sized + OwnedSliceSpec> TrustedSeed<S> {
+    /// Creates a new seed.
+    ///
+    /// # Safety
+    ///
+    /// The deserializer used with the seed should only produce the inner values which are valid
+    /// for `S`, i.e. `<S::SliceSpec as SliceSpec>::validate` should return `Ok(())` for the
+    /// inner slice of every deserialized value.
+    ///
+    /// Users and generated codes may rely on the validity of custom slices, so deserializing an
+    /// invalid value causes undefined behavior.
+    #[inline]
+    pub unsafe fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S: ?Sized> Clone for TrustedSeed<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: ?Sized> Copy for TrustedSeed<S> {}
+
+impl<S: ?Sized> fmt::Debug for TrustedSeed<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TrustedSeed")
+    }
+}
+
+impl<'de, S> DeserializeSeed<'de> for TrustedSeed<S>
+where
+    S: ?Sized + OwnedSliceSpec,
+    S::SliceSpec: SliceSpec<Inner = S::SliceInner>,
+    S::Inner: Deserialize<'de>,
+{
+    type Value = S::Custom;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let inner = S::Inner::deserialize(deserializer)?;
+        Ok(unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `S::SliceSpec::validate(s)` returns `Ok(())`.
+            //     + This is ensured by the caller of `TrustedSeed::new()`.
+            // * Safety condition for `<S as OwnedSliceSpec>` is satisfied.
+            crate::__from_inner_unchecked!(owned; S, inner)
+        })
+    }
+}
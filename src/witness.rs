@@ -0,0 +1,183 @@
+//! Validation witness.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{OwnedSliceSpec, SliceSpec};
+
+/// A proof that the borrowed inner slice is valid for the spec `S`.
+///
+/// This is returned by [`Validated::new`] and [`SliceSpec::check`], and can be converted into the
+/// custom slice types without validating the same slice again.
+/// This is useful to create several values (such as `&Custom`, `Box<Custom>`, and the owned
+/// custom type) from the same inner slice.
+///
+/// The witness borrows the validated slice, so it cannot be used as a proof for any other slice.
+/// It has the same size as `&'a S::Inner`.
+///
+/// # Examples
+///
+/// ```
+/// # /// ASCII string slice.
+/// # #[repr(transparent)]
+/// # #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// # pub struct AsciiStr(str);
+/// #
+/// # /// ASCII string validation error.
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// # pub struct AsciiError {
+/// #     /// Byte position of the first invalid byte.
+/// #     valid_up_to: usize,
+/// # }
+/// #
+/// # enum AsciiStrSpec {}
+/// #
+/// # impl validated_slice::SliceSpec for AsciiStrSpec {
+/// #     type Custom = AsciiStr;
+/// #     type Inner = str;
+/// #     type Error = AsciiError;
+/// #
+/// #     fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+/// #         match s.as_bytes().iter().position(|b| !b.is_ascii()) {
+/// #             Some(pos) => Err(AsciiError { valid_up_to: pos }),
+/// #             None => Ok(()),
+/// #         }
+/// #     }
+/// #
+/// #     validated_slice::impl_slice_spec_methods! {
+/// #         field=0;
+/// #         methods=[
+/// #             as_inner,
+/// #             as_inner_mut,
+/// #             from_inner_unchecked,
+/// #             from_inner_unchecked_mut,
+/// #         ];
+/// #     }
+/// # }
+/// #
+/// # validated_slice::impl_std_traits_for_slice! {
+/// #     Spec {
+/// #         spec: AsciiStrSpec,
+/// #         custom: AsciiStr,
+/// #         inner: str,
+/// #         error: AsciiError,
+/// #     };
+/// #     { From<&{Custom}> for Box<{Custom}> };
+/// # }
+/// use validated_slice::Validated;
+///
+/// let valid = Validated::<AsciiStrSpec>::new("foo")?;
+/// let borrowed: &AsciiStr = valid.into_custom();
+/// // No validation happens here.
+/// let boxed: Box<AsciiStr> = valid.convert();
+/// assert_eq!(borrowed, &*boxed);
+///
+/// assert!(Validated::<AsciiStrSpec>::new("\u{e9}").is_err());
+/// # Ok::<_, AsciiError>(())
+/// ```
+///
+/// [`SliceSpec::check`]: trait.SliceSpec.html#method.check
+pub struct Validated<'a, S: ?Sized + SliceSpec> {
+    /// Validated inner slice.
+    inner: &'a S::Inner,
+    /// Spec.
+    _spec: PhantomData<fn() -> S>,
+}
+
+impl<'a, S: ?Sized + SliceSpec> Validated<'a, S> {
+    /// Validates the inner slice and returns the witness.
+    #[inline]
+    pub fn new(s: &'a S::Inner) -> Result<Self, S::Error> {
+        S::validate(s)?;
+        Ok(Self {
+            inner: s,
+            _spec: PhantomData,
+        })
+    }
+
+    /// Creates the witness without any validation.
+    ///
+    /// # Safety
+    ///
+    /// `S::validate(s)` should return `Ok(())`.
+    ///
+    /// Users and generated codes may rely on the validity of the slice.
+    #[inline]
+    pub unsafe fn new_unchecked(s: &'a S::Inner) -> Self {
+        Self {
+            inner: s,
+            _spec: PhantomData,
+        }
+    }
+
+    /// Returns the validated inner slice.
+    #[inline]
+    pub fn as_inner(&self) -> &'a S::Inner {
+        self.inner
+    }
+
+    /// Converts the validated inner slice into the custom slice without validating it again.
+    #[inline]
+    pub fn into_custom(self) -> &'a S::Custom {
+        unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `S::validate(s)` returns `Ok(())`.
+            //     + This is ensured when `self` is created.
+            // * Safety condition for `<S as SliceSpec>` is satisfied.
+            crate::__from_inner_unchecked!(slice; S, self.inner)
+        }
+    }
+
+    /// Converts the custom slice into `T` without validating it again.
+    ///
+    /// This is useful for `Box<Custom>`, `Arc<Custom>`, and `Rc<Custom>`, which implement
+    /// `From<&Custom>`.
+    #[inline]
+    pub fn convert<T>(self) -> T
+    where
+        S::Custom: 'a,
+        T: From<&'a S::Custom>,
+    {
+        T::from(self.into_custom())
+    }
+
+    /// Creates the owned custom slice without validating it again.
+    #[inline]
+    pub fn into_owned<O>(self) -> O::Custom
+    where
+        O: OwnedSliceSpec<SliceSpec = S, SliceInner = S::Inner>,
+        O::Inner: From<&'a S::Inner>,
+    {
+        let inner = O::Inner::from(self.inner);
+        unsafe {
+            // This is safe only when all of the conditions below are met:
+            //
+            // * `<O::SliceSpec as SliceSpec>::validate(O::inner_as_slice_inner(&inner))` returns
+            //   `Ok(())`, as the contract of `OwnedSliceSpec::from_inner_unchecked` requires.
+            //     + `self.inner` is validated by `S` (which is `O::SliceSpec`) when `self` is
+            //       created, and `inner` is converted from it by `From` without changing the
+            //       content.
+            // * Safety condition for `<O as OwnedSliceSpec>` is satisfied.
+            crate::__from_inner_unchecked!(owned; O, inner)
+        }
+    }
+}
+
+impl<S: ?Sized + SliceSpec> Clone for Validated<'_, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: ?Sized + SliceSpec> Copy for Validated<'_, S> {}
+
+impl<S: ?Sized + SliceSpec> fmt::Debug for Validated<'_, S>
+where
+    S::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Validated").field(&self.inner).finish()
+    }
+}
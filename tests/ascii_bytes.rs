@@ -0,0 +1,400 @@
+//! ASCII bytes.
+//!
+//! Validity is defined per element, so new elements are validated individually.
+
+enum AsciiBytesSpec {}
+
+impl validated_slice::SliceSpec for AsciiBytesSpec {
+    type Custom = AsciiBytes;
+    type Inner = [u8];
+    type Error = NonAsciiError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        s.iter()
+            .try_for_each(<Self as validated_slice::ElementSpec>::validate_element)
+    }
+
+    fn validate_all(s: &Self::Inner) -> Vec<Self::Error> {
+        s.iter()
+            .filter_map(|b| <Self as validated_slice::ElementSpec>::validate_element(b).err())
+            .collect()
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+// Any sub-slice of ASCII bytes is also ASCII bytes.
+unsafe impl validated_slice::SliceClosedSpec for AsciiBytesSpec {}
+
+// Reordering and removing ASCII bytes keep them ASCII.
+unsafe impl validated_slice::OrderInsensitiveSpec for AsciiBytesSpec {}
+
+// `validate` checks every element by `validate_element`.
+unsafe impl validated_slice::ElementSpec for AsciiBytesSpec {
+    type Element = u8;
+
+    fn validate_element(elem: &u8) -> Result<(), Self::Error> {
+        if elem.is_ascii() {
+            Ok(())
+        } else {
+            Err(NonAsciiError { byte: *elem })
+        }
+    }
+}
+
+/// Non-ASCII byte error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonAsciiError {
+    /// Non-ASCII byte.
+    byte: u8,
+}
+
+/// ASCII bytes slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiBytes([u8]);
+
+validated_slice::impl_std_traits_for_slice! {
+    @list(const ASCII_BYTES_IMPLS);
+    Spec {
+        spec: AsciiBytesSpec,
+        custom: AsciiBytes,
+        inner: [u8],
+        error: NonAsciiError,
+    };
+    { AsRef<[u8]> };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Display(hex) };
+    { LowerHex(bytes) };
+    { UpperHex(bytes) };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: AsciiBytesSpec,
+        custom: AsciiBytes,
+        inner: [u8],
+        error: NonAsciiError,
+    };
+    { pub fn subslice };
+    { pub fn try_from_reporting };
+    { pub fn as_raw(name = as_slice) };
+    { fn constructors(vis = pub(crate)) };
+}
+
+enum AsciiByteVecSpec {}
+
+impl validated_slice::OwnedSliceSpec for AsciiByteVecSpec {
+    type Custom = AsciiByteVec;
+    type Inner = Vec<u8>;
+    type Error = NonAsciiError;
+    type SliceSpec = AsciiBytesSpec;
+    type SliceCustom = AsciiBytes;
+    type SliceInner = [u8];
+    type SliceError = NonAsciiError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        AsciiByteVec(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// ASCII bytes vector.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiByteVec(Vec<u8>);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    @list(pub(crate) const ASCII_BYTE_VEC_IMPLS);
+    Spec {
+        spec: AsciiByteVecSpec,
+        custom: AsciiByteVec,
+        inner: Vec<u8>,
+        error: NonAsciiError,
+        slice_custom: AsciiBytes,
+        slice_inner: [u8],
+        slice_error: NonAsciiError,
+    };
+    { AsRef<[u8]> };
+    { TryFrom<{Inner}> };
+    { LowerHex };
+    { UpperHex };
+    { !Hash };
+}
+
+validated_slice::impl_methods_for_owned_slice! {
+    Spec {
+        spec: AsciiByteVecSpec,
+        custom: AsciiByteVec,
+        inner: Vec<u8>,
+        error: NonAsciiError,
+        slice_custom: AsciiBytes,
+        slice_inner: [u8],
+        slice_error: NonAsciiError,
+    };
+    { pub fn try_push };
+    { pub fn try_insert };
+    { pub fn try_extend };
+    { pub fn try_mutate };
+    { pub fn try_from_vec };
+    { pub fn into_inner_vec };
+    { pub fn as_raw(name = as_slice) };
+    { fn constructors(vis = pub(crate)) };
+    { pub fn try_arc_from_owned };
+    { pub fn try_rc_from_owned };
+    { pub fn sort };
+    { pub fn sort_unstable };
+    { pub fn dedup };
+    { pub fn retain };
+}
+
+#[cfg(test)]
+mod ascii_bytes {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn subslice() {
+        let slice = <&AsciiBytes>::try_from(&b"hello"[..]).expect("Should never fail");
+        let sub = slice.subslice(1..3).expect("Should never fail");
+        assert_eq!(&sub.0, b"el");
+        assert!(slice.subslice(4..6).is_none());
+    }
+
+    #[test]
+    fn as_raw() {
+        let slice = <&AsciiBytes>::try_from(&b"foo"[..]).expect("Should never fail");
+        assert_eq!(slice.as_slice(), b"foo");
+    }
+
+    #[test]
+    fn constructors() {
+        use validated_slice::SliceSpec;
+
+        let slice = AsciiBytes::new(b"foo").expect("Should never fail");
+        assert_eq!(&slice.0, b"foo");
+        assert!(AsciiBytes::new(b"\xff").is_err());
+        // The bytes are ASCII.
+        let slice = unsafe { AsciiBytes::new_unchecked(b"bar") };
+        assert_eq!(&slice.0, b"bar");
+        let proof = AsciiBytesSpec::check(b"baz").expect("Should never fail");
+        assert_eq!(&AsciiBytes::from_proof(proof).0, b"baz");
+    }
+
+    #[test]
+    fn hex() {
+        let slice = <&AsciiBytes>::try_from(&b"\x0a:Z"[..]).expect("Should never fail");
+        assert_eq!(slice.to_string(), "0a3a5a");
+        assert_eq!(format!("{:x}", slice), "0a3a5a");
+        assert_eq!(format!("{:X}", slice), "0A3A5A");
+        assert_eq!(format!("{:#x}", slice), "0x0a3a5a");
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(
+            ASCII_BYTES_IMPLS,
+            [
+                "AsRef<[u8]>",
+                "TryFrom<&{Inner}> for &{Custom}",
+                "Display(hex)",
+                "LowerHex(bytes)",
+                "UpperHex(bytes)",
+            ]
+        );
+    }
+
+    #[test]
+    fn try_from_reporting() {
+        let slice = AsciiBytes::try_from_reporting(b"foo").expect("Should never fail");
+        assert_eq!(&slice.0, b"foo");
+        assert_eq!(
+            AsciiBytes::try_from_reporting(b"\x80a\xff").map(|_| ()),
+            Err(vec![
+                NonAsciiError { byte: 0x80 },
+                NonAsciiError { byte: 0xff }
+            ])
+        );
+    }
+}
+
+#[cfg(test)]
+mod ascii_byte_vec {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_push() {
+        let mut v = AsciiByteVec::default();
+        assert_eq!(v.try_push(b'a'), Ok(()));
+        assert_eq!(v.try_push(0xff), Err(NonAsciiError { byte: 0xff }));
+        assert_eq!(v.0, b"a");
+    }
+
+    #[test]
+    fn try_insert() {
+        let mut v = AsciiByteVec::try_from(b"ac".to_vec()).expect("Should never fail");
+        assert_eq!(v.try_insert(1, b'b'), Ok(()));
+        assert_eq!(v.try_insert(0, 0x80), Err(NonAsciiError { byte: 0x80 }));
+        assert_eq!(v.0, b"abc");
+    }
+
+    #[test]
+    fn try_insert_out_of_bounds_keeps_value() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut v = AsciiByteVec::try_from(b"abc".to_vec()).expect("Should never fail");
+        let result = catch_unwind(AssertUnwindSafe(|| v.try_insert(4, b'd')));
+        assert!(result.is_err());
+        assert_eq!(v.0, b"abc");
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut v = AsciiByteVec::default();
+        assert_eq!(v.try_extend(b"foo".iter().copied()), Ok(()));
+        assert_eq!(
+            v.try_extend(vec![b'b', 0xe9, b'r']),
+            Err(NonAsciiError { byte: 0xe9 })
+        );
+        assert_eq!(v.0, b"foob");
+    }
+
+    #[test]
+    fn try_mutate() {
+        let mut v = AsciiByteVec::try_from(b"foo".to_vec()).expect("Should never fail");
+        assert_eq!(v.try_mutate(|inner| inner.pop()), Ok(Some(b'o')));
+        assert_eq!(v.0, b"fo");
+        assert_eq!(
+            v.try_mutate(|inner| inner.extend_from_slice(b"\xffbar")),
+            Err(NonAsciiError { byte: 0xff })
+        );
+        assert_eq!(v.0, b"fo");
+    }
+
+    #[test]
+    fn constructors() {
+        let v = AsciiByteVec::new(b"foo".to_vec()).expect("Should never fail");
+        assert_eq!(v.0, b"foo");
+        assert_eq!(
+            AsciiByteVec::new(b"a\x80".to_vec()),
+            Err(NonAsciiError { byte: 0x80 })
+        );
+        // The bytes are ASCII.
+        let v = unsafe { AsciiByteVec::new_unchecked(b"bar".to_vec()) };
+        assert_eq!(v.0, b"bar");
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(
+            ASCII_BYTE_VEC_IMPLS,
+            ["AsRef<[u8]>", "TryFrom<{Inner}>", "LowerHex", "UpperHex"]
+        );
+    }
+
+    #[test]
+    fn as_raw() {
+        let v = AsciiByteVec::try_from(b"foo".to_vec()).expect("Should never fail");
+        assert_eq!(v.as_slice(), b"foo");
+    }
+
+    #[test]
+    fn hex() {
+        let v = AsciiByteVec::try_from(b"\x0a:Z".to_vec()).expect("Should never fail");
+        assert_eq!(format!("{:x}", v), "0a3a5a");
+        assert_eq!(format!("{:#X}", v), "0x0A3A5A");
+    }
+
+    #[test]
+    fn reorder() {
+        let mut v = AsciiByteVec::try_from(b"banana".to_vec()).expect("Should never fail");
+        v.sort();
+        assert_eq!(v.0, b"aaabnn");
+        v.dedup();
+        assert_eq!(v.0, b"abn");
+        v.retain(|&b| b != b'b');
+        assert_eq!(v.0, b"an");
+        let mut v = AsciiByteVec::try_from(b"cab".to_vec()).expect("Should never fail");
+        v.sort_unstable();
+        assert_eq!(v.0, b"abc");
+    }
+
+    #[test]
+    fn try_smartptr_from_owned() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let arc: Arc<AsciiBytes> =
+            AsciiBytes::try_arc_from_owned(b"foo".to_vec()).expect("Should never fail");
+        assert_eq!(&arc.0, b"foo");
+        let rc: Rc<AsciiBytes> =
+            AsciiBytes::try_rc_from_owned(b"bar".to_vec()).expect("Should never fail");
+        assert_eq!(&rc.0, b"bar");
+        assert_eq!(
+            AsciiBytes::try_arc_from_owned(b"\xffoo".to_vec()),
+            Err(NonAsciiError { byte: 0xff })
+        );
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let vecs = AsciiByteVec::try_from_vec(vec![b"foo".to_vec(), b"bar".to_vec()])
+            .expect("Should never fail");
+        assert_eq!(vecs[1].0, b"bar");
+        assert_eq!(
+            AsciiByteVec::into_inner_vec(vecs),
+            [b"foo".to_vec(), b"bar".to_vec()]
+        );
+
+        let e =
+            AsciiByteVec::try_from_vec(vec![b"foo".to_vec(), b"\xffa".to_vec(), b"\x80".to_vec()])
+                .unwrap_err();
+        assert_eq!(e.index(), 1);
+        assert_eq!(e.error(), &NonAsciiError { byte: 0xff });
+        assert_eq!(e.into_values().len(), 3);
+    }
+}
+
+#[cfg(test)]
+mod ascii_bytes_spec {
+    use super::*;
+
+    validated_slice::spec_tests!(
+        AsciiBytesSpec,
+        valid = [b"", b"foo", b"\x7f"],
+        invalid = [b"\x80", b"foo\xff"],
+        owned = AsciiByteVecSpec,
+    );
+}
@@ -41,7 +41,7 @@ pub struct AsciiError {
 // You can use `#[derive(Debug, PartialEq, PartialOrd)]` here, but in this example they are
 // implemented by macros in `validated_slice`, or implemented manually.
 #[repr(transparent)]
-#[derive(Eq, Ord, Hash)]
+#[derive(Eq)]
 pub struct AsciiStr(str);
 
 impl std::fmt::Debug for AsciiStr {
@@ -57,6 +57,14 @@ validated_slice::impl_std_traits_for_slice! {
         inner: str,
         error: AsciiError,
     };
+    // `Hash` and `Ord` should be specified together with `Borrow`.
+    Check { Borrow };
+    // Borrow<str> for AsciiStr
+    { Borrow<{Inner}> };
+    // Hash for AsciiStr
+    { Hash };
+    // Ord for AsciiStr
+    { Ord };
     // AsRef<[u8]> for AsciiStr
     { AsRef<[u8]> };
     // AsRef<str> for AsciiStr
@@ -75,6 +83,12 @@ validated_slice::impl_std_traits_for_slice! {
     { TryFrom<&{Inner}> for &{Custom} };
     // TryFrom<&'_ mut str> for &'_ mut AsciiStr
     { TryFrom<&mut {Inner}> for &mut {Custom} };
+    // TryFrom<&'_ mut Box<str>> for &'_ mut Box<AsciiStr>
+    { TryFrom<&mut Box<{Inner}>> for &mut Box<{Custom}> };
+    // TryFrom<&'_ [u8]> for &'_ AsciiStr
+    { TryFrom<&[u8]> for &{Custom} };
+    // From<&'_ mut Box<AsciiStr>> for &'_ mut Box<str>
+    { unsafe From<&mut Box<{Custom}>> for &mut Box<{Inner}> };
     // Default for &'_ AsciiStr
     { Default for &{Custom} };
     // Default for &'_ mut AsciiStr
@@ -83,6 +97,8 @@ validated_slice::impl_std_traits_for_slice! {
     { Display };
     // Deref<Target = str> for Custom
     { Deref<Target = {Inner}> };
+    // ErasedValidatedSlice for AsciiStr
+    { ErasedValidatedSlice };
 }
 
 validated_slice::impl_cmp_for_slice! {
@@ -104,6 +120,10 @@ validated_slice::impl_cmp_for_slice! {
     { (&{Custom}), ({Inner}), rev };
     { ({Custom}), (Cow<{Inner}>), rev };
     { (&{Custom}), (Cow<{Inner}>), rev };
+    { ({Custom}), (Arc<{Inner}>), rev };
+    // NOTE: `AsciiBoxStr` comparisons already provide this.
+    //{ ({Custom}), (Box<{Inner}>), rev };
+    { ({Custom}), (Rc<{Inner}>), rev };
     // NOTE: `{Inner}` should be local type to implement this.
     //{ ({Inner}), (Cow<{Custom}>), rev };
     // NOTE: `{Inner}` should be local type to implement this.
@@ -131,11 +151,6 @@ impl validated_slice::OwnedSliceSpec for AsciiBoxStrSpec {
         &s.0
     }
 
-    #[inline]
-    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
-        &mut s.0
-    }
-
     #[inline]
     fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
         s
@@ -152,8 +167,15 @@ impl validated_slice::OwnedSliceSpec for AsciiBoxStrSpec {
     }
 }
 
+impl validated_slice::OwnedSliceMutSpec for AsciiBoxStrSpec {
+    #[inline]
+    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
+        &mut s.0
+    }
+}
+
 /// ASCII string boxed slice.
-#[derive(Default, Clone, Eq, Ord, Hash)]
+#[derive(Default, Clone, Eq)]
 pub struct AsciiBoxStr(Box<str>);
 
 impl From<AsciiString> for AsciiBoxStr {
@@ -215,6 +237,10 @@ validated_slice::impl_std_traits_for_owned_slice! {
     { DerefMut<Target = {SliceCustom}> };
     // FromStr<Err = AsciiError> for AsciiBoxStr
     { FromStr };
+    // Hash for AsciiBoxStr
+    { Hash };
+    // Ord for AsciiBoxStr
+    { Ord };
 }
 
 validated_slice::impl_cmp_for_owned_slice! {
@@ -262,11 +288,6 @@ impl validated_slice::OwnedSliceSpec for AsciiStringSpec {
         &s.0
     }
 
-    #[inline]
-    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
-        &mut s.0
-    }
-
     #[inline]
     fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
         s
@@ -283,8 +304,15 @@ impl validated_slice::OwnedSliceSpec for AsciiStringSpec {
     }
 }
 
+impl validated_slice::OwnedSliceMutSpec for AsciiStringSpec {
+    #[inline]
+    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
+        &mut s.0
+    }
+}
+
 /// ASCII string boxed slice.
-#[derive(Default, Clone, Eq, Ord, Hash)]
+#[derive(Default, Clone, Eq)]
 pub struct AsciiString(String);
 
 impl From<AsciiBoxStr> for AsciiString {
@@ -323,16 +351,28 @@ validated_slice::impl_std_traits_for_owned_slice! {
     { Borrow<{SliceCustom}> };
     // BorrowMut<AsciiStr> for AsciiString
     { BorrowMut<{SliceCustom}> };
+    // ErasedValidatedSlice for AsciiString
+    { ErasedValidatedSlice };
     // ToOwned<Owned = AsciiString> for AsciiStr
     { ToOwned<Owned = {Custom}> for {SliceCustom} };
     // From<&'_ AsciiStr> for AsciiString
     { From<&{SliceCustom}> };
+    // From<Cow<'_, AsciiStr>> for AsciiString
+    { From<Cow<{SliceCustom}>> };
     // From<AsciiString> for String
     { From<{Custom}> for {Inner} };
+    // From<&'_ AsciiString> for String
+    { From<&{Custom}> for {Inner} };
+    // From<&'_ AsciiStr> for String
+    { From<&{SliceCustom}> for {Inner} };
+    // From<&'_ AsciiString> for &'_ AsciiStr
+    { From<&{Custom}> for &{SliceCustom} };
     // TryFrom<&'_ str> for AsciiString
     { TryFrom<&{SliceInner}> };
     // TryFrom<String> for AsciiString
     { TryFrom<{Inner}> };
+    // TryFrom<Vec<u8>> for AsciiString
+    { TryFrom<Vec<u8>> };
     // Default for AsciiString
     // NOTE: Same as `#[derive(Default)]` in this case.
     //{ Default };
@@ -346,6 +386,25 @@ validated_slice::impl_std_traits_for_owned_slice! {
     { DerefMut<Target = {SliceCustom}> };
     // FromStr<Err = AsciiError> for AsciiString
     { FromStr };
+    // Hash for AsciiString
+    { Hash };
+    // Ord for AsciiString
+    { Ord };
+}
+
+validated_slice::impl_methods_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+        custom: AsciiString,
+        inner: String,
+        error: AsciiError,
+        slice_custom: AsciiStr,
+        slice_inner: str,
+        slice_error: AsciiError,
+    };
+    { pub fn try_from_iter };
+    { pub fn as_bytes };
+    { pub fn as_raw(name = as_str) };
 }
 
 validated_slice::impl_cmp_for_owned_slice! {
@@ -372,6 +431,82 @@ validated_slice::impl_cmp_for_owned_slice! {
     { ({Inner}), (&{SliceCustom}), rev };
 }
 
+/// ASCII string which is borrowed or owned.
+pub enum AsciiCowStr<'a> {
+    /// Borrowed string.
+    Borrowed(&'a AsciiStr),
+    /// Owned string.
+    Owned(AsciiString),
+}
+
+validated_slice::impl_std_traits_for_cow_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+        custom: AsciiCowStr,
+        owned_custom: AsciiString,
+        slice_custom: AsciiStr,
+    };
+    // AsRef<str> for AsciiCowStr<'_>
+    { AsRef<str> };
+    // AsRef<AsciiStr> for AsciiCowStr<'_>
+    { AsRef<{SliceCustom}> };
+    // Borrow<AsciiStr> for AsciiCowStr<'_>
+    { Borrow<{SliceCustom}> };
+    // Clone for AsciiCowStr<'_>
+    { Clone };
+    // Eq for AsciiCowStr<'_>
+    { Eq };
+    // Ord for AsciiCowStr<'_>
+    { Ord };
+    // From<&'a AsciiStr> for AsciiCowStr<'a>
+    { From<&{SliceCustom}> };
+    // From<AsciiString> for AsciiCowStr<'_>
+    { From<{OwnedCustom}> };
+    // From<Cow<'a, AsciiStr>> for AsciiCowStr<'a>
+    { From<Cow<{SliceCustom}>> };
+    // From<AsciiCowStr<'a>> for Cow<'a, AsciiStr>
+    { From<{Custom}> for Cow<{SliceCustom}> };
+    // From<AsciiCowStr<'_>> for AsciiString
+    { From<{Custom}> for {OwnedCustom} };
+    // Default for AsciiCowStr<'_>
+    { Default };
+    // Debug for AsciiCowStr<'_>
+    { Debug };
+    // Display for AsciiCowStr<'_>
+    { Display };
+    // Hash for AsciiCowStr<'_>
+    { Hash for {Custom} };
+    // Deref<Target = AsciiStr> for AsciiCowStr<'_>
+    { Deref<Target = {SliceCustom}> };
+}
+
+validated_slice::impl_cmp_for_cow_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+        custom: AsciiCowStr,
+        owned_custom: AsciiString,
+        slice_custom: AsciiStr,
+        slice_inner: str,
+        base: Inner,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), ({OwnedCustom}), rev };
+    { ({Custom}), ({SliceCustom}), rev };
+    { ({Custom}), (&{SliceCustom}), rev };
+    { ({Custom}), (Cow<{SliceCustom}>), rev };
+    { ({Custom}), ({SliceInner}), rev };
+    { ({Custom}), (&{SliceInner}), rev };
+    { ({Custom}), (Cow<{SliceInner}>), rev };
+    { ({Custom}), (String), rev };
+}
+
+validated_slice::assert_auto_traits! {
+    AsciiStr: Send + Sync + Unpin;
+    AsciiBoxStr: Send + Sync + Unpin;
+    AsciiString: Send + Sync + Unpin;
+}
+
 #[cfg(test)]
 mod ascii_str {
     use super::*;
@@ -385,6 +520,28 @@ mod ascii_str {
     {
     }
 
+    #[test]
+    fn borrow_consistency() {
+        use std::borrow::Borrow;
+        use std::collections::hash_map::DefaultHasher;
+        use std::convert::TryFrom;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<T: ?Sized + Hash>(v: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let foo = <&AsciiStr>::try_from("foo").expect("Should never fail");
+        let bar = <&AsciiStr>::try_from("bar").expect("Should never fail");
+        assert_eq!(hash(foo), hash::<str>(foo.borrow()));
+        assert_eq!(
+            foo.cmp(bar),
+            Borrow::<str>::borrow(foo).cmp(Borrow::<str>::borrow(bar))
+        );
+    }
+
     #[test]
     fn partial_eq_custom()
     where
@@ -409,6 +566,12 @@ mod ascii_str {
         for<'a> std::borrow::Cow<'a, str>: PartialEq<AsciiStr>,
         for<'a, 'b> &'b AsciiStr: PartialEq<std::borrow::Cow<'a, str>>,
         for<'a, 'b> std::borrow::Cow<'a, str>: PartialEq<&'b AsciiStr>,
+        AsciiStr: PartialEq<std::sync::Arc<str>>,
+        std::sync::Arc<str>: PartialEq<AsciiStr>,
+        AsciiStr: PartialEq<Box<str>>,
+        Box<str>: PartialEq<AsciiStr>,
+        AsciiStr: PartialEq<std::rc::Rc<str>>,
+        std::rc::Rc<str>: PartialEq<AsciiStr>,
     {
     }
 
@@ -436,6 +599,46 @@ mod ascii_str {
     {
     }
 
+    #[test]
+    fn try_from_bytes() {
+        use std::convert::TryFrom;
+        use validated_slice::FromBytesError;
+
+        let custom = <&AsciiStr>::try_from(&b"foo"[..]).expect("Should never fail");
+        assert_eq!(&custom.0, "foo");
+        assert!(matches!(
+            <&AsciiStr>::try_from(&b"\xff"[..]),
+            Err(FromBytesError::Utf8(_))
+        ));
+        assert_eq!(
+            <&AsciiStr>::try_from("f\u{e9}".as_bytes()),
+            Err(FromBytesError::Spec(AsciiError { valid_up_to: 1 }))
+        );
+    }
+
+    #[test]
+    fn try_from_mut_box() {
+        use std::convert::TryFrom;
+
+        let mut boxed: Box<str> = "foo".into();
+        let custom = <&mut Box<AsciiStr>>::try_from(&mut boxed).expect("Should never fail");
+        *custom = Box::from(<&AsciiStr>::try_from("bar").expect("Should never fail"));
+        assert_eq!(&*boxed, "bar");
+
+        let mut invalid: Box<str> = "\u{e9}".into();
+        assert_eq!(
+            <&mut Box<AsciiStr>>::try_from(&mut invalid).map(|_| ()),
+            Err(AsciiError { valid_up_to: 0 })
+        );
+    }
+
+    #[test]
+    fn from_mut_box()
+    where
+        for<'a> &'a mut Box<str>: From<&'a mut Box<AsciiStr>>,
+    {
+    }
+
     #[test]
     fn default()
     where
@@ -618,6 +821,27 @@ mod ascii_box_str {
 mod ascii_string {
     use super::*;
 
+    #[test]
+    fn erased() {
+        use std::convert::TryFrom;
+        use validated_slice::{ErasedValidatedSlice, Validated};
+
+        let borrowed = <&AsciiStr>::try_from("foo").expect("Should never fail");
+        let owned = AsciiString::try_from("bar").expect("Should never fail");
+        let witness = Validated::<AsciiStrSpec>::new("baz").expect("Should never fail");
+        let values: Vec<&dyn ErasedValidatedSlice> = vec![&borrowed, &owned, &witness];
+        for value in &values {
+            assert!(value.revalidate());
+        }
+        assert_eq!(values[1].as_bytes(), b"bar");
+        assert!(values[0].type_name().ends_with("AsciiStr"));
+        assert!(values[1].type_name().ends_with("AsciiString"));
+        assert_eq!(values[2].type_name(), values[0].type_name());
+
+        let boxed: Box<dyn ErasedValidatedSlice> = Box::new(Box::<AsciiStr>::from(borrowed));
+        assert_eq!(boxed.as_bytes(), b"foo");
+    }
+
     #[test]
     fn as_ref()
     where
@@ -628,6 +852,22 @@ mod ascii_string {
     {
     }
 
+    #[test]
+    fn try_into_slice() {
+        use std::convert::{TryFrom, TryInto};
+
+        fn to_slice<'a, T: TryInto<&'a AsciiStr>>(v: T) -> Option<&'a AsciiStr> {
+            v.try_into().ok()
+        }
+
+        let owned = AsciiString::try_from("foo").expect("Should never fail");
+        let borrowed = to_slice(&owned).expect("Should never fail");
+        assert_eq!(&borrowed.0, "foo");
+        assert_eq!(to_slice(borrowed), Some(borrowed));
+        assert_eq!(to_slice("bar").map(|s| &s.0), Some("bar"));
+        assert_eq!(to_slice("\u{e9}"), None);
+    }
+
     #[test]
     fn borrow()
     where
@@ -712,7 +952,10 @@ mod ascii_string {
     fn from()
     where
         for<'a> AsciiString: From<&'a AsciiStr>,
+        for<'a> AsciiString: From<std::borrow::Cow<'a, AsciiStr>>,
         String: From<AsciiString>,
+        for<'a> String: From<&'a AsciiString>,
+        for<'a> String: From<&'a AsciiStr>,
     {
     }
 
@@ -724,6 +967,32 @@ mod ascii_string {
     {
     }
 
+    #[test]
+    fn as_raw() {
+        use std::convert::TryFrom;
+
+        let custom = AsciiString::try_from("foo").expect("Should never fail");
+        assert_eq!(custom.as_bytes(), b"foo");
+        assert_eq!(custom.as_str(), "foo");
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        use std::convert::TryFrom;
+        use validated_slice::FromBytesError;
+
+        let custom = AsciiString::try_from(b"foo".to_vec()).expect("Should never fail");
+        assert_eq!(custom.0, "foo");
+        match AsciiString::try_from(b"\xff".to_vec()) {
+            Err(FromBytesError::Utf8(e)) => assert_eq!(e.into_bytes(), b"\xff"),
+            v => panic!("Unexpected result: {:?}", v),
+        }
+        assert_eq!(
+            AsciiString::try_from("f\u{e9}".as_bytes().to_vec()),
+            Err(FromBytesError::Spec(AsciiError { valid_up_to: 1 }))
+        );
+    }
+
     #[test]
     fn fmt()
     where
@@ -767,4 +1036,107 @@ mod ascii_string {
         AsciiString: std::str::FromStr<Err = AsciiError>,
     {
     }
+
+    #[test]
+    fn try_from_iter() {
+        let ascii = AsciiString::try_from_iter("text".chars()).expect("Should never fail");
+        assert_eq!(ascii, "text");
+        assert_eq!(
+            AsciiString::try_from_iter("a\u{3042}".chars()),
+            Err(AsciiError { valid_up_to: 1 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod ascii_cow_str {
+    use super::*;
+
+    use std::borrow::Cow;
+
+    #[test]
+    fn as_ref()
+    where
+        for<'a> AsciiCowStr<'a>: AsRef<str>,
+        for<'a> AsciiCowStr<'a>: AsRef<AsciiStr>,
+    {
+    }
+
+    #[test]
+    fn from()
+    where
+        for<'a> AsciiCowStr<'a>: From<&'a AsciiStr>,
+        for<'a> AsciiCowStr<'a>: From<AsciiString>,
+        for<'a> AsciiCowStr<'a>: From<Cow<'a, AsciiStr>>,
+        for<'a> Cow<'a, AsciiStr>: From<AsciiCowStr<'a>>,
+        for<'a> AsciiString: From<AsciiCowStr<'a>>,
+    {
+        use std::convert::TryFrom;
+
+        let borrowed = <&AsciiStr>::try_from("text").expect("Should never fail");
+        let owned = AsciiString::try_from("text").expect("Should never fail");
+        assert_eq!(AsciiString::from(AsciiCowStr::from(borrowed)), owned);
+        assert_eq!(AsciiString::from(AsciiCowStr::from(owned.clone())), owned);
+    }
+
+    #[test]
+    fn partial_eq()
+    where
+        for<'a, 'b> AsciiCowStr<'a>: PartialEq<AsciiCowStr<'b>>,
+        for<'a> AsciiCowStr<'a>: PartialEq<AsciiString>,
+        for<'a> AsciiString: PartialEq<AsciiCowStr<'a>>,
+        for<'a> AsciiCowStr<'a>: PartialEq<AsciiStr>,
+        for<'a, 'b> AsciiCowStr<'a>: PartialEq<&'b AsciiStr>,
+        for<'a, 'b> AsciiCowStr<'a>: PartialEq<Cow<'b, AsciiStr>>,
+        for<'a> AsciiCowStr<'a>: PartialEq<str>,
+        for<'a> str: PartialEq<AsciiCowStr<'a>>,
+        for<'a, 'b> AsciiCowStr<'a>: PartialEq<&'b str>,
+        for<'a, 'b> AsciiCowStr<'a>: PartialEq<Cow<'b, str>>,
+        for<'a> AsciiCowStr<'a>: PartialEq<String>,
+    {
+        use std::convert::TryFrom;
+
+        let borrowed = <&AsciiStr>::try_from("text").expect("Should never fail");
+        let owned = AsciiString::try_from("text").expect("Should never fail");
+        assert!(AsciiCowStr::Borrowed(borrowed) == AsciiCowStr::Owned(owned.clone()));
+        assert!(AsciiCowStr::Owned(owned) == "text");
+        assert!(AsciiCowStr::Borrowed(borrowed) < "texts");
+    }
+
+    #[test]
+    fn fmt() {
+        use std::convert::TryFrom;
+
+        let borrowed = <&AsciiStr>::try_from("text").expect("Should never fail");
+        let cow = AsciiCowStr::Borrowed(borrowed);
+        assert_eq!(format!("{}", cow), "text");
+        assert_eq!(format!("{:?}", cow), format!("{:?}", borrowed));
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::convert::TryFrom;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_value<T: Hash>(v: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let borrowed = <&AsciiStr>::try_from("text").expect("Should never fail");
+        let owned = AsciiString::try_from("text").expect("Should never fail");
+        assert_eq!(
+            hash_value(&AsciiCowStr::Borrowed(borrowed)),
+            hash_value(&AsciiCowStr::Owned(owned))
+        );
+    }
+
+    #[test]
+    fn deref()
+    where
+        for<'a> AsciiCowStr<'a>: std::ops::Deref<Target = AsciiStr>,
+    {
+    }
 }
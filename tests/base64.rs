@@ -0,0 +1,76 @@
+//! Base64 display of byte strings.
+#![cfg(feature = "base64")]
+
+enum TokenSpec {}
+
+impl validated_slice::SliceSpec for TokenSpec {
+    type Custom = Token;
+    type Inner = [u8];
+    type Error = EmptyTokenError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        if s.is_empty() {
+            return Err(EmptyTokenError);
+        }
+        Ok(())
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Empty token error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmptyTokenError;
+
+/// Non-empty binary token.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Token([u8]);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: TokenSpec,
+        custom: Token,
+        inner: [u8],
+        error: EmptyTokenError,
+    };
+    { TryFrom<&{Inner}> for &{Custom} };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: TokenSpec,
+        custom: Token,
+        inner: [u8],
+        error: EmptyTokenError,
+    };
+    { pub fn display_base64 };
+}
+
+#[cfg(test)]
+mod token {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn display_base64() {
+        let token = <&Token>::try_from(&b"\xfb\xffhi"[..]).expect("Should never fail");
+        assert_eq!(token.display_base64().to_string(), "+/9oaQ==");
+        assert_eq!(format!("<{}>", token.display_base64()), "<+/9oaQ==>");
+        assert_eq!(
+            validated_slice::Base64Display::new_url_safe(&token.0).to_string(),
+            "-_9oaQ"
+        );
+    }
+}
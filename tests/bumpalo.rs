@@ -0,0 +1,63 @@
+//! `bumpalo` integration.
+#![cfg(feature = "bumpalo")]
+
+mod common;
+
+use bumpalo::Bump;
+
+use self::common::{AsciiError, AsciiStr, AsciiStrSpec};
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+        custom: AsciiStr,
+        inner: str,
+        error: AsciiError,
+    };
+    { AsRef<str> };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+        custom: AsciiStr,
+        inner: str,
+        error: AsciiError,
+    };
+    { pub fn alloc_validated };
+    { pub fn alloc_in };
+}
+
+#[cfg(test)]
+mod ascii_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    use validated_slice::bumpalo_adapter;
+
+    #[test]
+    fn alloc_validated() {
+        let bump = Bump::new();
+        let s = AsciiStr::alloc_validated(&bump, "foo").expect("Should never fail");
+        assert_eq!(s.as_ref(), "foo");
+        assert_eq!(
+            AsciiStr::alloc_validated(&bump, "caf\u{e9}").map_err(|e| e.valid_up_to()),
+            Err(3)
+        );
+        let s = bumpalo_adapter::alloc_validated::<AsciiStrSpec>(&bump, "bar")
+            .expect("Should never fail");
+        assert_eq!(s.as_ref(), "bar");
+    }
+
+    #[test]
+    fn alloc_in() {
+        let bump = Bump::new();
+        let copied = {
+            let owned = String::from("foo");
+            let s = <&AsciiStr>::try_from(owned.as_str()).expect("Should never fail");
+            s.alloc_in(&bump)
+        };
+        assert_eq!(copied.as_ref(), "foo");
+    }
+}
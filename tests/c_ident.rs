@@ -0,0 +1,315 @@
+//! C identifier.
+//!
+//! Types for `CStr` and `CString` which are valid C identifiers.
+
+use std::ffi::{CStr, CString};
+
+enum CIdentSpec {}
+
+impl validated_slice::SliceSpec for CIdentSpec {
+    type Custom = CIdent;
+    type Inner = CStr;
+    type Error = InvalidIdentError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        let bytes = s.to_bytes();
+        if bytes.is_empty() {
+            return Err(InvalidIdentError { valid_up_to: 0 });
+        }
+        if let Some(pos) = bytes.iter().enumerate().position(|(i, &b)| {
+            !(b == b'_' || b.is_ascii_alphabetic() || (i != 0 && b.is_ascii_digit()))
+        }) {
+            return Err(InvalidIdentError { valid_up_to: pos });
+        }
+        Ok(())
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Invalid C identifier error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidIdentError {
+    /// Byte position of the first invalid byte.
+    valid_up_to: usize,
+}
+
+/// C identifier slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CIdent(CStr);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: CIdentSpec,
+        custom: CIdent,
+        inner: CStr,
+        error: InvalidIdentError,
+    };
+    { AsRef<CStr> };
+    { AsRef<{Custom}> };
+    { From<&{Custom}> for &{Inner} };
+    { From<&{Custom}> for Arc<{Custom}> };
+    { From<&{Custom}> for Box<{Custom}> };
+    { From<&{Custom}> for Rc<{Custom}> };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Debug };
+    { Deref<Target = {Inner}> };
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        spec: CIdentSpec,
+        custom: CIdent,
+        inner: CStr,
+        base: Inner,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), (&{Custom}), rev };
+    { ({Custom}), ({Inner}), rev };
+    { ({Custom}), (&{Inner}), rev };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: CIdentSpec,
+        custom: CIdent,
+        inner: CStr,
+        error: InvalidIdentError,
+    };
+    { pub fn as_ptr };
+    { pub fn from_ptr };
+    { pub fn try_arc_from };
+    { pub fn try_rc_from };
+}
+
+enum CIdentStringSpec {}
+
+impl validated_slice::OwnedSliceSpec for CIdentStringSpec {
+    type Custom = CIdentString;
+    type Inner = CString;
+    type Error = InvalidIdentStringError;
+    type SliceSpec = CIdentSpec;
+    type SliceCustom = CIdent;
+    type SliceInner = CStr;
+    type SliceError = InvalidIdentError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, v: Self::Inner) -> Self::Error {
+        InvalidIdentStringError(e, v)
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        CIdentString(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+// The invalid value is not available for the slice errors.
+impl validated_slice::ConvertSliceErrorSpec for CIdentStringSpec {
+    #[inline]
+    fn convert_slice_error(e: Self::SliceError) -> Self::Error {
+        InvalidIdentStringError(e, CString::default())
+    }
+}
+
+/// Invalid C identifier string error.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidIdentStringError(InvalidIdentError, CString);
+
+/// C identifier string.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CIdentString(CString);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: CIdentStringSpec,
+        custom: CIdentString,
+        inner: CString,
+        error: InvalidIdentStringError,
+        slice_custom: CIdent,
+        slice_inner: CStr,
+        slice_error: InvalidIdentError,
+    };
+    { Borrow<{SliceCustom}> };
+    { ToOwned<Owned = {Custom}> for {SliceCustom} };
+    { AsRef<CStr> };
+    { AsRef<{SliceCustom}> };
+    { From<&{SliceCustom}> };
+    { From<{Custom}> for {Inner} };
+    { TryFrom<&{SliceInner}> };
+    { TryFrom<{Inner}> };
+    { From<{SliceError}> for {Error} };
+    { Deref<Target = {SliceCustom}> };
+}
+
+validated_slice::impl_methods_for_owned_slice! {
+    Spec {
+        spec: CIdentStringSpec,
+        custom: CIdentString,
+        inner: CString,
+        error: InvalidIdentStringError,
+        slice_custom: CIdent,
+        slice_inner: CStr,
+        slice_error: InvalidIdentError,
+    };
+    { pub fn into_raw };
+    { pub fn from_raw };
+}
+
+#[cfg(test)]
+mod c_ident {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    fn cstr(bytes: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(bytes).expect("Should never fail")
+    }
+
+    #[test]
+    fn try_from() {
+        assert!(<&CIdent>::try_from(cstr(b"foo_bar1\0")).is_ok());
+        assert_eq!(
+            <&CIdent>::try_from(cstr(b"1foo\0")),
+            Err(InvalidIdentError { valid_up_to: 0 })
+        );
+        assert_eq!(
+            <&CIdent>::try_from(cstr(b"foo-bar\0")),
+            Err(InvalidIdentError { valid_up_to: 3 })
+        );
+    }
+
+    #[test]
+    fn as_ptr() {
+        let ident = <&CIdent>::try_from(cstr(b"foo\0")).expect("Should never fail");
+        let ptr = ident.as_ptr();
+        assert_eq!(ptr, cstr(b"foo\0").as_ptr().cast::<std::os::raw::c_char>());
+        assert_eq!(ptr, ident.to_bytes_with_nul().as_ptr().cast());
+    }
+
+    #[test]
+    fn from_ptr() {
+        let valid = cstr(b"foo\0");
+        let ident = unsafe { CIdent::from_ptr(valid.as_ptr()) }.expect("Should never fail");
+        assert_eq!(ident, valid);
+
+        let invalid = cstr(b"foo bar\0");
+        assert_eq!(
+            unsafe { CIdent::from_ptr(invalid.as_ptr()) },
+            Err(InvalidIdentError { valid_up_to: 3 })
+        );
+    }
+
+    #[test]
+    fn smart_pointers() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let ident = <&CIdent>::try_from(cstr(b"foo\0")).expect("Should never fail");
+        assert_eq!(&*Box::<CIdent>::from(ident), ident);
+        assert_eq!(&*Arc::<CIdent>::from(ident), ident);
+        assert_eq!(&*Rc::<CIdent>::from(ident), ident);
+    }
+
+    #[test]
+    fn try_smart_pointers_from() {
+        let arc = CIdent::try_arc_from(cstr(b"foo\0")).expect("Should never fail");
+        assert_eq!(&*arc, cstr(b"foo\0"));
+        let rc = CIdent::try_rc_from(cstr(b"foo\0")).expect("Should never fail");
+        assert_eq!(&*rc, cstr(b"foo\0"));
+        assert_eq!(
+            CIdent::try_rc_from(cstr(b"foo bar\0")),
+            Err(InvalidIdentError { valid_up_to: 3 })
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let ident = <&CIdent>::try_from(cstr(b"foo\0")).expect("Should never fail");
+        assert_eq!(format!("{:?}", ident), format!("{:?}", cstr(b"foo\0")));
+    }
+}
+
+#[cfg(test)]
+mod c_ident_string {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        let valid = CString::new("foo").expect("Should never fail");
+        assert!(CIdentString::try_from(valid).is_ok());
+
+        let invalid = CString::new("foo bar").expect("Should never fail");
+        assert_eq!(
+            CIdentString::try_from(invalid.clone()),
+            Err(InvalidIdentStringError(
+                InvalidIdentError { valid_up_to: 3 },
+                invalid
+            ))
+        );
+    }
+
+    #[test]
+    fn from_slice_error() {
+        fn to_owned_ident(s: &CStr) -> Result<CIdentString, InvalidIdentStringError> {
+            Ok(<&CIdent>::try_from(s)?.to_owned())
+        }
+
+        assert_eq!(
+            to_owned_ident(CStr::from_bytes_with_nul(b"1foo\0").expect("Should never fail")),
+            Err(InvalidIdentStringError(
+                InvalidIdentError { valid_up_to: 0 },
+                CString::default()
+            ))
+        );
+    }
+
+    #[test]
+    fn raw_roundtrip() {
+        let ident =
+            CIdentString::try_from(CString::new("foo").expect("Should never fail")).unwrap();
+        let ptr = ident.clone().into_raw();
+        let restored = unsafe { CIdentString::from_raw(ptr) }.expect("Should never fail");
+        assert_eq!(restored, ident);
+    }
+
+    #[test]
+    fn from_raw_invalid() {
+        let ptr = CString::new("1foo").expect("Should never fail").into_raw();
+        assert_eq!(
+            unsafe { CIdentString::from_raw(ptr) },
+            Err(InvalidIdentStringError(
+                InvalidIdentError { valid_up_to: 0 },
+                CString::new("1foo").expect("Should never fail")
+            ))
+        );
+    }
+}
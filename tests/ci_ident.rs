@@ -0,0 +1,308 @@
+//! Case-insensitive ASCII identifier.
+//!
+//! Comparisons are customized, and `Hash` and `Ord` are implemented consistently with them.
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+enum CiIdentSpec {}
+
+// `CiIdent` has `#[repr(transparent)]`, and its only field is `str`.
+unsafe impl validated_slice::TrustedSpec for CiIdentSpec {}
+
+impl validated_slice::SliceSpec for CiIdentSpec {
+    type Custom = CiIdent;
+    type Inner = str;
+    type Error = InvalidIdentError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        match s
+            .bytes()
+            .position(|b| !(b == b'_' || b.is_ascii_alphanumeric()))
+        {
+            Some(pos) => Err(InvalidIdentError { valid_up_to: pos }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Invalid identifier error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidIdentError {
+    /// Byte position of the first invalid byte.
+    valid_up_to: usize,
+}
+
+/// Hashes the string in ASCII case-insensitive way.
+fn hash_ignore_ascii_case<H: Hasher>(s: &str, state: &mut H) {
+    s.bytes().for_each(|b| b.to_ascii_lowercase().hash(state));
+    // Prevent prefix collisions, as `str` does.
+    state.write_u8(0xff);
+}
+
+/// Case-insensitive ASCII identifier slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct CiIdent(str);
+
+impl PartialEq for CiIdent {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for CiIdent {}
+
+impl PartialOrd for CiIdent {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CiIdent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.0.bytes().map(|b| b.to_ascii_lowercase());
+        let rhs = other.0.bytes().map(|b| b.to_ascii_lowercase());
+        lhs.cmp(rhs)
+    }
+}
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: CiIdentSpec,
+        custom: CiIdent,
+        inner: str,
+        error: InvalidIdentError,
+    };
+    Check { Trusted };
+    { AsRef<str> };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Hash(with = hash_ignore_ascii_case) };
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        spec: CiIdentSpec,
+        custom: CiIdent,
+        inner: str,
+        base: Custom,
+    };
+    Cmp { PartialEq };
+    { ({Custom}), (&{Custom}), rev };
+}
+
+// Comparisons with raw strings use the same folded key.
+impl validated_slice::FoldedCmpSpec for CiIdentSpec {
+    #[inline]
+    fn cmp_folded(lhs: &str, rhs: &str) -> Ordering {
+        validated_slice::cmp_ignore_ascii_case(lhs, rhs)
+    }
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        spec: CiIdentSpec,
+        custom: CiIdent,
+        inner: str,
+        base: Folded,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), ({Inner}), rev };
+    { ({Custom}), (&{Inner}), rev };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: CiIdentSpec,
+        custom: CiIdent,
+        inner: str,
+        error: InvalidIdentError,
+    };
+    { pub fn eq_exact };
+}
+
+enum CiIdentBufSpec {}
+
+// The associated types are consistent with `CiIdentSpec`, which is also trusted.
+unsafe impl validated_slice::TrustedSpec for CiIdentBufSpec {}
+
+impl validated_slice::OwnedSliceSpec for CiIdentBufSpec {
+    type Custom = CiIdentBuf;
+    type Inner = String;
+    type Error = InvalidIdentError;
+    type SliceSpec = CiIdentSpec;
+    type SliceCustom = CiIdent;
+    type SliceInner = str;
+    type SliceError = InvalidIdentError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        CiIdentBuf(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// Case-insensitive ASCII identifier.
+#[derive(Debug, Clone)]
+pub struct CiIdentBuf(String);
+
+impl Eq for CiIdentBuf {}
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: CiIdentBufSpec,
+        custom: CiIdentBuf,
+        inner: String,
+        error: InvalidIdentError,
+        slice_custom: CiIdent,
+        slice_inner: str,
+        slice_error: InvalidIdentError,
+    };
+    Check { Borrow, Trusted };
+    { Borrow<{SliceCustom}> };
+    { ToOwned<Owned = {Custom}> for {SliceCustom} };
+    { From<&{SliceCustom}> };
+    { TryFrom<&{SliceInner}> };
+    { Hash(with = hash_ignore_ascii_case) };
+    { Ord };
+}
+
+validated_slice::impl_cmp_for_owned_slice! {
+    Spec {
+        spec: CiIdentBufSpec,
+        custom: CiIdentBuf,
+        inner: String,
+        slice_custom: CiIdent,
+        slice_inner: str,
+        base: Custom,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), ({SliceCustom}), rev };
+    { ({Custom}), (&{SliceCustom}), rev };
+}
+
+validated_slice::impl_cmp_for_owned_slice! {
+    Spec {
+        spec: CiIdentBufSpec,
+        custom: CiIdentBuf,
+        inner: String,
+        slice_custom: CiIdent,
+        slice_inner: str,
+        base: Folded,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), ({SliceInner}), rev };
+    { ({Custom}), (&{SliceInner}), rev };
+}
+
+#[cfg(test)]
+mod ci_ident {
+    use super::*;
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashSet};
+    use std::convert::TryFrom;
+
+    fn hash<T: Hash + ?Sized>(v: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        v.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn cmp() {
+        let lower = <&CiIdent>::try_from("foo_bar").expect("Should never fail");
+        let upper = <&CiIdent>::try_from("FOO_Bar").expect("Should never fail");
+        assert_eq!(lower, upper);
+        assert_eq!(CiIdentBuf::from(lower), upper);
+        assert_eq!(lower, CiIdentBuf::from(upper));
+    }
+
+    #[test]
+    fn cmp_folded() {
+        let ident = <&CiIdent>::try_from("foo_bar").expect("Should never fail");
+        assert_eq!(*ident, *"FOO_BAR");
+        assert_eq!("Foo_Bar", *ident);
+        assert!(*ident < *"GOO");
+        assert!("Bar" < *ident);
+        let buf = CiIdentBuf::from(ident);
+        assert_eq!(buf, *"FOO_bar");
+        assert_eq!("FOO_bar", buf);
+        assert!(buf > "BAR");
+    }
+
+    #[test]
+    fn eq_exact() {
+        let lower = <&CiIdent>::try_from("foo_bar").expect("Should never fail");
+        let upper = <&CiIdent>::try_from("FOO_Bar").expect("Should never fail");
+        assert_eq!(lower, upper);
+        assert!(!lower.eq_exact(upper));
+        assert!(lower.eq_exact(lower));
+    }
+
+    #[test]
+    fn hash_consistency() {
+        let lower = <&CiIdent>::try_from("foo_bar").expect("Should never fail");
+        let upper = <&CiIdent>::try_from("FOO_Bar").expect("Should never fail");
+        assert_eq!(hash(lower), hash(upper));
+        assert_eq!(hash(lower), hash(&CiIdentBuf::from(upper)));
+    }
+
+    #[test]
+    fn hash_set() {
+        let mut set = HashSet::new();
+        set.insert(CiIdentBuf::try_from("Foo").expect("Should never fail"));
+        let key = <&CiIdent>::try_from("FOO").expect("Should never fail");
+        assert!(set.contains(key));
+        assert!(!set.insert(key.to_owned()));
+    }
+
+    #[test]
+    fn btree_set() {
+        let mut set = BTreeSet::new();
+        set.insert(CiIdentBuf::try_from("foo").expect("Should never fail"));
+        set.insert(CiIdentBuf::try_from("Bar").expect("Should never fail"));
+        let key = <&CiIdent>::try_from("FOO").expect("Should never fail");
+        assert!(set.contains(key));
+        assert!(!set.insert(key.to_owned()));
+        let first = set.iter().next().expect("Should never fail");
+        assert_eq!(
+            first,
+            <&CiIdent>::try_from("bar").expect("Should never fail")
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! `defmt` integration.
+#![cfg(feature = "defmt")]
+
+mod common;
+
+use self::common::{AsciiStr, AsciiStrSpec, AsciiString, AsciiStringSpec};
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+    };
+    { defmt::Format };
+}
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+    };
+    { defmt::Format };
+}
+
+/// Asserts that the type implements `defmt::Format`.
+fn assert_format<T: ?Sized + defmt::Format>() {}
+
+#[test]
+fn format() {
+    assert_format::<AsciiStr>();
+    assert_format::<&AsciiStr>();
+    assert_format::<AsciiString>();
+}
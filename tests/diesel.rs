@@ -0,0 +1,86 @@
+//! `diesel` integration.
+#![cfg(feature = "diesel")]
+
+mod common;
+
+use self::common::{AsciiError, AsciiString, AsciiStringSpec};
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+    };
+    { diesel::AsExpression<diesel::sql_types::Text> };
+    { diesel::FromSql<diesel::sql_types::Text> };
+    { diesel::Queryable };
+    { diesel::ToSql<diesel::sql_types::Text> };
+}
+
+#[test]
+fn as_expression()
+where
+    AsciiString: diesel::expression::AsExpression<diesel::sql_types::Text>,
+    for<'a> &'a AsciiString: diesel::expression::AsExpression<diesel::sql_types::Text>,
+    AsciiString:
+        diesel::expression::AsExpression<diesel::sql_types::Nullable<diesel::sql_types::Text>>,
+{
+}
+
+#[test]
+fn to_sql_from_sql() {
+    use diesel::sql_types::{Nullable, Text};
+
+    fn assert_impls<DB, T>()
+    where
+        DB: diesel::backend::Backend,
+        T: diesel::serialize::ToSql<Text, DB>
+            + diesel::serialize::ToSql<Nullable<Text>, DB>
+            + diesel::deserialize::FromSql<Text, DB>
+            + diesel::deserialize::Queryable<Text, DB>,
+    {
+    }
+
+    // Impls for `AsciiString` should be available whenever impls for `String` are available.
+    #[allow(dead_code)]
+    fn assert_delegated<DB>()
+    where
+        DB: diesel::backend::Backend,
+        str: diesel::serialize::ToSql<Text, DB>,
+        String: diesel::deserialize::FromSql<Text, DB>,
+    {
+        assert_impls::<DB, AsciiString>();
+    }
+}
+
+#[test]
+fn sqlite_roundtrip() {
+    use diesel::sql_types::Text;
+    use diesel::{Connection, IntoSql, RunQueryDsl};
+    use std::convert::TryFrom;
+
+    let mut conn = diesel::SqliteConnection::establish(":memory:")
+        .expect("in-memory database should be available");
+
+    let value = AsciiString::try_from("ascii".to_owned()).expect("Should never fail");
+    let loaded: AsciiString = diesel::select((&value).into_sql::<Text>())
+        .get_result(&mut conn)
+        .expect("ASCII text should be loaded");
+    assert_eq!(loaded, value);
+    let loaded: AsciiString = diesel::select(value.clone().into_sql::<Text>())
+        .get_result(&mut conn)
+        .expect("ASCII text should be loaded");
+    assert_eq!(loaded, value);
+
+    let err = diesel::select(diesel::dsl::sql::<Text>("'non-ascii: \u{e9}'"))
+        .get_result::<AsciiString>(&mut conn)
+        .expect_err("non-ASCII text should be rejected");
+    match err {
+        diesel::result::Error::DeserializationError(source) => {
+            let source = source
+                .downcast_ref::<diesel::result::DeserializeFieldError>()
+                .and_then(|e| e.error.downcast_ref::<AsciiError>())
+                .expect("validation error should be returned");
+            assert_eq!(source.valid_up_to(), 11);
+        }
+        e => panic!("unexpected error: {:?}", e),
+    }
+}
@@ -0,0 +1,43 @@
+//! `equivalent` integration.
+#![cfg(feature = "equivalent")]
+
+mod common;
+
+use equivalent::{Comparable, Equivalent};
+
+use self::common::{AsciiStr, AsciiString, AsciiStringSpec};
+
+// `AsciiString` does not implement `Borrow`, so these targets do not conflict with the blanket
+// impls of `equivalent` crate.
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+    };
+    { equivalent::Equivalent<{Custom}> for {SliceCustom} };
+    { equivalent::Equivalent<{Custom}> for {SliceInner} };
+    { equivalent::Comparable<{Custom}> for {SliceCustom} };
+    { equivalent::Comparable<{Custom}> for {SliceInner} };
+}
+
+#[test]
+fn equivalent() {
+    use std::convert::TryFrom;
+
+    let owned = AsciiString::try_from("foo".to_owned()).expect("Should never fail");
+    let borrowed = <&AsciiStr>::try_from("foo").expect("Should never fail");
+    assert!(borrowed.equivalent(&owned));
+    assert!("foo".equivalent(&owned));
+    assert!(!"bar".equivalent(&owned));
+}
+
+#[test]
+fn comparable() {
+    use std::cmp::Ordering;
+    use std::convert::TryFrom;
+
+    let owned = AsciiString::try_from("foo".to_owned()).expect("Should never fail");
+    let borrowed = <&AsciiStr>::try_from("bar").expect("Should never fail");
+    assert_eq!(borrowed.compare(&owned), Ordering::Less);
+    assert_eq!("foo".compare(&owned), Ordering::Equal);
+    assert_eq!("qux".compare(&owned), Ordering::Greater);
+}
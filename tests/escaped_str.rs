@@ -0,0 +1,194 @@
+//! Escaped string.
+//!
+//! Validity of the string depends on the context given as a type parameter.
+
+use std::marker::PhantomData;
+
+use validated_slice::ValidationContext;
+
+/// Context for HTML text.
+pub enum Html {}
+
+impl ValidationContext<str> for Html {
+    type Error = UnescapedError;
+
+    fn validate(s: &str) -> Result<(), Self::Error> {
+        find_unescaped(s, &['<', '>', '"'])
+    }
+}
+
+/// Context for SQL string literals.
+pub enum Sql {}
+
+impl ValidationContext<str> for Sql {
+    type Error = UnescapedError;
+
+    fn validate(s: &str) -> Result<(), Self::Error> {
+        // Quotes should be doubled.
+        find_unescaped(&s.replace("''", "__"), &['\''])
+    }
+}
+
+/// Returns the error for the first unescaped character.
+fn find_unescaped(s: &str, chars: &[char]) -> Result<(), UnescapedError> {
+    match s.find(chars) {
+        Some(valid_up_to) => Err(UnescapedError { valid_up_to }),
+        None => Ok(()),
+    }
+}
+
+/// Unescaped character error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnescapedError {
+    /// Byte position of the first unescaped character.
+    valid_up_to: usize,
+}
+
+/// Spec for `Escaped<C>`.
+pub struct EscapedSpec<C>(PhantomData<C>);
+
+impl<C: ValidationContext<str>> validated_slice::SliceSpec for EscapedSpec<C> {
+    type Custom = Escaped<C>;
+    type Inner = str;
+    type Error = C::Error;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        C::validate(s)
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=1;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// String escaped for the context `C`.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+pub struct Escaped<C>(PhantomData<C>, str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        tags: [C: ValidationContext<str>],
+        spec: EscapedSpec<C>,
+        custom: Escaped<C>,
+        inner: str,
+        error: C::Error,
+    };
+    { AsRef<str> };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Debug };
+    { Deref<Target = {Inner}> };
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        tags: [C: ValidationContext<str>],
+        spec: EscapedSpec<C>,
+        custom: Escaped<C>,
+        inner: str,
+        base: Inner,
+    };
+    Cmp { PartialEq };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), ({Inner}), rev };
+}
+
+/// Spec for `EscapedString<C>`.
+pub struct EscapedStringSpec<C>(PhantomData<C>);
+
+impl<C: ValidationContext<str>> validated_slice::OwnedSliceSpec for EscapedStringSpec<C> {
+    type Custom = EscapedString<C>;
+    type Inner = String;
+    type Error = C::Error;
+    type SliceSpec = EscapedSpec<C>;
+    type SliceCustom = Escaped<C>;
+    type SliceInner = str;
+    type SliceError = C::Error;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.1
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        EscapedString(PhantomData, s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.1
+    }
+}
+
+/// Owned string escaped for the context `C`.
+pub struct EscapedString<C>(PhantomData<C>, String);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        tags: [C: ValidationContext<str>],
+        spec: EscapedStringSpec<C>,
+        custom: EscapedString<C>,
+        inner: String,
+        error: C::Error,
+        slice_custom: Escaped<C>,
+        slice_inner: str,
+        slice_error: C::Error,
+    };
+    { Borrow<{SliceCustom}> };
+    { ToOwned<Owned = {Custom}> for {SliceCustom} };
+    { TryFrom<{Inner}> };
+    { Debug };
+    { Deref<Target = {SliceCustom}> };
+}
+
+#[cfg(test)]
+mod escaped {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        let html = <&Escaped<Html>>::try_from("it's").expect("Should never fail");
+        assert_eq!(html, "it's");
+        assert_eq!(
+            <&Escaped<Sql>>::try_from("it's"),
+            Err(UnescapedError { valid_up_to: 2 })
+        );
+
+        let sql = <&Escaped<Sql>>::try_from("it''s <b>").expect("Should never fail");
+        assert_eq!(*sql, *"it''s <b>");
+        assert_eq!(
+            <&Escaped<Html>>::try_from("it''s <b>"),
+            Err(UnescapedError { valid_up_to: 6 })
+        );
+    }
+
+    #[test]
+    fn owned() {
+        let owned =
+            EscapedString::<Html>::try_from(String::from("a &amp; b")).expect("Should never fail");
+        let borrowed: &Escaped<Html> = &owned;
+        assert_eq!(borrowed, "a &amp; b");
+        assert_eq!(borrowed.to_owned().1, "a &amp; b");
+        assert!(EscapedString::<Html>::try_from(String::from("<")).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+//! Four-character code.
+//!
+//! Exact length constraint is layered on the spec for the bytes.
+
+use validated_slice::{ExactLen, LenError};
+
+enum FourCcBytesSpec {}
+
+impl validated_slice::SliceSpec for FourCcBytesSpec {
+    type Custom = FourCc;
+    type Inner = [u8];
+    type Error = NonPrintableError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        match s.iter().position(|b| !(b.is_ascii_graphic() || *b == b' ')) {
+            Some(pos) => Err(NonPrintableError { valid_up_to: pos }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Spec of `FourCc`.
+type FourCcSpec = ExactLen<4, FourCcBytesSpec>;
+
+/// Non-printable byte error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonPrintableError {
+    /// Position of the first non-printable byte.
+    valid_up_to: usize,
+}
+
+/// Four-character code.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FourCc([u8]);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: FourCcSpec,
+        custom: FourCc,
+        inner: [u8],
+        error: LenError<NonPrintableError>,
+    };
+    { AsRef<[u8]> };
+    { TryFrom<&{Inner}> for &{Custom} };
+}
+
+#[cfg(test)]
+mod four_cc {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        assert!(<&FourCc>::try_from(&b"avc1"[..]).is_ok());
+        assert_eq!(
+            <&FourCc>::try_from(&b"mp4"[..]),
+            Err(LenError::TooShort { min: 4, len: 3 })
+        );
+        assert_eq!(
+            <&FourCc>::try_from(&b"hvc1 "[..]),
+            Err(LenError::TooLong { max: 4, len: 5 })
+        );
+        assert_eq!(
+            <&FourCc>::try_from(&b"av\x001"[..]),
+            Err(LenError::Inner(NonPrintableError { valid_up_to: 2 }))
+        );
+    }
+}
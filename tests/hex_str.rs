@@ -0,0 +1,293 @@
+//! Hexadecimal string.
+//!
+//! Traits for the borrowed and owned types are implemented by a single macro invocation.
+
+enum HexStrSpec {}
+
+impl validated_slice::SliceSpec for HexStrSpec {
+    type Custom = HexStr;
+    type Inner = str;
+    type Error = NonHexError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        match s.bytes().position(|b| !b.is_ascii_hexdigit()) {
+            Some(pos) => Err(NonHexError { valid_up_to: pos }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+// Any sub-slice of a hexadecimal string is also a hexadecimal string.
+unsafe impl validated_slice::SliceClosedSpec for HexStrSpec {}
+
+/// Non-hexadecimal character error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonHexError {
+    /// Byte position of the first non-hexadecimal character.
+    valid_up_to: usize,
+}
+
+/// Hexadecimal string slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexStr(str);
+
+enum HexStringSpec {}
+
+impl validated_slice::OwnedSliceSpec for HexStringSpec {
+    type Custom = HexString;
+    type Inner = String;
+    type Error = NonHexError;
+    type SliceSpec = HexStrSpec;
+    type SliceCustom = HexStr;
+    type SliceInner = str;
+    type SliceError = NonHexError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        HexString(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+impl validated_slice::OwnedSliceMutSpec for HexStringSpec {
+    #[inline]
+    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
+        &mut s.0
+    }
+}
+
+/// Hexadecimal string.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexString(String);
+
+validated_slice::impl_traits_for_slice_pair! {
+    Slice {
+        Spec {
+            spec: HexStrSpec,
+            custom: HexStr,
+            inner: str,
+            error: NonHexError,
+        };
+        Traits {
+            { AsRef<str> };
+            { Borrow<{Inner}> };
+            // Changing ASCII case keeps the string valid.
+            { unsafe BorrowMut<{Inner}> };
+            { TryFrom<&{Inner}> for &{Custom} };
+            { Default for &{Custom} };
+            { Needle<{Inner}> for &{Custom} };
+            { Display(with = fmt_lowercase) };
+            { Deref<Target = {Inner}> };
+        };
+        Cmp(base = Inner) { PartialEq, PartialOrd } {
+            { ({Custom}), ({Inner}), rev };
+            { ({Custom}), (&{Inner}), rev };
+        };
+    };
+    Owned {
+        Spec {
+            spec: HexStringSpec,
+            custom: HexString,
+            inner: String,
+            error: NonHexError,
+            slice_custom: HexStr,
+            slice_inner: str,
+            slice_error: NonHexError,
+        };
+        Traits {
+            { BorrowMut<{SliceCustom}> };
+            { AsRef<str> };
+            { TryFrom<{Inner}> };
+            { Display(with = fmt_lowercase) };
+            { Deref<Target = {SliceCustom}> };
+        };
+        Cmp(base = Inner) { PartialEq, PartialOrd } {
+            { ({Custom}), ({SliceInner}), rev };
+        };
+    };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: HexStrSpec,
+        custom: HexStr,
+        inner: str,
+        error: NonHexError,
+    };
+    { pub fn strip_prefix };
+    { pub fn strip_suffix(revalidate) };
+    { pub fn eq_inner };
+    { pub fn eq_ignore_ascii_case };
+}
+
+/// Formats the hexadecimal string in lowercase.
+fn fmt_lowercase(s: &str, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    use std::fmt::Write;
+
+    s.chars()
+        .try_for_each(|c| f.write_char(c.to_ascii_lowercase()))
+}
+
+#[cfg(test)]
+mod hex_str {
+    use super::*;
+
+    use std::borrow::{Borrow, Cow};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        assert!(<&HexStr>::try_from("c0ffee").is_ok());
+        assert_eq!(
+            <&HexStr>::try_from("coffee"),
+            Err(NonHexError { valid_up_to: 1 })
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let slice = <&HexStr>::try_from("c0ffee").expect("Should never fail");
+        let owned: HexString = slice.to_owned();
+        assert_eq!(Borrow::<HexStr>::borrow(&owned), slice);
+        assert_eq!(HexString::from(slice), owned);
+    }
+
+    #[test]
+    fn cmp() {
+        let slice = <&HexStr>::try_from("c0ffee").expect("Should never fail");
+        let owned = HexString::from(slice);
+        assert_eq!(owned, *slice);
+        assert_eq!(*slice, owned);
+        assert_eq!(owned, slice);
+        assert_eq!(slice, owned);
+        assert_eq!(owned, Cow::Borrowed(slice));
+        assert_eq!(owned, *"c0ffee");
+    }
+    #[test]
+    fn borrow() {
+        use std::borrow::BorrowMut;
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert("c0ffee".to_owned(), 42);
+        let slice = <&HexStr>::try_from("c0ffee").expect("Should never fail");
+        assert_eq!(map.get(Borrow::<str>::borrow(slice)), Some(&42));
+
+        let mut owned = HexString::from(slice);
+        let slice_mut: &mut HexStr = owned.borrow_mut();
+        BorrowMut::<str>::borrow_mut(slice_mut).make_ascii_uppercase();
+        assert_eq!(owned, *"C0FFEE");
+    }
+
+    #[test]
+    fn display() {
+        let slice = <&HexStr>::try_from("C0FFEE").expect("Should never fail");
+        assert_eq!(format!("{}", slice), "c0ffee");
+        assert_eq!(format!("{}", HexString::from(slice)), "c0ffee");
+    }
+
+    #[test]
+    fn strip() {
+        let slice = <&HexStr>::try_from("c0ffee").expect("Should never fail");
+        let prefix = <&HexStr>::try_from("c0").expect("Should never fail");
+        let rest: &HexStr = slice.strip_prefix(prefix).expect("Should never fail");
+        assert_eq!(rest, "ffee");
+        assert_eq!(slice.strip_prefix("ff"), None);
+        let rest: &HexStr = slice
+            .strip_suffix("ee")
+            .expect("Should never fail")
+            .expect("Should never fail");
+        assert_eq!(rest, "c0ff");
+        assert!(slice.strip_suffix("c0").is_none());
+        let rest: &HexStr = slice.strip_prefix('c').expect("Should never fail");
+        assert_eq!(rest, "0ffee");
+        let rest: &HexStr = slice
+            .strip_prefix(|c: char| c.is_ascii_alphabetic())
+            .expect("Should never fail");
+        assert_eq!(rest, "0ffee");
+        assert!(slice.strip_prefix(char::is_numeric).is_none());
+    }
+
+    /// Needle which reports the fixed match length regardless of the haystack.
+    struct HostileNeedle(usize);
+
+    impl validated_slice::Needle<str> for HostileNeedle {
+        fn find_in(&mut self, _: &str) -> Option<std::ops::Range<usize>> {
+            Some(0..self.0)
+        }
+
+        fn match_prefix_len(&mut self, _: &str) -> Option<usize> {
+            Some(self.0)
+        }
+
+        fn match_suffix_len(&mut self, _: &str) -> Option<usize> {
+            Some(self.0)
+        }
+    }
+
+    #[test]
+    fn strip_hostile_needle() {
+        let slice = <&HexStr>::try_from("c0ffee").expect("Should never fail");
+        // The remainder is always a sub-slice of the original slice.
+        let rest: &HexStr = slice
+            .strip_prefix(HostileNeedle(2))
+            .expect("Should never fail");
+        assert_eq!(rest, "ffee");
+        let rest: &HexStr = slice
+            .strip_suffix(HostileNeedle(2))
+            .expect("Should never fail")
+            .expect("Should never fail");
+        assert_eq!(rest, "c0ff");
+        // Out of range lengths cause panics, not invalid slices.
+        assert!(std::panic::catch_unwind(|| slice.strip_prefix(HostileNeedle(100))).is_err());
+        assert!(std::panic::catch_unwind(|| slice.strip_suffix(HostileNeedle(100))).is_err());
+    }
+
+    #[test]
+    fn eq_inner() {
+        let slice = <&HexStr>::try_from("c0ffee").expect("Should never fail");
+        assert!(slice.eq_inner("c0ffee"));
+        assert!(!slice.eq_inner("C0FFEE"));
+        assert!(slice.eq_ignore_ascii_case("C0FFEE"));
+        assert!(!slice.eq_ignore_ascii_case("c0ffe"));
+
+        let words = ["cafe", "C0FFEE", "f00d"];
+        let found = words
+            .iter()
+            .filter(|w| slice.eq_ignore_ascii_case(w))
+            .count();
+        assert_eq!(found, 1);
+    }
+}
@@ -0,0 +1,353 @@
+//! Hostname and DNS label.
+//!
+//! Custom types over the same inner type with unrelated specs, converted into each other by
+//! revalidation.
+
+enum HostnameStrSpec {}
+
+impl validated_slice::SliceSpec for HostnameStrSpec {
+    type Custom = HostnameStr;
+    type Inner = str;
+    type Error = InvalidHostnameError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        if s.is_empty() {
+            return Err(InvalidHostnameError { valid_up_to: 0 });
+        }
+        match s
+            .bytes()
+            .position(|b| !(b == b'-' || b == b'.' || b.is_ascii_alphanumeric()))
+        {
+            Some(pos) => Err(InvalidHostnameError { valid_up_to: pos }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Invalid hostname error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidHostnameError {
+    /// Byte position of the first invalid byte.
+    valid_up_to: usize,
+}
+
+/// Hostname slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct HostnameStr(str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: HostnameStrSpec,
+        custom: HostnameStr,
+        inner: str,
+        error: InvalidHostnameError,
+    };
+    { AsRef<str> };
+    { TryFrom<&{Inner}> for &{Custom} };
+    // TryFrom<&'_ LabelStr> for &'_ HostnameStr
+    { TryFrom<&LabelStr> for &{Custom} };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: HostnameStrSpec,
+        custom: HostnameStr,
+        inner: str,
+        error: InvalidHostnameError,
+    };
+    { pub fn forward(str) };
+}
+
+enum LabelStrSpec {}
+
+impl validated_slice::SliceSpec for LabelStrSpec {
+    type Custom = LabelStr;
+    type Inner = str;
+    type Error = InvalidLabelError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        if s.len() > 63 {
+            return Err(InvalidLabelError { valid_up_to: 63 });
+        }
+        match s
+            .bytes()
+            .position(|b| !(b == b'-' || b.is_ascii_alphanumeric()))
+        {
+            Some(pos) => Err(InvalidLabelError { valid_up_to: pos }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Invalid DNS label error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidLabelError {
+    /// Byte position of the first invalid byte.
+    valid_up_to: usize,
+}
+
+/// DNS label slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct LabelStr(str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: LabelStrSpec,
+        custom: LabelStr,
+        inner: str,
+        error: InvalidLabelError,
+    };
+    { AsRef<str> };
+    { TryFrom<&{Inner}> for &{Custom} };
+    // TryFrom<&'_ HostnameStr> for &'_ LabelStr
+    { TryFrom<&HostnameStr> for &{Custom} };
+}
+
+enum HostnameStringSpec {}
+
+impl validated_slice::OwnedSliceSpec for HostnameStringSpec {
+    type Custom = HostnameString;
+    type Inner = String;
+    type Error = InvalidHostnameStringError;
+    type SliceSpec = HostnameStrSpec;
+    type SliceCustom = HostnameStr;
+    type SliceInner = str;
+    type SliceError = InvalidHostnameError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, v: Self::Inner) -> Self::Error {
+        InvalidHostnameStringError(e, v)
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        HostnameString(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// Invalid hostname string error.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidHostnameStringError(InvalidHostnameError, String);
+
+/// Hostname string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostnameString(String);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: HostnameStringSpec,
+        custom: HostnameString,
+        inner: String,
+        error: InvalidHostnameStringError,
+        slice_custom: HostnameStr,
+        slice_inner: str,
+        slice_error: InvalidHostnameError,
+    };
+    { TryFrom<{Inner}> };
+    // FromStr<Err = InvalidHostnameStringError> for HostnameString
+    { FromStr(error = {Error}) };
+    // Empty sub-slices are invalid.
+    { Index<Range<usize>, Output = {SliceCustom}>(revalidate) };
+}
+
+enum LabelStringSpec {}
+
+impl validated_slice::OwnedSliceSpec for LabelStringSpec {
+    type Custom = LabelString;
+    type Inner = String;
+    type Error = InvalidLabelError;
+    type SliceSpec = LabelStrSpec;
+    type SliceCustom = LabelStr;
+    type SliceInner = str;
+    type SliceError = InvalidLabelError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        LabelString(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// Label parse error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseLabelError {
+    /// Invalid label.
+    Invalid(InvalidLabelError),
+}
+
+/// DNS label string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelString(String);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: LabelStringSpec,
+        custom: LabelString,
+        inner: String,
+        error: InvalidLabelError,
+        slice_custom: LabelStr,
+        slice_inner: str,
+        slice_error: InvalidLabelError,
+    };
+    // FromStr<Err = ParseLabelError> for LabelString
+    { FromStr(error = ParseLabelError, map_err = ParseLabelError::Invalid) };
+}
+
+#[cfg(test)]
+mod hostname_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from_label() {
+        let label = <&LabelStr>::try_from("localhost").expect("Should never fail");
+        let host = <&HostnameStr>::try_from(label).expect("Should never fail");
+        assert_eq!(&host.0, "localhost");
+
+        let empty = <&LabelStr>::try_from("").expect("Should never fail");
+        assert_eq!(
+            <&HostnameStr>::try_from(empty),
+            Err(InvalidHostnameError { valid_up_to: 0 })
+        );
+    }
+
+    #[test]
+    fn forward() {
+        let host = <&HostnameStr>::try_from("example.com").expect("Should never fail");
+        assert_eq!(host.len(), 11);
+        assert!(!host.is_empty());
+        assert_eq!(host.chars().filter(|&c| c == '.').count(), 1);
+        assert_eq!(host.char_indices().find(|&(_, c)| c == '.'), Some((7, '.')));
+        assert_eq!(host.bytes().last(), Some(b'm'));
+    }
+}
+
+#[cfg(test)]
+mod label_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from_hostname() {
+        let host = <&HostnameStr>::try_from("localhost").expect("Should never fail");
+        let label = <&LabelStr>::try_from(host).expect("Should never fail");
+        assert_eq!(&label.0, "localhost");
+
+        let host = <&HostnameStr>::try_from("example.com").expect("Should never fail");
+        assert_eq!(
+            <&LabelStr>::try_from(host),
+            Err(InvalidLabelError { valid_up_to: 7 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod hostname_string {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let host: HostnameString = "example.com".parse().expect("Should never fail");
+        assert_eq!(host.0, "example.com");
+        assert_eq!(
+            "exa mple.com".parse::<HostnameString>(),
+            Err(InvalidHostnameStringError(
+                InvalidHostnameError { valid_up_to: 3 },
+                "exa mple.com".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn index_range() {
+        let host: HostnameString = "www.example.com".parse().expect("Should never fail");
+        let sub: &HostnameStr = &host[4..11];
+        assert_eq!(&sub.0, "example");
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_range_invalid() {
+        let host: HostnameString = "example.com".parse().expect("Should never fail");
+        let _ = &host[3..3];
+    }
+}
+
+#[cfg(test)]
+mod label_string {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let label: LabelString = "localhost".parse().expect("Should never fail");
+        assert_eq!(label.0, "localhost");
+        assert_eq!(
+            "example.com".parse::<LabelString>(),
+            Err(ParseLabelError::Invalid(InvalidLabelError {
+                valid_up_to: 7
+            }))
+        );
+    }
+}
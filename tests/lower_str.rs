@@ -0,0 +1,484 @@
+//! Lowercase string.
+//!
+//! Spec fields of the macros are omitted and inferred from the spec types.
+//! Traits and comparisons for the owned type are implemented by a single macro invocation.
+
+/// Spec for `LowerStr`.
+// This should be as visible as `LowerStr`, because the generated trait impls refer to it.
+pub enum LowerStrSpec {}
+
+impl validated_slice::SliceSpec for LowerStrSpec {
+    type Custom = LowerStr;
+    type Inner = str;
+    type Error = UppercaseError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        match s.char_indices().find(|(_, c)| c.is_uppercase()) {
+            Some((pos, _)) => Err(UppercaseError { valid_up_to: pos }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+// Any sub-slice of a lowercase string is also a lowercase string.
+unsafe impl validated_slice::SliceClosedSpec for LowerStrSpec {}
+
+// Concatenation of lowercase strings is also a lowercase string.
+unsafe impl validated_slice::ConcatClosedSpec for LowerStrSpec {}
+
+/// Uppercase character error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UppercaseError {
+    /// Byte position of the first uppercase character.
+    valid_up_to: usize,
+}
+
+validated_slice::impl_validation_error! {
+    Error {
+        ty: UppercaseError,
+        message: "uppercase character found",
+    };
+}
+
+/// Lowercase string slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Eq, Ord, Hash)]
+pub struct LowerStr(str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: LowerStrSpec,
+    };
+    { AsRef<str> for {Custom} };
+    { From<&{Custom}> for &{Inner} };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Default for &{Custom} };
+    { Debug };
+    { Display };
+    { Deref<Target = {Inner}> };
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        spec: LowerStrSpec,
+        base: Inner,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), ({Inner}), rev };
+    { ({Custom}), (&{Inner}), rev };
+    { ({Custom}), (Arc<{Inner}>), rev };
+    { ({Custom}), (Box<{Inner}>), rev };
+    { ({Custom}), (Label via Label::text), rev };
+    { for<'a> ({Custom}), (Cow<'a, {Inner}>), rev };
+}
+
+/// Text label, which does not implement `AsRef<str>`.
+#[derive(Debug)]
+pub struct Label {
+    /// Text.
+    text: String,
+}
+
+impl Label {
+    /// Returns the text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Spec for `LowerString`.
+pub enum LowerStringSpec {}
+
+impl validated_slice::OwnedSliceSpec for LowerStringSpec {
+    type Custom = LowerString;
+    type Inner = String;
+    type Error = UppercaseError;
+    type SliceSpec = LowerStrSpec;
+    type SliceCustom = LowerStr;
+    type SliceInner = str;
+    type SliceError = UppercaseError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        LowerString(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// Lowercase string.
+#[derive(Default, Clone, Eq, Ord, Hash)]
+pub struct LowerString(String);
+
+validated_slice::impl_traits_for_owned_slice! {
+    Spec {
+        spec: LowerStringSpec,
+    };
+    Traits {
+        { Borrow<{SliceCustom}> };
+        { ToOwned<Owned = {Custom}> for {SliceCustom} };
+        { AsRef<str> };
+        { From<&{SliceCustom}> };
+        { From<Cow<{SliceCustom}>> };
+        { From<&{Custom}> for {Inner} };
+        { From<&{SliceCustom}> for {Inner} };
+        { TryFrom<&{SliceInner}> };
+        { TryFrom<{Inner}> };
+        { TryFrom<Cow<{SliceInner}>> };
+        { Debug(name = "LowerString") for {Custom} };
+        { Display };
+        { Deref<Target = {SliceCustom}> };
+        { FromStr };
+    };
+    Cmp(base = Inner) { PartialEq, PartialOrd } {
+        { ({Custom}), ({Custom}) };
+        { ({Custom}), ({SliceCustom}), rev };
+        { ({Custom}), (&{SliceCustom}), rev };
+        { ({Custom}), ({SliceInner}), rev };
+        { ({Custom}), (&{SliceInner}), rev };
+        { ({Custom}), (Label via Label::text), rev };
+        { for<'a> ({Custom}), (Cow<'a, {SliceInner}>), rev };
+    };
+}
+
+#[cfg(test)]
+mod lower_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        assert!(<&LowerStr>::try_from("foo").is_ok());
+        assert_eq!(
+            <&LowerStr>::try_from("fOo"),
+            Err(UppercaseError { valid_up_to: 1 })
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        let s = <&LowerStr>::try_from("foo").expect("Should never fail");
+        assert_eq!(format!("{:?}", s), "\"foo\"");
+        assert_eq!(format!("{}", s), "foo");
+    }
+
+    #[test]
+    fn error() {
+        let e = <&LowerStr>::try_from("fOo").expect_err("Should fail");
+        assert_eq!(e.valid_up_to(), 1);
+        assert_eq!(
+            e.to_string(),
+            "uppercase character found at byte position 1"
+        );
+        let _: &dyn std::error::Error = &e;
+    }
+
+    #[test]
+    fn cmp() {
+        let s = <&LowerStr>::try_from("foo").expect("Should never fail");
+        assert_eq!(*s, *"foo");
+        assert_eq!(s, "foo");
+        assert!(*s < *"goo");
+        let arc: std::sync::Arc<str> = "foo".into();
+        assert_eq!(*s, arc);
+        assert_eq!(Box::<str>::from("foo"), *s);
+        let label = Label {
+            text: "foo".to_owned(),
+        };
+        assert_eq!(*s, label);
+        assert!(label < *<&LowerStr>::try_from("goo").expect("Should never fail"));
+        assert!(arc < *<&LowerStr>::try_from("goo").expect("Should never fail"));
+        assert_eq!(<&LowerStr>::default(), "");
+    }
+}
+
+// Uppercase characters are lowercased.
+impl validated_slice::NormalizeSpec for LowerStringSpec {
+    fn normalize(s: &Self::SliceInner) -> Self::Inner {
+        s.to_lowercase()
+    }
+}
+
+validated_slice::impl_methods_for_owned_slice! {
+    Spec {
+        spec: LowerStringSpec,
+        custom: LowerString,
+        inner: String,
+        error: UppercaseError,
+        slice_custom: LowerStr,
+        slice_inner: str,
+        slice_error: UppercaseError,
+    };
+    { pub fn from_normalized };
+    { pub fn from_str_normalized };
+    { pub fn replace };
+    { pub fn to_owned_with };
+    { pub fn try_from_cow };
+    { pub fn into_inner_cow };
+    { pub fn from_proof };
+    { pub fn to_ascii_uppercase(transform = str::to_ascii_uppercase) };
+    { pub fn doubled(unsafe trusted_transform = repeat_twice) };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: LowerStrSpec,
+        custom: LowerStr,
+        inner: str,
+        error: UppercaseError,
+    };
+    { pub fn from_proof };
+}
+
+/// Repeats the string twice.
+///
+/// This preserves the validity, since the result consists of the lowercase input.
+fn repeat_twice(s: &str) -> String {
+    s.repeat(2)
+}
+
+#[cfg(test)]
+mod lower_string {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let s: LowerString = "foo".parse().expect("Should never fail");
+        assert_eq!(s, "foo");
+        assert_eq!(
+            "Foo".parse::<LowerString>(),
+            Err(UppercaseError { valid_up_to: 0 })
+        );
+    }
+
+    #[test]
+    fn from_normalized() {
+        use std::borrow::Cow;
+
+        let clean = LowerString::from_normalized("foo").expect("Should never fail");
+        assert!(matches!(clean, Cow::Borrowed(_)));
+        assert_eq!(*clean, *"foo");
+
+        let dirty = LowerString::from_normalized("FoO").expect("Should never fail");
+        assert!(matches!(dirty, Cow::Owned(_)));
+        assert_eq!(*dirty, *"foo");
+    }
+
+    #[test]
+    fn from_str_normalized() {
+        let clean = LowerString::from_str_normalized("foo").expect("Should never fail");
+        assert_eq!(clean, "foo");
+
+        let dirty = LowerString::from_str_normalized("FoO").expect("Should never fail");
+        assert_eq!(dirty, "foo");
+        // `FromStr` is kept strict.
+        assert!("FoO".parse::<LowerString>().is_err());
+    }
+
+    #[test]
+    fn cow_glue() {
+        use std::borrow::Cow;
+        use std::convert::TryFrom;
+
+        let borrowed = LowerString::try_from_cow(Cow::Borrowed("foo")).expect("Should never fail");
+        assert!(matches!(borrowed, Cow::Borrowed(_)));
+        let owned =
+            LowerString::try_from_cow(Cow::Owned("bar".to_owned())).expect("Should never fail");
+        assert!(matches!(owned, Cow::Owned(_)));
+        assert_eq!(
+            LowerString::try_from_cow(Cow::Borrowed("Foo")).map(|_| ()),
+            Err(UppercaseError { valid_up_to: 0 })
+        );
+
+        let normalized = LowerString::from_normalized("FoO").expect("Should never fail");
+        let inner: Cow<'_, str> = LowerString::into_inner_cow(normalized);
+        assert_eq!(inner, "foo");
+        assert!(matches!(
+            LowerString::into_inner_cow(borrowed),
+            Cow::Borrowed("foo")
+        ));
+
+        let s = LowerString::try_from(Cow::Borrowed("baz")).expect("Should never fail");
+        assert_eq!(s, "baz");
+        assert!(LowerString::try_from(Cow::<str>::Owned("Baz".to_owned())).is_err());
+    }
+
+    #[test]
+    fn transform() {
+        use std::convert::TryFrom;
+
+        let s = <&LowerStr>::try_from("foo").expect("Should never fail");
+        assert_eq!(s.doubled(), "foofoo");
+        assert_eq!(
+            s.to_ascii_uppercase(),
+            Err(UppercaseError { valid_up_to: 0 })
+        );
+        let digits = <&LowerStr>::try_from("42").expect("Should never fail");
+        assert_eq!(
+            digits.to_ascii_uppercase(),
+            Ok(LowerString::try_from("42").unwrap())
+        );
+    }
+
+    #[test]
+    fn replace() {
+        use std::convert::TryFrom;
+
+        let s = <&LowerStr>::try_from("foo-bar-foo").expect("Should never fail");
+        let from = <&LowerStr>::try_from("foo").expect("Should never fail");
+        let to = <&LowerStr>::try_from("baz").expect("Should never fail");
+        assert_eq!(s.replace(from, to), "baz-bar-baz");
+    }
+
+    #[test]
+    fn to_owned_with() {
+        use std::convert::TryFrom;
+
+        let s = <&LowerStr>::try_from("foo").expect("Should never fail");
+        assert_eq!(
+            s.to_owned_with(|s| s.repeat(2)),
+            Ok(LowerString::try_from("foofoo").unwrap())
+        );
+        assert_eq!(
+            s.to_owned_with(|s| format!("{}Bar", s)),
+            Err(UppercaseError { valid_up_to: 3 })
+        );
+    }
+
+    #[test]
+    fn to_owned() {
+        use std::convert::TryFrom;
+
+        let slice = <&LowerStr>::try_from("foo").expect("Should never fail");
+        let owned: LowerString = slice.to_owned();
+        assert_eq!(owned, *slice);
+        assert_eq!(format!("{}", owned), "foo");
+    }
+
+    #[test]
+    fn validated() {
+        use validated_slice::Validated;
+
+        let valid = Validated::<LowerStrSpec>::new("foo").expect("Should never fail");
+        let slice: &LowerStr = valid.into_custom();
+        let owned: LowerString = valid.into_owned::<LowerStringSpec>();
+        assert_eq!(owned, *slice);
+        assert_eq!(valid.as_inner(), "foo");
+        assert_eq!(
+            Validated::<LowerStrSpec>::new("fOo").map(Validated::into_custom),
+            Err(UppercaseError { valid_up_to: 1 })
+        );
+    }
+
+    #[test]
+    fn from_proof() {
+        use validated_slice::SliceSpec;
+
+        /// Creates the custom slice in generic code without `unsafe`.
+        fn generic_check<S: SliceSpec>(s: &S::Inner) -> Option<&S::Custom> {
+            S::check(s).ok().map(|proof| proof.into_custom())
+        }
+
+        let proof = LowerStrSpec::check("foo").expect("Should never fail");
+        let slice = LowerStr::from_proof(proof);
+        assert_eq!(LowerString::from_proof(proof), *slice);
+        assert!(generic_check::<LowerStrSpec>("bar").is_some());
+        assert!(generic_check::<LowerStrSpec>("Bar").is_none());
+    }
+
+    #[test]
+    fn from_cow() {
+        use std::borrow::Cow;
+        use std::convert::TryFrom;
+
+        let slice = <&LowerStr>::try_from("foo").expect("Should never fail");
+        assert_eq!(LowerString::from(Cow::Borrowed(slice)), "foo");
+
+        let owned: LowerString = "bar".parse().expect("Should never fail");
+        let ptr = owned.as_ptr();
+        let owned = LowerString::from(Cow::<LowerStr>::Owned(owned));
+        assert_eq!(owned, "bar");
+        assert_eq!(owned.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn into_inner_by_ref() {
+        use std::convert::TryFrom;
+
+        let owned: LowerString = "foo".parse().expect("Should never fail");
+        assert_eq!(String::from(&owned), "foo");
+        let slice = <&LowerStr>::try_from("bar").expect("Should never fail");
+        assert_eq!(String::from(slice), "bar");
+    }
+
+    #[test]
+    fn cmp_via() {
+        let s: LowerString = "foo".parse().expect("Should never fail");
+        let label = Label {
+            text: "foo".to_owned(),
+        };
+        assert_eq!(s, label);
+        assert_eq!(label, s);
+    }
+
+    #[test]
+    fn cmp_cow() {
+        use std::borrow::Cow;
+
+        let s: LowerString = "foo".parse().expect("Should never fail");
+        let cow: Cow<'_, str> = Cow::Borrowed("foo");
+        assert_eq!(s, cow);
+        assert_eq!(cow, *s);
+        assert!(Cow::<str>::Owned("bar".to_owned()) < s);
+    }
+
+    #[test]
+    fn debug() {
+        let s: LowerString = "foo".parse().expect("Should never fail");
+        assert_eq!(format!("{:?}", s), "LowerString(\"foo\")");
+    }
+}
+
+#[cfg(test)]
+mod lower_str_spec {
+    use super::*;
+
+    validated_slice::spec_tests!(
+        LowerStrSpec,
+        valid = ["", "foo", "foo bar", "\u{3042}"],
+        invalid = ["Foo", "fOO", "\u{c4}"],
+        owned = LowerStringSpec,
+    );
+}
@@ -0,0 +1,150 @@
+//! Mutation hooks.
+//!
+//! ASCII string with the cached number of uppercase letters, which is updated on mutation.
+
+mod common;
+
+use validated_slice::SliceSpec;
+
+use self::common::{AsciiError, AsciiStr, AsciiStrSpec};
+
+impl AsciiStr {
+    /// Converts the string to its uppercase equivalent in-place.
+    fn make_ascii_uppercase(&mut self) {
+        // Uppercase conversion keeps the string ASCII.
+        AsciiStrSpec::as_inner_mut(self).make_ascii_uppercase();
+    }
+}
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+        custom: AsciiStr,
+        inner: str,
+        error: AsciiError,
+    };
+    { Deref<Target = {Inner}> };
+}
+
+enum CountedAsciiStringSpec {}
+
+impl validated_slice::OwnedSliceSpec for CountedAsciiStringSpec {
+    type Custom = CountedAsciiString;
+    type Inner = String;
+    type Error = AsciiError;
+    type SliceSpec = AsciiStrSpec;
+    type SliceCustom = AsciiStr;
+    type SliceInner = str;
+    type SliceError = AsciiError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.text
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        let uppercase = count_uppercase(&s);
+        CountedAsciiString {
+            text: s,
+            uppercase,
+            mutations: 0,
+        }
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.text
+    }
+}
+
+impl validated_slice::OwnedSliceMutSpec for CountedAsciiStringSpec {
+    #[inline]
+    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
+        &mut s.text
+    }
+}
+
+impl validated_slice::MutationHookSpec for CountedAsciiStringSpec {
+    fn before_mutation(s: &mut Self::Custom) {
+        s.mutations += 1;
+    }
+
+    fn after_mutation(s: &mut Self::Custom) {
+        s.uppercase = count_uppercase(&s.text);
+    }
+}
+
+/// Returns the number of uppercase letters.
+fn count_uppercase(s: &str) -> usize {
+    s.bytes().filter(u8::is_ascii_uppercase).count()
+}
+
+/// ASCII string with the number of uppercase letters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountedAsciiString {
+    /// String.
+    text: String,
+    /// Number of uppercase letters.
+    uppercase: usize,
+    /// Number of mutations.
+    mutations: usize,
+}
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: CountedAsciiStringSpec,
+        custom: CountedAsciiString,
+        inner: String,
+        error: AsciiError,
+        slice_custom: AsciiStr,
+        slice_inner: str,
+        slice_error: AsciiError,
+    };
+    { TryFrom<{Inner}> };
+    { Deref<Target = {SliceCustom}> };
+}
+
+validated_slice::impl_methods_for_owned_slice! {
+    Spec {
+        spec: CountedAsciiStringSpec,
+        custom: CountedAsciiString,
+        inner: String,
+        error: AsciiError,
+        slice_custom: AsciiStr,
+        slice_inner: str,
+        slice_error: AsciiError,
+    };
+    { pub fn mutate };
+}
+
+#[cfg(test)]
+mod counted_ascii_string {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn mutate() {
+        let mut s = CountedAsciiString::try_from("Hello".to_owned()).expect("Should never fail");
+        assert_eq!(s.uppercase, 1);
+        {
+            let mut guard = s.mutate();
+            guard.make_ascii_uppercase();
+            assert_eq!(&**guard, "HELLO");
+        }
+        assert_eq!(s.uppercase, 5);
+        assert_eq!(s.mutations, 1);
+        assert_eq!(&**s, "HELLO");
+    }
+}
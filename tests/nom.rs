@@ -0,0 +1,119 @@
+//! `nom` integration.
+#![cfg(feature = "nom")]
+
+mod common;
+
+use validated_slice::ParseErrorKind;
+
+use self::common::{AsciiStr, AsciiStrSpec};
+
+// Any sub-slice of an ASCII string is also an ASCII string.
+unsafe impl validated_slice::SliceClosedSpec for AsciiStrSpec {}
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+        custom: AsciiStr,
+        inner: str,
+        error: common::AsciiError,
+    };
+    { AsRef<str> };
+    { nom::AsBytes };
+    { nom::Compare };
+    { nom::FindSubstring };
+    { nom::FindToken };
+    { nom::Input };
+    { nom::Offset };
+    { nom::ParseTo };
+}
+
+#[test]
+fn input_traits()
+where
+    for<'a> &'a AsciiStr: nom::AsBytes,
+    for<'a> &'a AsciiStr: nom::Compare<&'static str>,
+    for<'a> &'a AsciiStr: nom::FindSubstring<&'static str>,
+    for<'a> &'a AsciiStr: nom::FindToken<char>,
+    for<'a> &'a AsciiStr: nom::Input<Item = char>,
+    for<'a> &'a AsciiStr: nom::Offset,
+    for<'a> &'a AsciiStr: nom::ParseTo<u32>,
+{
+}
+
+#[test]
+fn parse() {
+    use nom::character::complete::{alpha1, char, digit1};
+    use nom::sequence::separated_pair;
+    use nom::{IResult, Parser};
+    use std::convert::TryFrom;
+
+    fn key_value(input: &AsciiStr) -> IResult<&AsciiStr, (&AsciiStr, &AsciiStr)> {
+        separated_pair(alpha1, char('='), digit1).parse(input)
+    }
+
+    let input = <&AsciiStr>::try_from("key=42;rest").expect("Should never fail");
+    let (rest, (key, value)) = key_value(input).expect("Should never fail");
+    assert_eq!(rest.as_ref(), ";rest");
+    assert_eq!(key.as_ref(), "key");
+    assert_eq!(nom::ParseTo::<u32>::parse_to(&value), Some(42));
+}
+
+enum KeyValueStrSpec {}
+
+impl validated_slice::SliceSpec for KeyValueStrSpec {
+    type Custom = KeyValueStr;
+    type Inner = str;
+    type Error = validated_slice::ParseError<nom::Err<nom::error::ErrorKind>>;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        use nom::character::complete::{alpha1, char, digit1};
+        use nom::sequence::separated_pair;
+        use nom::{IResult, Parser};
+
+        validated_slice::validate_by_parser(s, |s| -> IResult<&str, _> {
+            separated_pair(alpha1, char('='), digit1).parse(s)
+        })
+        // The error borrows the input, so keep only the error kind.
+        .map_err(|e| e.map(|e| e.map(|e| e.code)))
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// `key=value` string.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyValueStr(str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: KeyValueStrSpec,
+        custom: KeyValueStr,
+        inner: str,
+        error: validated_slice::ParseError<nom::Err<nom::error::ErrorKind>>,
+    };
+    { TryFrom<&{Inner}> for &{Custom} };
+}
+
+#[test]
+fn parser_backed_spec() {
+    use std::convert::TryFrom;
+
+    assert!(<&KeyValueStr>::try_from("key=42").is_ok());
+
+    let e = <&KeyValueStr>::try_from("key=").expect_err("Should never succeed");
+    assert_eq!(e.valid_up_to(), 4);
+    assert!(matches!(e.kind(), ParseErrorKind::Parser(_)));
+
+    let e = <&KeyValueStr>::try_from("key=42;rest").expect_err("Should never succeed");
+    assert_eq!(e.valid_up_to(), 6);
+    assert_eq!(e.kind(), &ParseErrorKind::TrailingInput);
+}
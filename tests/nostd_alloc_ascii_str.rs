@@ -43,7 +43,7 @@ pub struct AsciiError {
 // You can use `#[derive(Debug, PartialEq, PartialOrd)]` here, but in this example they are
 // implemented by macros in `validated_slice`, or implemented manually.
 #[repr(transparent)]
-#[derive(Eq, Ord, Hash)]
+#[derive(Eq)]
 pub struct AsciiStr(str);
 
 impl core::fmt::Debug for AsciiStr {
@@ -89,6 +89,10 @@ validated_slice::impl_std_traits_for_slice! {
     { Display };
     // Deref<Target = str> for Custom
     { Deref<Target = {Inner}> };
+    // Hash for AsciiStr
+    { Hash };
+    // Ord for AsciiStr
+    { Ord };
 }
 
 validated_slice::impl_cmp_for_slice! {
@@ -141,11 +145,6 @@ impl validated_slice::OwnedSliceSpec for AsciiBoxStrSpec {
         &s.0
     }
 
-    #[inline]
-    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
-        &mut s.0
-    }
-
     #[inline]
     fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
         s
@@ -162,8 +161,15 @@ impl validated_slice::OwnedSliceSpec for AsciiBoxStrSpec {
     }
 }
 
+impl validated_slice::OwnedSliceMutSpec for AsciiBoxStrSpec {
+    #[inline]
+    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
+        &mut s.0
+    }
+}
+
 /// ASCII string boxed slice.
-#[derive(Default, Clone, Eq, Ord, Hash)]
+#[derive(Default, Clone, Eq)]
 pub struct AsciiBoxStr(Box<str>);
 
 impl From<AsciiString> for AsciiBoxStr {
@@ -229,6 +235,10 @@ validated_slice::impl_std_traits_for_owned_slice! {
     { DerefMut<Target = {SliceCustom}> };
     // FromStr<Err = AsciiError> for AsciiBoxStr
     { FromStr };
+    // Hash for AsciiBoxStr
+    { Hash };
+    // Ord for AsciiBoxStr
+    { Ord };
 }
 
 validated_slice::impl_cmp_for_owned_slice! {
@@ -280,11 +290,6 @@ impl validated_slice::OwnedSliceSpec for AsciiStringSpec {
         &s.0
     }
 
-    #[inline]
-    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
-        &mut s.0
-    }
-
     #[inline]
     fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
         s
@@ -301,8 +306,15 @@ impl validated_slice::OwnedSliceSpec for AsciiStringSpec {
     }
 }
 
+impl validated_slice::OwnedSliceMutSpec for AsciiStringSpec {
+    #[inline]
+    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
+        &mut s.0
+    }
+}
+
 /// ASCII string boxed slice.
-#[derive(Default, Clone, Eq, Ord, Hash)]
+#[derive(Default, Clone, Eq)]
 pub struct AsciiString(String);
 
 impl From<AsciiBoxStr> for AsciiString {
@@ -368,6 +380,10 @@ validated_slice::impl_std_traits_for_owned_slice! {
     { DerefMut<Target = {SliceCustom}> };
     // FromStr<Err = AsciiError> for AsciiString
     { FromStr };
+    // Hash for AsciiString
+    { Hash };
+    // Ord for AsciiString
+    { Ord };
 }
 
 validated_slice::impl_cmp_for_owned_slice! {
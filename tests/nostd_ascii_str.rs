@@ -34,6 +34,17 @@ pub struct AsciiError {
     valid_up_to: usize,
 }
 
+validated_slice::impl_validation_error! {
+    Std {
+        core: core,
+        alloc: alloc_should_never_used,
+    };
+    Error {
+        ty: AsciiError,
+        message: "non-ASCII character found",
+    };
+}
+
 /// ASCII string slice.
 // `#[repr(transparent)]` or `#[repr(C)]` is required.
 // Without it, generated codes would be unsound.
@@ -41,7 +52,7 @@ pub struct AsciiError {
 // You can use `#[derive(Debug, PartialEq, PartialOrd)]` here, but in this example they are
 // implemented by macros in `validated_slice`, or implemented manually.
 #[repr(transparent)]
-#[derive(Eq, Ord, Hash)]
+#[derive(Eq)]
 pub struct AsciiStr(str);
 
 impl core::fmt::Debug for AsciiStr {
@@ -81,6 +92,10 @@ validated_slice::impl_std_traits_for_slice! {
     { Display };
     // Deref<Target = str> for Custom
     { Deref<Target = {Inner}> };
+    // Hash for AsciiStr
+    { Hash };
+    // Ord for AsciiStr
+    { Ord };
 }
 
 validated_slice::impl_cmp_for_slice! {
@@ -182,3 +197,15 @@ mod ascii_str {
     {
     }
 }
+
+#[cfg(test)]
+mod ascii_error {
+    use super::*;
+
+    #[test]
+    fn error() {
+        let e = AsciiError { valid_up_to: 1 };
+        assert_eq!(e.valid_up_to(), 1);
+        let _: &dyn core::error::Error = &e;
+    }
+}
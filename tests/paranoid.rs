@@ -0,0 +1,51 @@
+//! `paranoid` feature.
+#![cfg(feature = "paranoid")]
+
+mod common;
+
+use self::common::{AsciiStr, AsciiStrSpec, AsciiString, AsciiStringSpec};
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+    };
+    { AsRef<str> };
+    { Deref<Target = {Inner}> };
+}
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+    };
+    { Borrow<{SliceCustom}> };
+    { ToOwned<Owned = {Custom}> for {SliceCustom} };
+    { Deref<Target = {SliceCustom}> };
+}
+
+#[cfg(test)]
+mod paranoid {
+    use super::*;
+
+    use std::borrow::Borrow;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn valid() {
+        let s = AsciiString::try_from("foo".to_owned()).expect("Should never fail");
+        let slice: &AsciiStr = s.borrow();
+        assert_eq!(&**slice, "foo");
+        assert_eq!(slice.to_owned(), s);
+    }
+
+    #[test]
+    #[should_panic(expected = "paranoid check")]
+    fn invalid() {
+        // Break the invariant intentionally.
+        let s = unsafe {
+            <AsciiStringSpec as validated_slice::OwnedSliceSpec>::from_inner_unchecked(
+                "f\u{f6}\u{f6}".to_owned(),
+            )
+        };
+        let _: &AsciiStr = s.borrow();
+    }
+}
@@ -32,7 +32,7 @@ impl validated_slice::SliceSpec for PlainStrSpec {
 // You can use `#[derive(Debug, PartialEq, PartialOrd)]` here, but in this example they are
 // implemented by macros in `validated_slice`.
 #[repr(transparent)]
-#[derive(Eq, Ord, Hash)]
+#[derive(Eq)]
 pub struct PlainStr(str);
 
 validated_slice::impl_std_traits_for_slice! {
@@ -42,6 +42,8 @@ validated_slice::impl_std_traits_for_slice! {
         inner: str,
         error: std::convert::Infallible,
     };
+    // Panicking targets are allowed, because the validation never fails.
+    Check { NoPanic };
     // AsMut<str> for PlainStr
     // NOTE: `AsMut<[u8]> for str` is not implemented.
     //{ AsMut<str> };
@@ -57,6 +59,8 @@ validated_slice::impl_std_traits_for_slice! {
     { From<&{Inner}> for &{Custom} };
     // From<&'_ mut str> for &'_ mut PlainStr
     { From<&mut {Inner}> for &mut {Custom} };
+    // From<&'_ mut Box<str>> for &'_ mut Box<PlainStr>
+    { From<&mut Box<{Inner}>> for &mut Box<{Custom}> };
     // From<&'_ PlainStr> for &'_ str
     { From<&{Custom}> for &{Inner} };
     // From<&'_ mut PlainStr> for &'_ mut str
@@ -79,6 +83,10 @@ validated_slice::impl_std_traits_for_slice! {
     { Deref<Target = {Inner}> };
     // DerefMut<Target = str> for PlainStr
     { DerefMut<Target = {Inner}> };
+    // Hash for PlainStr
+    { Hash };
+    // Ord for PlainStr
+    { Ord };
 }
 
 validated_slice::impl_cmp_for_slice! {
@@ -127,11 +135,6 @@ impl validated_slice::OwnedSliceSpec for PlainBoxStrSpec {
         &s.0
     }
 
-    #[inline]
-    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
-        &mut s.0
-    }
-
     #[inline]
     fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
         s
@@ -148,8 +151,15 @@ impl validated_slice::OwnedSliceSpec for PlainBoxStrSpec {
     }
 }
 
+impl validated_slice::OwnedSliceMutSpec for PlainBoxStrSpec {
+    #[inline]
+    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
+        &mut s.0
+    }
+}
+
 /// ASCII string boxed slice.
-#[derive(Default, Clone, Eq, Ord, Hash)]
+#[derive(Default, Clone, Eq)]
 pub struct PlainBoxStr(Box<str>);
 
 impl From<PlainString> for PlainBoxStr {
@@ -168,6 +178,8 @@ validated_slice::impl_std_traits_for_owned_slice! {
         slice_inner: str,
         slice_error: std::convert::Infallible,
     };
+    // Panicking targets are allowed, because the validation never fails.
+    Check { NoPanic };
     // AsMut<str> for PlainBoxStr
     // NOTE: `AsMut<[u8]> for str` is not implemented.
     //{ AsMut<str> };
@@ -213,6 +225,10 @@ validated_slice::impl_std_traits_for_owned_slice! {
     { DerefMut<Target = {SliceCustom}> };
     // FromStr<Err = std::convert::Infallible> for PlainBoxStr
     { FromStr };
+    // Hash for PlainBoxStr
+    { Hash };
+    // Ord for PlainBoxStr
+    { Ord };
 }
 
 validated_slice::impl_cmp_for_owned_slice! {
@@ -260,11 +276,6 @@ impl validated_slice::OwnedSliceSpec for PlainStringSpec {
         &s.0
     }
 
-    #[inline]
-    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
-        &mut s.0
-    }
-
     #[inline]
     fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
         s
@@ -281,8 +292,15 @@ impl validated_slice::OwnedSliceSpec for PlainStringSpec {
     }
 }
 
+impl validated_slice::OwnedSliceMutSpec for PlainStringSpec {
+    #[inline]
+    fn as_slice_inner_mut(s: &mut Self::Custom) -> &mut Self::SliceInner {
+        &mut s.0
+    }
+}
+
 /// ASCII string boxed slice.
-#[derive(Default, Clone, Eq, Ord, Hash)]
+#[derive(Default, Clone, Eq)]
 pub struct PlainString(String);
 
 impl From<PlainBoxStr> for PlainString {
@@ -301,9 +319,12 @@ validated_slice::impl_std_traits_for_owned_slice! {
         slice_inner: str,
         slice_error: std::convert::Infallible,
     };
-    // AsMut<str> for PlainString
+    // Panicking targets are allowed, because the validation never fails.
+    Check { NoPanic };
     // NOTE: `AsMut<[u8]> for str` is not implemented.
-    //{ AsMut<str> };
+    //{ AsMut<[u8]> };
+    // AsMut<str> for PlainString
+    { AsMut<str> };
     // AsMut<PlainStr> for PlainString
     { AsMut<{SliceCustom}> };
     // AsRef<[u8]> for PlainString
@@ -344,6 +365,12 @@ validated_slice::impl_std_traits_for_owned_slice! {
     { DerefMut<Target = {SliceCustom}> };
     // FromStr<Err = std::convert::Infallible> for PlainString
     { FromStr };
+    // Index<Range<usize>, Output = PlainStr> for PlainString
+    { Index<Range<usize>, Output = {SliceCustom}>(revalidate) };
+    // Hash for PlainString
+    { Hash };
+    // Ord for PlainString
+    { Ord };
 }
 
 validated_slice::impl_cmp_for_owned_slice! {
@@ -418,6 +445,7 @@ mod plain_str {
         for<'a> &'a mut PlainStr: From<&'a mut str>,
         for<'a> &'a str: From<&'a PlainStr>,
         for<'a> &'a mut str: From<&'a mut PlainStr>,
+        for<'a> &'a mut Box<PlainStr>: From<&'a mut Box<str>>,
     {
     }
 
@@ -614,6 +642,13 @@ mod plain_string {
     {
     }
 
+    #[test]
+    fn as_mut_inner() {
+        let mut s = PlainString("hello".to_owned());
+        AsMut::<str>::as_mut(&mut s).make_ascii_uppercase();
+        assert_eq!(s.0, "HELLO");
+    }
+
     #[test]
     fn borrow()
     where
@@ -745,4 +780,9 @@ mod plain_string {
         PlainString: std::str::FromStr<Err = std::convert::Infallible>,
     {
     }
+    #[test]
+    fn index_revalidate() {
+        let s = PlainString("hello".to_owned());
+        assert_eq!(&s[1..3], "el");
+    }
 }
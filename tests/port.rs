@@ -0,0 +1,142 @@
+//! Port number.
+//!
+//! A sized value type for non-zero TCP/UDP port numbers.
+
+use std::num::ParseIntError;
+
+enum PortSpec {}
+
+// `Port` has no invariants other than the validity of the inner value.
+unsafe impl validated_slice::TrustedSpec for PortSpec {}
+
+impl validated_slice::ValueSpec for PortSpec {
+    type Custom = Port;
+    type Inner = u16;
+    type Error = PortError;
+
+    fn validate(v: &Self::Inner) -> Result<(), Self::Error> {
+        if *v == 0 {
+            return Err(PortError::Zero);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn as_inner(v: &Self::Custom) -> &Self::Inner {
+        &v.0
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(v: Self::Inner) -> Self::Custom {
+        Port(v)
+    }
+
+    #[inline]
+    fn into_inner(v: Self::Custom) -> Self::Inner {
+        v.0
+    }
+}
+
+/// Port number error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PortError {
+    /// Port number is zero.
+    Zero,
+    /// Failed to parse a string as an integer.
+    Parse(ParseIntError),
+}
+
+impl From<ParseIntError> for PortError {
+    fn from(e: ParseIntError) -> Self {
+        PortError::Parse(e)
+    }
+}
+
+/// Non-zero port number.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Port(u16);
+
+validated_slice::impl_std_traits_for_value! {
+    Spec {
+        spec: PortSpec,
+        custom: Port,
+        inner: u16,
+        error: PortError,
+    };
+    Check { Trusted };
+    { AsRef<{Inner}> };
+    { From<{Custom}> for {Inner} };
+    { TryFrom<{Inner}> };
+    { TryFrom<&{Inner}> };
+    { Debug(with = fmt_port_debug) };
+    { LowerHex };
+    { UpperHex };
+    { Binary };
+    { Octal };
+    { Display for {Custom} };
+    { Deref<Target = {Inner}> };
+    { FromStr };
+}
+
+fn fmt_port_debug(v: &u16, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, ":{}", v)
+}
+
+validated_slice::impl_cmp_for_value! {
+    Spec {
+        spec: PortSpec,
+        custom: Port,
+        inner: u16,
+        base: Inner,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), ({Inner}), rev };
+    { ({Custom}), (&{Inner}), rev };
+    { (&{Custom}), ({Inner}), rev };
+}
+
+#[cfg(test)]
+mod port {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        assert_eq!(Port::try_from(80).map(u16::from), Ok(80));
+        assert_eq!(Port::try_from(&443).map(u16::from), Ok(443));
+        assert_eq!(Port::try_from(0), Err(PortError::Zero));
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("8080".parse::<Port>(), Port::try_from(8080));
+        assert_eq!("0".parse::<Port>(), Err(PortError::Zero));
+        assert!(matches!("65536".parse::<Port>(), Err(PortError::Parse(_))));
+    }
+
+    #[test]
+    fn fmt() {
+        let port = Port::try_from(80).expect("Should never fail");
+        assert_eq!(format!("{:?}", port), ":80");
+        assert_eq!(format!("{:#06x}", port), "0x0050");
+        assert_eq!(
+            format!("{:X}", Port::try_from(443).expect("Should never fail")),
+            "1BB"
+        );
+        assert_eq!(format!("{:b}", port), "1010000");
+        assert_eq!(format!("{:o}", port), "120");
+        assert_eq!(format!("{}", port), "80");
+    }
+
+    #[test]
+    fn cmp() {
+        let port = Port::try_from(80).expect("Should never fail");
+        assert_eq!(port, 80);
+        assert_eq!(80, port);
+        assert_eq!(&port, 80);
+        assert!(port < 443);
+        assert_eq!(&80, port);
+        assert_eq!(*port + 1, 81);
+    }
+}
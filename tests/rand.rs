@@ -0,0 +1,61 @@
+//! `rand` integration.
+#![cfg(feature = "rand")]
+
+mod common;
+
+use self::common::{AsciiString, AsciiStringSpec};
+
+impl validated_slice::GenerateSpec for AsciiStringSpec {
+    fn generate<R: rand::Rng + ?Sized>(rng: &mut R) -> Self::Inner {
+        let len = rng.random_range(0..16);
+        (0..len)
+            .map(|_| char::from(rng.random::<u8>() & 0x7f))
+            .collect()
+    }
+}
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+    };
+    { rand::Distribution };
+}
+
+/// Simple xorshift RNG for deterministic tests.
+struct XorShift(u64);
+
+impl rand::RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+#[test]
+fn distribution()
+where
+    rand::distr::StandardUniform: rand::distr::Distribution<AsciiString>,
+{
+    use rand::Rng;
+    use std::convert::TryFrom;
+
+    let mut rng = XorShift(0x1234_5678_9abc_def0);
+    for _ in 0..64 {
+        let value: AsciiString = rng.random();
+        let inner = <AsciiStringSpec as validated_slice::OwnedSliceSpec>::into_inner(value.clone());
+        assert_eq!(AsciiString::try_from(inner), Ok(value));
+    }
+}
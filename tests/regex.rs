@@ -0,0 +1,74 @@
+//! `regex` integration.
+#![cfg(feature = "regex")]
+
+use validated_slice::RegexMismatchError;
+
+validated_slice::regex_spec!(
+    IdentSpec,
+    /// Identifier.
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub IdentStr,
+    r"^[A-Za-z_][A-Za-z0-9_]*$"
+);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: IdentSpec,
+        custom: IdentStr,
+        inner: str,
+        error: RegexMismatchError,
+    };
+    { AsRef<str> };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Deref<Target = {Inner}> };
+}
+
+validated_slice::regex_spec!(
+    VersionSpec,
+    /// Version string without anchors in the pattern.
+    #[derive(Debug)]
+    pub VersionStr,
+    r"[0-9]+\.[0-9]+"
+);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: VersionSpec,
+        custom: VersionStr,
+        inner: str,
+        error: RegexMismatchError,
+    };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Deref<Target = {Inner}> };
+}
+
+#[cfg(test)]
+mod ident_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        let ident = <&IdentStr>::try_from("foo_bar").expect("Should never fail");
+        assert_eq!(&**ident, "foo_bar");
+        let err = <&IdentStr>::try_from("0foo").expect_err("Should never succeed");
+        assert_eq!(err.valid_up_to(), 0);
+    }
+}
+
+#[cfg(test)]
+mod version_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn whole_match() {
+        assert!(<&VersionStr>::try_from("1.42").is_ok());
+        let err = <&VersionStr>::try_from("1.42-beta").expect_err("Should never succeed");
+        assert_eq!(err.valid_up_to(), 4);
+        let err = <&VersionStr>::try_from("v1.42").expect_err("Should never succeed");
+        assert_eq!(err.valid_up_to(), 0);
+    }
+}
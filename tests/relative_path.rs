@@ -0,0 +1,231 @@
+//! Relative path.
+//!
+//! Types for `Path` and `PathBuf` which do not have roots.
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+enum RelativePathSpec {}
+
+impl validated_slice::SliceSpec for RelativePathSpec {
+    type Custom = RelativePath;
+    type Inner = Path;
+    type Error = AbsolutePathError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        if s.has_root() {
+            return Err(AbsolutePathError);
+        }
+        Ok(())
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Absolute path error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AbsolutePathError;
+
+/// Relative path slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct RelativePath(Path);
+
+validated_slice::impl_traits_for_slice! {
+    Spec {
+        spec: RelativePathSpec,
+        custom: RelativePath,
+        inner: Path,
+        error: AbsolutePathError,
+    };
+    Traits {
+        { AsRef<Path> };
+        { AsRef<{Custom}> };
+        { From<&{Custom}> for &{Inner} };
+        // NOTE: `AsRef<OsStr>` conflicts with this, as `PathBuf: From<&T>` is implemented for any
+        // `T: AsRef<OsStr>`.
+        { From<&{Custom}> for PathBuf };
+        { From<&{Custom}> for Box<{Custom}> };
+        { TryFrom<&{Inner}> for &{Custom} };
+        { Deref<Target = {Inner}> };
+    };
+    Cmp(base = Inner) { PartialEq, PartialOrd } {
+        { ({Custom}), ({Custom}) };
+        { ({Custom}), (&{Custom}), rev };
+        { ({Custom}), ({Inner}), rev };
+        { ({Custom}), (&{Inner}), rev };
+    };
+}
+
+impl Eq for RelativePath {}
+
+impl Ord for RelativePath {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+enum RelativePathBufSpec {}
+
+impl validated_slice::OwnedSliceSpec for RelativePathBufSpec {
+    type Custom = RelativePathBuf;
+    type Inner = PathBuf;
+    type Error = AbsolutePathBufError;
+    type SliceSpec = RelativePathSpec;
+    type SliceCustom = RelativePath;
+    type SliceInner = Path;
+    type SliceError = AbsolutePathError;
+
+    #[inline]
+    fn convert_validation_error(_: Self::SliceError, v: Self::Inner) -> Self::Error {
+        AbsolutePathBufError(v)
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        RelativePathBuf(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// Absolute path buffer error.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AbsolutePathBufError(PathBuf);
+
+/// Relative path buffer.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RelativePathBuf(PathBuf);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: RelativePathBufSpec,
+        custom: RelativePathBuf,
+        inner: PathBuf,
+        error: AbsolutePathBufError,
+        slice_custom: RelativePath,
+        slice_inner: Path,
+        slice_error: AbsolutePathError,
+    };
+    { Borrow<{SliceCustom}> };
+    { ToOwned<Owned = {Custom}> for {SliceCustom} };
+    { AsRef<Path> };
+    { AsRef<OsStr> };
+    { AsRef<{SliceCustom}> };
+    { From<&{SliceCustom}> };
+    { From<{Custom}> for PathBuf };
+    { TryFrom<&{SliceInner}> };
+    { TryFrom<{Inner}> };
+    { Deref<Target = {SliceCustom}> };
+    { FromStr };
+}
+
+#[cfg(test)]
+mod relative_path {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn as_ref()
+    where
+        RelativePath: AsRef<Path>,
+    {
+        let path = <&RelativePath>::try_from(Path::new("foo/bar")).expect("Should never fail");
+        assert_eq!(AsRef::<Path>::as_ref(path), Path::new("foo/bar"));
+    }
+
+    #[test]
+    fn try_from() {
+        assert!(<&RelativePath>::try_from(Path::new("foo/bar")).is_ok());
+        assert_eq!(
+            <&RelativePath>::try_from(Path::new("/foo/bar")),
+            Err(AbsolutePathError)
+        );
+    }
+
+    #[test]
+    fn into_path_buf()
+    where
+        for<'a> PathBuf: From<&'a RelativePath>,
+    {
+        let path = <&RelativePath>::try_from(Path::new("foo/bar")).expect("Should never fail");
+        assert_eq!(PathBuf::from(path), Path::new("foo/bar").to_path_buf());
+    }
+
+    #[test]
+    fn cmp() {
+        let path = <&RelativePath>::try_from(Path::new("foo/bar")).expect("Should never fail");
+        assert_eq!(path, Path::new("foo/bar"));
+        assert!(*path < *Path::new("foo/baz"));
+    }
+}
+
+#[cfg(test)]
+mod relative_path_buf {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn from_str()
+    where
+        RelativePathBuf: std::str::FromStr + AsRef<Path> + AsRef<OsStr>,
+    {
+        let path: RelativePathBuf = "foo/bar".parse().expect("Should never fail");
+        assert_eq!(AsRef::<Path>::as_ref(&path), Path::new("foo/bar"));
+        assert_eq!(AsRef::<OsStr>::as_ref(&path), OsStr::new("foo/bar"));
+        assert_eq!(
+            "/foo/bar".parse::<RelativePathBuf>(),
+            Err(AbsolutePathError)
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        assert!(RelativePathBuf::try_from(PathBuf::from("foo/bar")).is_ok());
+        assert_eq!(
+            RelativePathBuf::try_from(PathBuf::from("/foo/bar")),
+            Err(AbsolutePathBufError(PathBuf::from("/foo/bar")))
+        );
+    }
+
+    #[test]
+    fn into_path_buf()
+    where
+        PathBuf: From<RelativePathBuf>,
+    {
+        let path = RelativePathBuf::try_from(Path::new("foo/bar")).expect("Should never fail");
+        assert_eq!(PathBuf::from(path), PathBuf::from("foo/bar"));
+    }
+
+    #[test]
+    fn to_owned() {
+        let path = <&RelativePath>::try_from(Path::new("foo/bar")).expect("Should never fail");
+        let owned: RelativePathBuf = path.to_owned();
+        assert_eq!(&*owned, path);
+    }
+}
@@ -0,0 +1,43 @@
+//! `rkyv` integration.
+#![cfg(feature = "rkyv")]
+
+mod common;
+
+use self::common::{AsciiString, AsciiStringSpec};
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+    };
+    { rkyv::Archive };
+    { rkyv::Serialize };
+    { rkyv::Deserialize };
+}
+
+#[test]
+fn roundtrip() {
+    use std::convert::TryFrom;
+
+    let value = AsciiString::try_from("ascii".to_owned()).expect("Should never fail");
+    let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&value).expect("Should never fail");
+    let archived = unsafe {
+        // This is safe because `bytes` is created by `rkyv::to_bytes` for `AsciiString`.
+        rkyv::access_unchecked::<rkyv::Archived<AsciiString>>(&bytes)
+    };
+    assert_eq!(&**archived, "ascii");
+    let deserialized =
+        rkyv::deserialize::<AsciiString, rkyv::rancor::Error>(archived).expect("Should never fail");
+    assert_eq!(deserialized, value);
+}
+
+#[test]
+fn deserialize_invalid() {
+    // The archived form of `AsciiString` is same as that of `Box<str>`.
+    let value: Box<str> = "non-ascii: \u{3042}".into();
+    let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&value).expect("Should never fail");
+    let archived = unsafe {
+        // This is safe because `bytes` is created by `rkyv::to_bytes` for `Box<str>`.
+        rkyv::access_unchecked::<rkyv::Archived<AsciiString>>(&bytes)
+    };
+    assert!(rkyv::deserialize::<AsciiString, rkyv::rancor::Error>(archived).is_err());
+}
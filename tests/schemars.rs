@@ -0,0 +1,47 @@
+//! `schemars` integration.
+#![cfg(feature = "schemars")]
+
+mod common;
+
+use self::common::{AsciiStr, AsciiStrSpec, AsciiString, AsciiStringSpec};
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+    };
+    { JsonSchema(spec) };
+}
+
+impl validated_slice::JsonSchemaSpec for AsciiStrSpec {
+    const FORMAT: Option<&'static str> = Some("ascii");
+    const PATTERN: Option<&'static str> = Some("^[\\x00-\\x7f]*$");
+}
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+    };
+    { JsonSchema(spec) };
+}
+
+#[test]
+fn json_schema_borrowed() {
+    let schema = schemars::schema_for!(AsciiStr);
+    assert_eq!(schema.get("type").and_then(|v| v.as_str()), Some("string"));
+    assert_eq!(schema.get("format").and_then(|v| v.as_str()), Some("ascii"));
+    assert_eq!(
+        schema.get("pattern").and_then(|v| v.as_str()),
+        Some("^[\\x00-\\x7f]*$")
+    );
+}
+
+#[test]
+fn json_schema_owned() {
+    let schema = schemars::schema_for!(AsciiString);
+    assert_eq!(schema.get("type").and_then(|v| v.as_str()), Some("string"));
+    assert_eq!(schema.get("format").and_then(|v| v.as_str()), Some("ascii"));
+    assert_eq!(
+        schema.get("pattern").and_then(|v| v.as_str()),
+        Some("^[\\x00-\\x7f]*$")
+    );
+}
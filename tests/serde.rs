@@ -0,0 +1,149 @@
+//! `serde` integration.
+#![cfg(feature = "serde")]
+
+mod common;
+
+use serde::{Deserialize, Serialize};
+
+use self::common::{AsciiError, AsciiStr, AsciiStrSpec, AsciiString, AsciiStringSpec};
+
+validated_slice::impl_traits_for_slice_pair! {
+    Slice {
+        Spec {
+            spec: AsciiStrSpec,
+            custom: AsciiStr,
+            inner: str,
+            error: AsciiError,
+        };
+        Traits {
+            { AsRef<str> };
+            { Display };
+        };
+    };
+    Owned {
+        Spec {
+            spec: AsciiStringSpec,
+            custom: AsciiString,
+            inner: String,
+            error: AsciiError,
+            slice_custom: AsciiStr,
+            slice_inner: str,
+            slice_error: AsciiError,
+        };
+        Traits {
+            { AsRef<str> };
+            { FromStr };
+            { Display };
+        };
+    };
+}
+
+/// Record with ASCII string fields.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    /// Field (de)serialized by `Display` and `FromStr`.
+    #[serde(with = "validated_slice::serde_adapter::display_from_str")]
+    name: AsciiString,
+    /// Field (de)serialized by `AsRef<str>` and `TryFrom<String>`.
+    #[serde(with = "validated_slice::serde_adapter::try_from_string")]
+    comment: AsciiString,
+}
+
+impl validated_slice::serde_adapter::Sanitize for AsciiString {
+    fn sanitize(s: String) -> String {
+        s.trim()
+            .chars()
+            .map(|c| if c.is_ascii() { c } else { '?' })
+            .collect()
+    }
+}
+
+validated_slice::impl_methods_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+        custom: AsciiString,
+        inner: String,
+        error: AsciiError,
+        slice_custom: AsciiStr,
+        slice_inner: str,
+        slice_error: AsciiError,
+    };
+    { mod ascii_string_serde(serde) };
+}
+
+/// Record with ASCII string field, (de)serialized by the generated module.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ModuleRecord {
+    #[serde(with = "ascii_string_serde")]
+    name: AsciiString,
+}
+
+/// Record with ASCII string fields, deserialized leniently.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct LenientRecord {
+    #[serde(with = "validated_slice::serde_adapter::sanitized_string")]
+    name: AsciiString,
+}
+
+#[cfg(test)]
+mod serde_adapter {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn round_trip() {
+        let record = Record {
+            name: AsciiString::try_from("alice".to_owned()).expect("Should never fail"),
+            comment: AsciiString::try_from("hello".to_owned()).expect("Should never fail"),
+        };
+        let json = serde_json::to_string(&record).expect("Should never fail");
+        assert_eq!(json, r#"{"name":"alice","comment":"hello"}"#);
+        let decoded: Record = serde_json::from_str(&json).expect("Should never fail");
+        assert_eq!(decoded, record);
+    }
+
+    #[test]
+    fn invalid() {
+        let e = serde_json::from_str::<Record>(r#"{"name":"é","comment":""}"#)
+            .expect_err("Should never succeed");
+        assert!(e.to_string().contains("invalid ASCII byte at 0"));
+        assert!(serde_json::from_str::<Record>(r#"{"name":"","comment":"é"}"#).is_err());
+    }
+
+    #[test]
+    fn sanitized() {
+        let record: LenientRecord =
+            serde_json::from_str(r#"{"name":" caf\u00e9 "}"#).expect("Should never fail");
+        assert_eq!(record.name.as_ref(), "caf?");
+        let json = serde_json::to_string(&record).expect("Should never fail");
+        assert_eq!(json, r#"{"name":"caf?"}"#);
+    }
+
+    #[test]
+    fn helper_module() {
+        let record = ModuleRecord {
+            name: AsciiString::try_from("alice".to_owned()).expect("Should never fail"),
+        };
+        let json = serde_json::to_string(&record).expect("Should never fail");
+        assert_eq!(json, r#"{"name":"alice"}"#);
+        let decoded: ModuleRecord = serde_json::from_str(&json).expect("Should never fail");
+        assert_eq!(decoded, record);
+        let e = serde_json::from_str::<ModuleRecord>(r#"{"name":"é"}"#)
+            .expect_err("Should never succeed");
+        assert!(e.to_string().contains("invalid ASCII byte at 0"));
+    }
+
+    #[test]
+    fn trusted() {
+        use serde::de::DeserializeSeed;
+        use validated_slice::serde_adapter::TrustedSeed;
+
+        // The input is known to be valid.
+        let seed = unsafe { TrustedSeed::<AsciiStringSpec>::new() };
+        let value = seed
+            .deserialize(&mut serde_json::Deserializer::from_str(r#""alice""#))
+            .expect("Should never fail");
+        assert_eq!(value.as_ref(), "alice");
+    }
+}
@@ -0,0 +1,141 @@
+//! Shared ASCII string.
+//!
+//! The owned inner type is immutable `Arc<str>`, so the spec does not implement
+//! `OwnedSliceMutSpec`.
+
+use std::sync::Arc;
+
+enum AsciiStrSpec {}
+
+impl validated_slice::SliceSpec for AsciiStrSpec {
+    type Custom = AsciiStr;
+    type Inner = str;
+    type Error = AsciiError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        match s.as_bytes().iter().position(|b| !b.is_ascii()) {
+            Some(pos) => Err(AsciiError { valid_up_to: pos }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// ASCII string validation error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsciiError {
+    /// Byte position of the first invalid byte.
+    valid_up_to: usize,
+}
+
+/// ASCII string slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiStr(str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+        custom: AsciiStr,
+        inner: str,
+        error: AsciiError,
+    };
+    { AsRef<str> };
+    { TryFrom<&{Inner}> for &{Custom} };
+}
+
+enum SharedAsciiStrSpec {}
+
+impl validated_slice::OwnedSliceSpec for SharedAsciiStrSpec {
+    type Custom = SharedAsciiStr;
+    type Inner = Arc<str>;
+    type Error = AsciiError;
+    type SliceSpec = AsciiStrSpec;
+    type SliceCustom = AsciiStr;
+    type SliceInner = str;
+    type SliceError = AsciiError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        SharedAsciiStr(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// Shared ASCII string.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SharedAsciiStr(Arc<str>);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: SharedAsciiStrSpec,
+        custom: SharedAsciiStr,
+        inner: Arc<str>,
+        error: AsciiError,
+        slice_custom: AsciiStr,
+        slice_inner: str,
+        slice_error: AsciiError,
+    };
+    { Borrow<{SliceCustom}> };
+    { AsRef<str> };
+    { From<&{SliceCustom}> };
+    { TryFrom<&{SliceInner}> };
+    { TryFrom<{Inner}> };
+    { Deref<Target = {SliceCustom}> };
+}
+
+#[cfg(test)]
+mod shared_ascii_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        let s = SharedAsciiStr::try_from("foo").expect("Should never fail");
+        assert_eq!(s.as_ref(), "foo");
+        assert_eq!(
+            SharedAsciiStr::try_from(Arc::<str>::from("caf\u{e9}")),
+            Err(AsciiError { valid_up_to: 3 })
+        );
+    }
+
+    #[test]
+    fn shared() {
+        let s = SharedAsciiStr::try_from("foo").expect("Should never fail");
+        let cloned = s.clone();
+        assert!(Arc::ptr_eq(&s.0, &cloned.0));
+        let borrowed: &AsciiStr = &cloned;
+        assert_eq!(SharedAsciiStr::from(borrowed), s);
+    }
+}
@@ -0,0 +1,109 @@
+//! Short name.
+//!
+//! Length constraints are layered on the spec for the characters.
+
+use validated_slice::{LenError, MaxLen, NonEmpty};
+
+enum NameCharsSpec {}
+
+impl validated_slice::SliceSpec for NameCharsSpec {
+    type Custom = ShortName;
+    type Inner = str;
+    type Error = NonAlphabeticError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        match s.bytes().position(|b| !b.is_ascii_alphabetic()) {
+            Some(pos) => Err(NonAlphabeticError { valid_up_to: pos }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Spec of `ShortName`.
+type ShortNameSpec = MaxLen<8, NonEmpty<NameCharsSpec>>;
+
+/// Validation error of `ShortName`.
+type ShortNameError = LenError<LenError<NonAlphabeticError>>;
+
+/// Non-alphabetic character error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonAlphabeticError {
+    /// Byte position of the first non-alphabetic character.
+    valid_up_to: usize,
+}
+
+/// Short name, which consists of 1 to 8 ASCII alphabets.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShortName(str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: ShortNameSpec,
+        custom: ShortName,
+        inner: str,
+        error: ShortNameError,
+    };
+    { AsRef<str> };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Deref<Target = {Inner}> };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: ShortNameSpec,
+        custom: ShortName,
+        inner: str,
+        error: ShortNameError,
+    };
+    { pub fn first_char };
+    { pub fn last_char };
+}
+
+#[cfg(test)]
+mod short_name {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        assert_eq!(
+            <&ShortName>::try_from("alice").map(AsRef::as_ref),
+            Ok("alice")
+        );
+        assert_eq!(
+            <&ShortName>::try_from(""),
+            Err(LenError::Inner(LenError::TooShort { min: 1, len: 0 }))
+        );
+        assert_eq!(
+            <&ShortName>::try_from("bartholomew"),
+            Err(LenError::TooLong { max: 8, len: 11 })
+        );
+        assert_eq!(
+            <&ShortName>::try_from("bob1"),
+            Err(LenError::Inner(LenError::Inner(NonAlphabeticError {
+                valid_up_to: 3
+            })))
+        );
+    }
+
+    #[test]
+    fn first_and_last_char() {
+        let name = <&ShortName>::try_from("Alice").expect("Should never fail");
+        assert_eq!(name.first_char(), 'A');
+        assert_eq!(name.last_char(), 'e');
+    }
+}
@@ -0,0 +1,504 @@
+//! Sorted `u32` slice.
+//!
+//! Types for slices whose elements are sorted in ascending order.
+
+use std::ops::Range;
+
+enum SortedU32SliceSpec {}
+
+impl validated_slice::SliceSpec for SortedU32SliceSpec {
+    type Custom = SortedU32Slice;
+    type Inner = [u32];
+    type Error = UnsortedError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        match s.windows(2).position(|w| w[0] > w[1]) {
+            Some(pos) => Err(UnsortedError {
+                sorted_up_to: pos + 1,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+// Any sub-slice of a sorted slice is also sorted.
+unsafe impl validated_slice::SliceClosedSpec for SortedU32SliceSpec {}
+
+// The slice was sorted before the edit, so only the neighborhood of the region can be unsorted.
+unsafe impl validated_slice::RevalidateRegionSpec for SortedU32SliceSpec {
+    fn revalidate_region(s: &Self::Inner, edited: Range<usize>) -> Result<(), Self::Error> {
+        let start = edited.start.saturating_sub(1);
+        let end = (edited.end + 1).min(s.len());
+        match s[start..end].windows(2).position(|w| w[0] > w[1]) {
+            Some(pos) => Err(UnsortedError {
+                sorted_up_to: start + pos + 1,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl validated_slice::SortedSliceSpec for SortedU32SliceSpec {}
+
+/// Unsorted slice error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnsortedError {
+    /// Number of leading elements which are sorted.
+    sorted_up_to: usize,
+}
+
+/// Sorted `u32` slice.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedU32Slice([u32]);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: SortedU32SliceSpec,
+        custom: SortedU32Slice,
+        inner: [u32],
+        error: UnsortedError,
+    };
+    { AsRef<[u32]> };
+    { From<&{Custom}> for &{Inner} };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { TryFrom<&mut {Inner}> for &mut {Custom} };
+    { From<&{Custom}> for Arc<{Custom}> };
+    { From<&{Custom}> for Rc<{Custom}> };
+    { Default for &{Custom} };
+    { Index<usize> };
+    { Needle<{Inner}> for &{Custom} };
+}
+
+validated_slice::impl_methods_for_slice! {
+    Spec {
+        spec: SortedU32SliceSpec,
+        custom: SortedU32Slice,
+        inner: [u32],
+        error: UnsortedError,
+    };
+    { pub fn get };
+    { pub fn forward(slice) };
+    { pub fn try_modify(region) };
+    { pub fn make_mut_arc };
+    { pub fn make_mut_rc };
+    { pub fn weak_arc };
+    { pub fn weak_rc };
+    { pub fn new_unchecked };
+    { pub fn contains };
+    { pub fn starts_with };
+    { pub fn ends_with };
+    { pub fn find };
+    { pub fn windows };
+    { pub fn chunks };
+    { pub fn binary_search };
+    { pub fn binary_search_by };
+    { pub fn contains_sorted };
+}
+
+enum SortedU32VecSpec {}
+
+impl validated_slice::OwnedSliceSpec for SortedU32VecSpec {
+    type Custom = SortedU32Vec;
+    type Inner = Vec<u32>;
+    type Error = UnsortedVecError;
+    type SliceSpec = SortedU32SliceSpec;
+    type SliceCustom = SortedU32Slice;
+    type SliceInner = [u32];
+    type SliceError = UnsortedError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, v: Self::Inner) -> Self::Error {
+        UnsortedVecError(e, v)
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        SortedU32Vec(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// Unsorted vector error.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnsortedVecError(UnsortedError, Vec<u32>);
+
+/// Sorted `u32` vector.
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedU32Vec(Vec<u32>);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: SortedU32VecSpec,
+        custom: SortedU32Vec,
+        inner: Vec<u32>,
+        error: UnsortedVecError,
+        slice_custom: SortedU32Slice,
+        slice_inner: [u32],
+        slice_error: UnsortedError,
+    };
+    { Borrow<{SliceCustom}> };
+    { From<&{SliceCustom}> };
+    { TryFrom<{Inner}> };
+    { Deref<Target = {SliceCustom}> };
+    { Index<usize> };
+    { Index<Range<usize>, Output = {SliceCustom}> };
+    { Index<RangeInclusive<usize>, Output = {SliceCustom}> };
+    { Index<RangeFull, Output = {SliceCustom}> };
+}
+
+validated_slice::impl_methods_for_owned_slice! {
+    Spec {
+        spec: SortedU32VecSpec,
+        custom: SortedU32Vec,
+        inner: Vec<u32>,
+        error: UnsortedVecError,
+        slice_custom: SortedU32Slice,
+        slice_inner: [u32],
+        slice_error: UnsortedError,
+    };
+    { pub fn new_unchecked };
+    { pub fn dedup(revalidate) };
+    { pub fn retain(revalidate) };
+}
+
+#[cfg(test)]
+mod sorted_u32_slice {
+    use super::*;
+
+    #[test]
+    fn index()
+    where
+        SortedU32Slice: std::ops::Index<usize, Output = u32>,
+    {
+        use std::convert::TryFrom;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 4][..]).expect("Should never fail");
+        assert_eq!(slice[0], 1);
+        assert_eq!(slice[2], 4);
+    }
+
+    #[test]
+    fn get() {
+        use std::convert::TryFrom;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 4][..]).expect("Should never fail");
+        assert_eq!(slice.get(1), Some(&2));
+        assert_eq!(slice.get(3), None);
+        assert_eq!(slice.get(1..), Some(&[2, 4][..]));
+    }
+
+    #[test]
+    fn forward() {
+        use std::convert::TryFrom;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 4][..]).expect("Should never fail");
+        assert_eq!(slice.len(), 3);
+        assert!(!slice.is_empty());
+        assert_eq!(slice.iter().rev().copied().collect::<Vec<_>>(), [4, 2, 1]);
+    }
+
+    #[test]
+    fn try_modify() {
+        use std::convert::TryFrom;
+
+        let mut array = [1, 2, 4];
+        let slice = <&mut SortedU32Slice>::try_from(&mut array[..]).expect("Should never fail");
+        assert_eq!(slice.try_modify(1, |v| *v = 3), Ok(()));
+        assert_eq!(&slice.0, &[1, 3, 4][..]);
+        assert_eq!(
+            slice.try_modify(1, |v| *v = 5),
+            Err(UnsortedError { sorted_up_to: 2 })
+        );
+        assert_eq!(&slice.0, &[1, 3, 4][..]);
+        assert_eq!(
+            slice.try_modify(0, |v| *v = 4),
+            Err(UnsortedError { sorted_up_to: 1 })
+        );
+        assert_eq!(slice.try_modify(2, |v| *v = 9), Ok(()));
+        assert_eq!(&slice.0, &[1, 3, 9][..]);
+    }
+
+    #[test]
+    fn make_mut_arc() {
+        use std::convert::TryFrom;
+        use std::sync::Arc;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 4][..]).expect("Should never fail");
+        let mut shared = Arc::<SortedU32Slice>::from(slice);
+        let orig = Arc::clone(&shared);
+        assert_eq!(
+            SortedU32Slice::make_mut_arc(&mut shared).try_modify(1, |v| *v = 3),
+            Ok(())
+        );
+        assert_eq!(&shared.0, &[1, 3, 4][..]);
+        assert_eq!(&orig.0, &[1, 2, 4][..]);
+
+        // Unique buffer is edited in place.
+        let ptr = Arc::as_ptr(&shared);
+        assert_eq!(
+            SortedU32Slice::make_mut_arc(&mut shared).try_modify(0, |v| *v = 5),
+            Err(UnsortedError { sorted_up_to: 1 })
+        );
+        assert_eq!(Arc::as_ptr(&shared), ptr);
+        assert_eq!(&shared.0, &[1, 3, 4][..]);
+    }
+
+    #[test]
+    fn make_mut_rc() {
+        use std::convert::TryFrom;
+        use std::rc::Rc;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 4][..]).expect("Should never fail");
+        let mut shared = Rc::<SortedU32Slice>::from(slice);
+        let orig = Rc::clone(&shared);
+        assert_eq!(
+            SortedU32Slice::make_mut_rc(&mut shared).try_modify(2, |v| *v = 8),
+            Ok(())
+        );
+        assert_eq!(&shared.0, &[1, 2, 8][..]);
+        assert_eq!(&orig.0, &[1, 2, 4][..]);
+    }
+
+    #[test]
+    fn weak() {
+        use std::convert::TryFrom;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 4][..]).expect("Should never fail");
+        let shared = Arc::<SortedU32Slice>::from(slice);
+        let weak = SortedU32Slice::downgrade_arc(&shared);
+        let upgraded = SortedU32Slice::upgrade_arc(&weak).expect("Should never fail");
+        assert!(Arc::ptr_eq(&shared, &upgraded));
+        drop((shared, upgraded));
+        assert!(SortedU32Slice::upgrade_arc(&weak).is_none());
+
+        let shared = Rc::<SortedU32Slice>::from(slice);
+        let weak = SortedU32Slice::downgrade_rc(&shared);
+        assert_eq!(SortedU32Slice::upgrade_rc(&weak).as_deref(), Some(slice));
+        drop(shared);
+        assert!(SortedU32Slice::upgrade_rc(&weak).is_none());
+    }
+
+    #[test]
+    fn new_unchecked() {
+        // The array is sorted.
+        let slice = unsafe { SortedU32Slice::new_unchecked(&[1, 2, 4]) };
+        assert_eq!(&slice.0, &[1, 2, 4][..]);
+    }
+
+    #[test]
+    fn try_from() {
+        use std::convert::TryFrom;
+
+        assert_eq!(
+            <&SortedU32Slice>::try_from(&[1, 3, 2][..]),
+            Err(UnsortedError { sorted_up_to: 2 })
+        );
+    }
+
+    #[test]
+    fn search() {
+        use std::convert::TryFrom;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 3, 5, 8][..]).expect("Should never fail");
+        let needle = <&SortedU32Slice>::try_from(&[3, 5][..]).expect("Should never fail");
+        assert!(slice.contains(needle));
+        assert!(slice.contains(&[2, 3][..]));
+        assert!(!slice.contains(&[1, 3][..]));
+        assert!(slice.starts_with(&[1, 2][..]));
+        assert!(!slice.starts_with(needle));
+        assert!(slice.ends_with(&[5, 8][..]));
+        assert_eq!(slice.find(needle), Some(2));
+        assert_eq!(slice.find(&[][..]), Some(0));
+        assert_eq!(slice.find(&[4][..]), None);
+        assert_eq!(slice.find(&[5, 8]), Some(3));
+        assert_eq!(slice.find(&vec![2, 3]), Some(1));
+        assert_eq!(slice.find(|&x: &u32| x > 4), Some(3));
+        assert!(slice.starts_with(|&x: &u32| x == 1));
+        assert!(!slice.ends_with(|&x: &u32| x % 2 == 1));
+    }
+
+    #[test]
+    fn windows_and_chunks() {
+        use std::convert::TryFrom;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 3, 5, 8][..]).expect("Should never fail");
+        let windows: Vec<&SortedU32Slice> = slice.windows(4).collect();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(&windows[1].0, &[2, 3, 5, 8][..]);
+        let chunks: Vec<&[u32]> = slice.chunks(2).map(|chunk| &chunk.0).collect();
+        assert_eq!(chunks, [&[1, 2][..], &[3, 5][..], &[8][..]]);
+    }
+
+    #[test]
+    fn sub_slices_adapters() {
+        use std::convert::TryFrom;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 3, 5, 8][..]).expect("Should never fail");
+        let mut chunks = slice.chunks(2);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.next_back().map(|chunk| &chunk.0), Some(&[8][..]));
+        assert_eq!(chunks.len(), 2);
+        let rev: Vec<&[u32]> = slice.windows(4).rev().map(|w| &w.0).collect();
+        assert_eq!(rev, [&[2, 3, 5, 8][..], &[1, 2, 3, 5][..]]);
+        let mut fused = slice.windows(5).fuse();
+        assert!(fused.next().is_some());
+        assert!(fused.next().is_none());
+        assert!(fused.next().is_none());
+    }
+
+    #[test]
+    fn binary_search() {
+        use std::convert::TryFrom;
+
+        let slice = <&SortedU32Slice>::try_from(&[1, 2, 3, 5, 8][..]).expect("Should never fail");
+        assert_eq!(slice.binary_search(&5), Ok(3));
+        assert_eq!(slice.binary_search(&4), Err(3));
+        assert_eq!(slice.binary_search_by(|x| x.cmp(&8)), Ok(4));
+        assert!(slice.contains_sorted(&1));
+        assert!(!slice.contains_sorted(&7));
+    }
+}
+
+#[cfg(test)]
+mod sorted_u32_vec {
+    use super::*;
+
+    #[test]
+    fn index()
+    where
+        SortedU32Vec: std::ops::Index<usize, Output = u32>,
+    {
+        use std::convert::TryFrom;
+
+        let vec = SortedU32Vec::try_from(vec![1, 2, 4]).expect("Should never fail");
+        assert_eq!(vec[1], 2);
+        assert_eq!(vec.get(2), Some(&4));
+    }
+
+    #[test]
+    fn index_range() {
+        use std::convert::TryFrom;
+
+        let vec = SortedU32Vec::try_from(vec![1, 2, 4, 8]).expect("Should never fail");
+        let sub: &SortedU32Slice = &vec[1..3];
+        assert_eq!(&sub.0, &[2, 4][..]);
+        assert_eq!(&vec[1..=3].0, &[2, 4, 8][..]);
+        assert_eq!(&vec[..].0, &[1, 2, 4, 8][..]);
+    }
+
+    #[test]
+    fn reorder_revalidate() {
+        use std::convert::TryFrom;
+
+        let mut vec = SortedU32Vec::try_from(vec![1, 2, 2, 4, 5]).expect("Should never fail");
+        assert_eq!(vec.dedup(), Ok(()));
+        assert_eq!(vec.0, [1, 2, 4, 5]);
+        assert_eq!(vec.retain(|&v| v % 2 == 1), Ok(()));
+        assert_eq!(vec.0, [1, 5]);
+    }
+
+    #[test]
+    fn new_unchecked() {
+        // The vector is sorted.
+        let vec = unsafe { SortedU32Vec::new_unchecked(vec![1, 2, 4]) };
+        assert_eq!(vec.0, [1, 2, 4]);
+    }
+}
+
+/// Slice whose validation panics on `u32::MAX`, to test `try_modify` with panics.
+mod panicking_revalidation {
+    enum NoMaxSliceSpec {}
+
+    impl validated_slice::SliceSpec for NoMaxSliceSpec {
+        type Custom = NoMaxSlice;
+        type Inner = [u32];
+        type Error = ();
+
+        fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+            assert!(!s.contains(&u32::MAX), "validation panicked");
+            Ok(())
+        }
+
+        validated_slice::impl_slice_spec_methods! {
+            field=0;
+            methods=[
+                as_inner,
+                as_inner_mut,
+                from_inner_unchecked,
+                from_inner_unchecked_mut,
+            ];
+        }
+    }
+
+    /// `u32` slice without `u32::MAX`.
+    #[repr(transparent)]
+    #[derive(Debug)]
+    pub struct NoMaxSlice([u32]);
+
+    validated_slice::impl_std_traits_for_slice! {
+        Spec {
+            spec: NoMaxSliceSpec,
+            custom: NoMaxSlice,
+            inner: [u32],
+            error: (),
+        };
+        { TryFrom<&mut {Inner}> for &mut {Custom} };
+    }
+
+    validated_slice::impl_methods_for_slice! {
+        Spec {
+            spec: NoMaxSliceSpec,
+            custom: NoMaxSlice,
+            inner: [u32],
+            error: (),
+        };
+        { pub fn try_modify };
+    }
+
+    #[test]
+    fn try_modify_restores_on_panic() {
+        use std::convert::TryFrom;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut array = [1, 2, 3];
+        let slice = <&mut NoMaxSlice>::try_from(&mut array[..]).expect("Should never fail");
+        let result = catch_unwind(AssertUnwindSafe(|| slice.try_modify(1, |v| *v = u32::MAX)));
+        assert!(result.is_err());
+        assert_eq!(&slice.0, &[1, 2, 3][..]);
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            slice.try_modify(1, |_| panic!("closure panicked"))
+        }));
+        assert!(result.is_err());
+        assert_eq!(&slice.0, &[1, 2, 3][..]);
+    }
+}
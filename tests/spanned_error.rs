@@ -0,0 +1,145 @@
+//! Located validation errors.
+//!
+//! Config keys such as `server.port`, with diagnostic output of validation errors.
+
+use std::ops::Range;
+
+use validated_slice::{CaretSnippet, SpannedError};
+
+enum ConfigKeySpec {}
+
+impl validated_slice::SliceSpec for ConfigKeySpec {
+    type Custom = ConfigKey;
+    type Inner = str;
+    type Error = ConfigKeyError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        let mut after_dot = true;
+        for (pos, c) in s.char_indices() {
+            if c == '.' && !after_dot {
+                after_dot = true;
+            } else if c.is_ascii_lowercase() || (c == '_' && !after_dot) {
+                after_dot = false;
+            } else {
+                return Err(ConfigKeyError::InvalidChar {
+                    pos,
+                    len: c.len_utf8(),
+                });
+            }
+        }
+        if after_dot {
+            return Err(ConfigKeyError::UnexpectedEnd { pos: s.len() });
+        }
+        Ok(())
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Config key error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigKeyError {
+    /// Invalid character.
+    InvalidChar {
+        /// Byte position of the character.
+        pos: usize,
+        /// Byte length of the character.
+        len: usize,
+    },
+    /// Unexpected end of the key.
+    UnexpectedEnd {
+        /// Byte length of the key.
+        pos: usize,
+    },
+}
+
+impl SpannedError for ConfigKeyError {
+    fn span(&self) -> Range<usize> {
+        match *self {
+            ConfigKeyError::InvalidChar { pos, len } => pos..(pos + len),
+            ConfigKeyError::UnexpectedEnd { pos } => pos..pos,
+        }
+    }
+
+    fn expected(&self) -> Option<&str> {
+        Some("lowercase letter")
+    }
+
+    fn found(&self) -> Option<&str> {
+        match self {
+            ConfigKeyError::InvalidChar { .. } => None,
+            ConfigKeyError::UnexpectedEnd { .. } => Some("end of key"),
+        }
+    }
+}
+
+/// Config key.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConfigKey(str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: ConfigKeySpec,
+        custom: ConfigKey,
+        inner: str,
+        error: ConfigKeyError,
+    };
+    { TryFrom<&{Inner}> for &{Custom} };
+}
+
+#[cfg(test)]
+mod config_key {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    fn snippet(s: &str) -> String {
+        let e = <&ConfigKey>::try_from(s).expect_err("Should fail");
+        CaretSnippet::new(s, &e).to_string()
+    }
+
+    #[test]
+    fn invalid_char() {
+        assert_eq!(
+            snippet("server.Port"),
+            "server.Port\n       ^ expected lowercase letter"
+        );
+        assert_eq!(
+            snippet("\u{e9}t\u{e9}"),
+            "\u{e9}t\u{e9}\n^ expected lowercase letter"
+        );
+        assert_eq!(
+            snippet("caf\u{e9}.x"),
+            "caf\u{e9}.x\n   ^ expected lowercase letter"
+        );
+    }
+
+    #[test]
+    fn unexpected_end() {
+        assert_eq!(
+            snippet("server."),
+            "server.\n       ^ expected lowercase letter, found end of key"
+        );
+    }
+
+    #[test]
+    fn multiline() {
+        let input = "server.port\nserver..host";
+        let e = ConfigKeyError::InvalidChar { pos: 19, len: 1 };
+        assert_eq!(
+            CaretSnippet::new(input, &e).to_string(),
+            "server..host\n       ^ expected lowercase letter"
+        );
+    }
+}
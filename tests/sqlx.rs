@@ -0,0 +1,69 @@
+//! `sqlx` integration.
+#![cfg(feature = "sqlx")]
+
+mod common;
+
+use self::common::{AsciiError, AsciiString, AsciiStringSpec};
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+    };
+    { sqlx::Type };
+    { sqlx::Encode };
+    { sqlx::Decode };
+}
+
+#[test]
+fn sqlx_traits() {
+    fn assert_impls<'q, DB, T>()
+    where
+        DB: sqlx::Database,
+        T: sqlx::Type<DB> + sqlx::Encode<'q, DB> + sqlx::Decode<'q, DB>,
+    {
+    }
+
+    // Impls for `AsciiString` should be available whenever impls for `String` are available.
+    #[allow(dead_code)]
+    fn assert_delegated<'q, DB>()
+    where
+        DB: sqlx::Database,
+        String: sqlx::Type<DB> + sqlx::Encode<'q, DB> + sqlx::Decode<'q, DB>,
+        for<'e> &'e str: sqlx::Encode<'q, DB>,
+    {
+        assert_impls::<DB, AsciiString>();
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn decode() {
+    use sqlx::Connection;
+    use std::convert::TryFrom;
+
+    let mut conn = sqlx::SqliteConnection::connect("sqlite::memory:")
+        .await
+        .expect("in-memory database should be available");
+
+    let valid: AsciiString = sqlx::query_scalar("SELECT 'ascii'")
+        .fetch_one(&mut conn)
+        .await
+        .expect("ASCII text should be decoded");
+    assert_eq!(
+        valid,
+        AsciiString::try_from("ascii".to_owned()).expect("Should never fail")
+    );
+
+    let err = sqlx::query_scalar::<_, AsciiString>("SELECT 'non-ascii: \u{e9}'")
+        .fetch_one(&mut conn)
+        .await
+        .expect_err("non-ASCII text should be rejected");
+    match err {
+        sqlx::Error::ColumnDecode { source, .. } => {
+            let source = source
+                .downcast_ref::<AsciiError>()
+                .expect("validation error should be returned as is");
+            assert_eq!(source.valid_up_to(), 11);
+        }
+        e => panic!("unexpected error: {:?}", e),
+    }
+}
@@ -0,0 +1,161 @@
+//! Constant-time comparison by `subtle` crate.
+#![cfg(feature = "subtle")]
+
+enum SecretStrSpec {}
+
+impl validated_slice::SliceSpec for SecretStrSpec {
+    type Custom = SecretStr;
+    type Inner = str;
+    type Error = EmptySecretError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        if s.is_empty() {
+            return Err(EmptySecretError);
+        }
+        Ok(())
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+impl validated_slice::ConstTimeEqSpec for SecretStrSpec {
+    fn eq_const_time(lhs: &str, rhs: &str) -> bool {
+        validated_slice::ct_eq_bytes(lhs, rhs)
+    }
+}
+
+/// Empty secret error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmptySecretError;
+
+/// Non-empty secret string slice.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct SecretStr(str);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: SecretStrSpec,
+        custom: SecretStr,
+        inner: str,
+        error: EmptySecretError,
+    };
+    { TryFrom<&{Inner}> for &{Custom} };
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        spec: SecretStrSpec,
+        custom: SecretStr,
+        inner: str,
+        base: ConstTime,
+    };
+    Cmp { PartialEq };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), (&{Inner}), rev };
+}
+
+enum SecretStringSpec {}
+
+impl validated_slice::OwnedSliceSpec for SecretStringSpec {
+    type Custom = SecretString;
+    type Inner = String;
+    type Error = EmptySecretError;
+    type SliceSpec = SecretStrSpec;
+    type SliceCustom = SecretStr;
+    type SliceInner = str;
+    type SliceError = EmptySecretError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        SecretString(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// Non-empty secret string.
+#[derive(Debug, Clone)]
+pub struct SecretString(String);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: SecretStringSpec,
+        custom: SecretString,
+        inner: String,
+        error: EmptySecretError,
+        slice_custom: SecretStr,
+        slice_inner: str,
+        slice_error: EmptySecretError,
+    };
+    { TryFrom<&{SliceInner}> };
+}
+
+validated_slice::impl_cmp_for_owned_slice! {
+    Spec {
+        spec: SecretStringSpec,
+        custom: SecretString,
+        inner: String,
+        slice_custom: SecretStr,
+        slice_inner: str,
+        base: ConstTime,
+    };
+    Cmp { PartialEq };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), (&{SliceCustom}), rev };
+}
+
+#[cfg(test)]
+mod secret {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn borrowed() {
+        let secret = <&SecretStr>::try_from("hunter2").expect("Should never fail");
+        let same = <&SecretStr>::try_from("hunter2").expect("Should never fail");
+        let other = <&SecretStr>::try_from("hunter3").expect("Should never fail");
+        assert!(*secret == *same);
+        assert!(*secret != *other);
+        assert!(*secret == "hunter2");
+        assert!("hunter" != *secret);
+    }
+
+    #[test]
+    fn owned() {
+        let secret = SecretString::try_from("hunter2").expect("Should never fail");
+        let other = SecretString::try_from("hunter").expect("Should never fail");
+        let slice = <&SecretStr>::try_from("hunter2").expect("Should never fail");
+        assert!(secret == secret.clone());
+        assert!(secret != other);
+        assert!(secret == slice);
+        assert!(slice != other);
+    }
+}
@@ -0,0 +1,353 @@
+//! Tagged string.
+//!
+//! Validated string types with the same validation and distinct type identity per domain.
+
+use std::marker::PhantomData;
+
+/// Spec for `TaggedStr<T>`.
+pub struct TaggedStrSpec<T>(PhantomData<T>);
+
+impl<T> validated_slice::SliceSpec for TaggedStrSpec<T> {
+    type Custom = TaggedStr<T>;
+    type Inner = str;
+    type Error = EmptyError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        if s.is_empty() {
+            return Err(EmptyError);
+        }
+        Ok(())
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=1;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+// `validate` does not depend on the tag, and the methods only forward to the field.
+unsafe impl<T> validated_slice::TrustedSpec for TaggedStrSpec<T> {}
+
+/// Empty string error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmptyError;
+
+/// Non-empty string tagged with the domain type `T`.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+pub struct TaggedStr<T>(PhantomData<T>, str);
+
+/// Tag for user names.
+pub enum User {}
+
+/// Tag for group names.
+pub enum Group {}
+
+/// Name whose `From` impl does not keep the content, to test that the converted value is
+/// validated.
+pub struct HostileName;
+
+impl From<HostileName> for Box<str> {
+    fn from(_: HostileName) -> Self {
+        Box::from("")
+    }
+}
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: TaggedStrSpec<User>,
+        custom: TaggedStr<User>,
+        inner: str,
+        error: EmptyError,
+    };
+    { AsRef<str> };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { TryFrom<&mut {Inner}> for &mut {Custom} };
+    { TryFrom<String> for Box<{Custom}> };
+    { TryFrom<HostileName> for Box<{Custom}> };
+    { Debug };
+    { Deref<Target = {Inner}> };
+}
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: TaggedStrSpec<Group>,
+        custom: TaggedStr<Group>,
+        inner: str,
+        error: EmptyError,
+    };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Debug };
+    { Deref<Target = {Inner}> };
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        spec: TaggedStrSpec<User>,
+        custom: TaggedStr<User>,
+        inner: str,
+        owned_inner: String,
+        base: Inner,
+    };
+    Cmp { PartialEq };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), ({Inner}), rev };
+    { ({Custom}), ({OwnedInner}), rev };
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        spec: TaggedStrSpec<Group>,
+        custom: TaggedStr<Group>,
+        inner: str,
+        base: Inner,
+    };
+    Cmp { PartialEq };
+    { ({Custom}), ({Inner}) };
+}
+
+// `PartialEq<str> for TaggedStr<Group>` is already implemented above.
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        spec: TaggedStrSpec<Group>,
+        custom: TaggedStr<Group>,
+        inner: str,
+        base: Inner,
+    };
+    Cmp { PartialEq };
+    { ({Custom}), ({Inner}), rev_only };
+}
+
+// Impls generic over the tag.
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        tags: [T],
+        spec: TaggedStrSpec<T>,
+        custom: TaggedStr<T>,
+        inner: str,
+        error: EmptyError,
+    };
+    Check { Trusted };
+    { Borrow<{Inner}> };
+    { From<&{Custom}> for &{Inner} };
+    // Explicit `for {Custom}` keeps the tag parameter.
+    { Display for {Custom} };
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        tags: [T],
+        spec: TaggedStrSpec<T>,
+        custom: TaggedStr<T>,
+        inner: str,
+        base: Inner,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), (&{Inner}), rev };
+}
+
+/// Spec for `TaggedString<T>`.
+pub struct TaggedStringSpec<T>(PhantomData<T>);
+
+impl<T> validated_slice::OwnedSliceSpec for TaggedStringSpec<T> {
+    type Custom = TaggedString<T>;
+    type Inner = String;
+    type Error = EmptyError;
+    type SliceSpec = TaggedStrSpec<T>;
+    type SliceCustom = TaggedStr<T>;
+    type SliceInner = str;
+    type SliceError = EmptyError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.1
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        TaggedString(PhantomData, s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.1
+    }
+}
+
+// `TaggedStrSpec<T>` is also trusted.
+unsafe impl<T> validated_slice::TrustedSpec for TaggedStringSpec<T> {}
+
+/// Non-empty owned string tagged with the domain type `T`.
+pub struct TaggedString<T>(PhantomData<T>, String);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        tags: [T],
+        spec: TaggedStringSpec<T>,
+        custom: TaggedString<T>,
+        inner: String,
+        error: EmptyError,
+        slice_custom: TaggedStr<T>,
+        slice_inner: str,
+        slice_error: EmptyError,
+    };
+    Check { Trusted };
+    { Borrow<{SliceCustom}> };
+    { ToOwned<Owned = {Custom}> for {SliceCustom} };
+    { From<&{SliceCustom}> };
+    { TryFrom<{Inner}> };
+    { Debug };
+    { Display for {Custom} };
+    { Deref<Target = {SliceCustom}> };
+}
+
+validated_slice::impl_cmp_for_owned_slice! {
+    Spec {
+        tags: [T],
+        spec: TaggedStringSpec<T>,
+        custom: TaggedString<T>,
+        inner: String,
+        slice_custom: TaggedStr<T>,
+        slice_inner: str,
+        base: Inner,
+    };
+    Cmp { PartialEq };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), ({SliceCustom}), rev };
+    { ({Custom}), (&{SliceCustom}), rev };
+}
+
+#[cfg(test)]
+mod tagged_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn try_from() {
+        let user = <&TaggedStr<User>>::try_from("root").expect("Should never fail");
+        let group = <&TaggedStr<Group>>::try_from("root").expect("Should never fail");
+        assert_eq!(user, "root");
+        assert_eq!(&**user, &**group);
+        assert_eq!(<&TaggedStr<User>>::try_from(""), Err(EmptyError));
+    }
+
+    #[test]
+    fn try_from_string() {
+        let user =
+            Box::<TaggedStr<User>>::try_from(String::from("root")).expect("Should never fail");
+        assert_eq!(*user, *"root");
+        assert_eq!(
+            Box::<TaggedStr<User>>::try_from(String::new()).map(|_| ()),
+            Err(EmptyError)
+        );
+    }
+
+    #[test]
+    fn try_from_hostile_conversion() {
+        assert_eq!(
+            Box::<TaggedStr<User>>::try_from(HostileName).map(|_| ()),
+            Err(EmptyError)
+        );
+    }
+
+    #[test]
+    fn cmp_owned_inner() {
+        let user = <&TaggedStr<User>>::try_from("root").expect("Should never fail");
+        let name = String::from("root");
+        assert_eq!(*user, name);
+        assert_eq!(name, *user);
+    }
+
+    #[test]
+    fn cmp_rev_only() {
+        let group = <&TaggedStr<Group>>::try_from("wheel").expect("Should never fail");
+        assert_eq!(*group, *"wheel");
+        assert_eq!(*"wheel", *group);
+    }
+
+    #[test]
+    fn mutation() {
+        let mut buf = String::from("root");
+        let user = <&mut TaggedStr<User>>::try_from(buf.as_mut_str()).expect("Should never fail");
+        <TaggedStrSpec<User> as validated_slice::SliceSpec>::as_inner_mut(user)
+            .make_ascii_uppercase();
+        assert_eq!(buf, "ROOT");
+    }
+
+    #[test]
+    fn debug() {
+        let user = <&TaggedStr<User>>::try_from("root").expect("Should never fail");
+        assert_eq!(format!("{:?}", user), "\"root\"");
+    }
+
+    #[test]
+    fn display_for_custom() {
+        let user = <&TaggedStr<User>>::try_from("root").expect("Should never fail");
+        let group = <&TaggedStr<Group>>::try_from("wheel").expect("Should never fail");
+        assert_eq!(format!("{}", user), "root");
+        assert_eq!(format!("{}", group), "wheel");
+        let owned =
+            TaggedString::<User>::try_from(String::from("root")).expect("Should never fail");
+        assert_eq!(format!("{}", owned), "root");
+    }
+}
+
+#[cfg(test)]
+mod tagged_string {
+    use super::*;
+
+    use std::borrow::{Borrow, ToOwned};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn generic_over_tag() {
+        fn name_len<T>(name: &TaggedStr<T>) -> usize {
+            <&str>::from(name).len()
+        }
+
+        let user = TaggedString::<User>::try_from(String::from("root")).expect("Should never fail");
+        let group =
+            TaggedString::<Group>::try_from(String::from("wheel")).expect("Should never fail");
+        assert_eq!(name_len(&user), 4);
+        assert_eq!(name_len(&group), 5);
+        assert_eq!(<TaggedStr<Group> as Borrow<str>>::borrow(&group), "wheel");
+        assert_eq!(
+            TaggedString::<Group>::try_from(String::new()).map(|_| ()),
+            Err(EmptyError)
+        );
+    }
+
+    #[test]
+    fn to_owned() {
+        let borrowed = <&TaggedStr<Group>>::try_from("wheel").expect("Should never fail");
+        let owned: TaggedString<Group> = borrowed.to_owned();
+        assert_eq!(owned, *borrowed);
+        assert_eq!(borrowed, owned);
+        assert_eq!(format!("{:?}", owned), "\"wheel\"");
+    }
+
+    #[test]
+    fn cmp() {
+        let user = <&TaggedStr<User>>::try_from("alice").expect("Should never fail");
+        assert!(*user == "alice");
+        assert!("alice" == *user);
+        assert!(*user < "bob");
+    }
+}
@@ -0,0 +1,36 @@
+//! `ufmt` integration.
+#![cfg(feature = "ufmt")]
+
+mod common;
+
+use self::common::{AsciiStr, AsciiStrSpec, AsciiString, AsciiStringSpec};
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        spec: AsciiStrSpec,
+    };
+    { ufmt::uDisplay };
+}
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        spec: AsciiStringSpec,
+    };
+    { ufmt::uDisplay };
+}
+
+#[cfg(test)]
+mod ascii_str {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn uwrite() {
+        let slice = <&AsciiStr>::try_from("foo").expect("Should never fail");
+        let owned = AsciiString::try_from("bar".to_owned()).expect("Should never fail");
+        let mut buf = String::new();
+        ufmt::uwrite!(buf, "{} {}", slice, owned).expect("Should never fail");
+        assert_eq!(buf, "foo bar");
+    }
+}
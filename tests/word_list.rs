@@ -0,0 +1,220 @@
+//! Word list.
+//!
+//! Custom types with lifetime parameters, which borrow the words from the other string.
+
+use std::marker::PhantomData;
+
+/// Spec for `WordList`.
+pub struct WordListSpec<'a>(PhantomData<&'a str>);
+
+impl<'a> validated_slice::SliceSpec for WordListSpec<'a> {
+    type Custom = WordList<'a>;
+    type Inner = [&'a str];
+    type Error = WordError;
+
+    fn validate(s: &Self::Inner) -> Result<(), Self::Error> {
+        match s
+            .iter()
+            .position(|word| word.is_empty() || word.contains(char::is_whitespace))
+        {
+            Some(index) => Err(WordError { index }),
+            None => Ok(()),
+        }
+    }
+
+    validated_slice::impl_slice_spec_methods! {
+        field=0;
+        methods=[
+            as_inner,
+            as_inner_mut,
+            from_inner_unchecked,
+            from_inner_unchecked_mut,
+        ];
+    }
+}
+
+/// Invalid word error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WordError {
+    /// Index of the first invalid word.
+    index: usize,
+}
+
+/// Slice of words, which are non-empty and have no whitespaces.
+// `#[repr(transparent)]` or `#[repr(C)]` is required.
+// Without it, generated codes would be unsound.
+#[repr(transparent)]
+#[derive(Eq)]
+pub struct WordList<'a>([&'a str]);
+
+validated_slice::impl_std_traits_for_slice! {
+    Spec {
+        lifetimes: ['a],
+        spec: WordListSpec<'a>,
+        custom: WordList<'a>,
+        inner: [&'a str],
+        error: WordError,
+    };
+    { AsRef<[&'a str]> };
+    { From<&{Custom}> for &{Inner} };
+    { TryFrom<&{Inner}> for &{Custom} };
+    { Default for &{Custom} };
+    { Debug };
+    { Deref<Target = {Inner}> };
+}
+
+validated_slice::impl_cmp_for_slice! {
+    Spec {
+        lifetimes: ['a],
+        spec: WordListSpec<'a>,
+        custom: WordList<'a>,
+        inner: [&'a str],
+        base: Inner,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), ({Inner}), rev };
+}
+
+/// Spec for `WordListBuf`.
+pub struct WordListBufSpec<'a>(PhantomData<&'a str>);
+
+impl<'a> validated_slice::OwnedSliceSpec for WordListBufSpec<'a> {
+    type Custom = WordListBuf<'a>;
+    type Inner = Vec<&'a str>;
+    type Error = WordError;
+    type SliceSpec = WordListSpec<'a>;
+    type SliceCustom = WordList<'a>;
+    type SliceInner = [&'a str];
+    type SliceError = WordError;
+
+    #[inline]
+    fn convert_validation_error(e: Self::SliceError, _: Self::Inner) -> Self::Error {
+        e
+    }
+
+    #[inline]
+    fn as_slice_inner(s: &Self::Custom) -> &Self::SliceInner {
+        &s.0
+    }
+
+    #[inline]
+    fn inner_as_slice_inner(s: &Self::Inner) -> &Self::SliceInner {
+        s
+    }
+
+    #[inline]
+    unsafe fn from_inner_unchecked(s: Self::Inner) -> Self::Custom {
+        WordListBuf(s)
+    }
+
+    #[inline]
+    fn into_inner(s: Self::Custom) -> Self::Inner {
+        s.0
+    }
+}
+
+/// Vector of words.
+#[derive(Default, Clone, Eq)]
+pub struct WordListBuf<'a>(Vec<&'a str>);
+
+validated_slice::impl_std_traits_for_owned_slice! {
+    Spec {
+        lifetimes: ['a],
+        spec: WordListBufSpec<'a>,
+        custom: WordListBuf<'a>,
+        inner: Vec<&'a str>,
+        error: WordError,
+        slice_custom: WordList<'a>,
+        slice_inner: [&'a str],
+        slice_error: WordError,
+    };
+    { Borrow<{SliceCustom}> };
+    { ToOwned<Owned = {Custom}> for {SliceCustom} };
+    { AsRef<{SliceCustom}> };
+    { From<&{SliceCustom}> };
+    { From<{Custom}> for {Inner} };
+    { TryFrom<&{SliceInner}> };
+    { TryFrom<{Inner}> };
+    { Debug };
+    { Deref<Target = {SliceCustom}> };
+}
+
+validated_slice::impl_cmp_for_owned_slice! {
+    Spec {
+        lifetimes: ['a],
+        spec: WordListBufSpec<'a>,
+        custom: WordListBuf<'a>,
+        inner: Vec<&'a str>,
+        slice_custom: WordList<'a>,
+        slice_inner: [&'a str],
+        base: Inner,
+    };
+    Cmp { PartialEq, PartialOrd };
+    { ({Custom}), ({Custom}) };
+    { ({Custom}), ({SliceCustom}), rev };
+    { ({Custom}), ({SliceInner}), rev };
+}
+
+validated_slice::assert_auto_traits! {
+    WordList<'static>: Send + Sync;
+    WordListBuf<'static>: Send + Sync;
+}
+
+#[cfg(test)]
+mod word_list {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn borrowed_words() {
+        let text = String::from("foo bar baz");
+        let words = text.split(' ').collect::<Vec<_>>();
+        let list = <&WordList<'_>>::try_from(&words[..]).expect("Should never fail");
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[1], "bar");
+        assert_eq!(*list, ["foo", "bar", "baz"][..]);
+        assert_eq!(
+            <&WordList<'_>>::try_from(&["foo", "", "bar"][..]),
+            Err(WordError { index: 1 })
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        let list = <&WordList<'_>>::try_from(&["foo", "bar"][..]).expect("Should never fail");
+        assert_eq!(format!("{:?}", list), r#"["foo", "bar"]"#);
+        assert_eq!(format!("{:?}", <&WordList<'_>>::default()), "[]");
+    }
+
+    #[test]
+    fn cmp() {
+        let foo = <&WordList<'_>>::try_from(&["foo"][..]).expect("Should never fail");
+        let bar = <&WordList<'_>>::try_from(&["bar"][..]).expect("Should never fail");
+        assert!(*bar < *foo);
+        assert!(*foo > ["bar"][..]);
+    }
+}
+
+#[cfg(test)]
+mod word_list_buf {
+    use super::*;
+
+    use std::convert::TryFrom;
+
+    #[test]
+    fn owned_words() {
+        let text = String::from("foo bar");
+        let buf =
+            WordListBuf::try_from(text.split(' ').collect::<Vec<_>>()).expect("Should never fail");
+        let list: &WordList<'_> = &buf;
+        assert_eq!(buf, *list);
+        assert_eq!(list.to_owned(), buf);
+        assert_eq!(Vec::from(buf), ["foo", "bar"]);
+        assert_eq!(
+            WordListBuf::try_from(vec!["foo bar"]),
+            Err(WordError { index: 0 })
+        );
+    }
+}